@@ -0,0 +1,48 @@
+//! Generates API clients from vendored proto contracts in `contracts/`.
+//!
+//! Contracts are parsed without `protoc`. Modules per contract file are always generated, messages
+//! and clients are taken from pre-generated `src/api/v1/protos.rs` instead when
+//! `TINKOFF_PREGENERATED_PROTOS` environment variable is set. Missing contracts or `VERSION` file
+//! fail the build.
+
+#[path = "build/contracts.rs"]
+mod contracts;
+
+use std::{env, path::PathBuf};
+
+const CONTRACTS_DIR: &str = "contracts";
+const PREGENERATED_ENV: &str = "TINKOFF_PREGENERATED_PROTOS";
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    println!("cargo::rustc-check-cfg=cfg(generated_protos)");
+    println!("cargo::rerun-if-changed={CONTRACTS_DIR}");
+    println!("cargo::rerun-if-changed=build");
+    println!("cargo::rerun-if-env-changed={PREGENERATED_ENV}");
+
+    let contracts_dir = PathBuf::from(CONTRACTS_DIR);
+    let out_dir = PathBuf::from(env::var("OUT_DIR")?);
+
+    // Embedded as `CONTRACT_VERSION` and `CONTRACT_COMMIT`
+    let (version, commit) = contracts::read_version(&contracts_dir)?;
+    println!("cargo::rustc-env={}={version}", contracts::VERSION_ENV);
+    println!("cargo::rustc-env={}={commit}", contracts::COMMIT_ENV);
+
+    let missing = contracts::missing_contracts(&contracts_dir);
+    if !missing.is_empty() {
+        return Err(format!(
+            "Proto contracts are missing in '{CONTRACTS_DIR}': {}",
+            missing.join(", ")
+        )
+        .into());
+    }
+
+    let descriptor = contracts::descriptor(&contracts_dir)?;
+    contracts::generate_modules(&descriptor, &out_dir)?;
+
+    if env::var_os(PREGENERATED_ENV).is_none() {
+        contracts::generate_protos(&descriptor, &out_dir)?;
+        println!("cargo::rustc-cfg=generated_protos");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,557 @@
+//! Generation of API clients from proto contracts, shared by `build.rs` and examples
+
+#![allow(dead_code)]
+
+#[path = "proto.rs"]
+mod proto;
+
+use heck::{ToSnakeCase, ToUpperCamelCase};
+use prost::Message;
+use prost_types::{DescriptorProto, FileDescriptorSet};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+/// Proto package of API contracts
+const PACKAGE: &str = "tinkoff.public.invest.api.contract.v1";
+
+/// Contract files, one generated module per file
+pub const PROTO_FILES: &[&str] = &[
+    "common.proto",
+    "instruments.proto",
+    "marketdata.proto",
+    "operations.proto",
+    "orders.proto",
+    "sandbox.proto",
+    "signals.proto",
+    "stoporders.proto",
+    "users.proto",
+];
+
+/// Name of generated file with messages and clients
+pub const PROTOS_FILE: &str = "protos.rs";
+
+/// Name of generated file with per contract file modules
+pub const MODULES_FILE: &str = "modules.rs";
+
+/// Name of generated file with contract version constants
+pub const CONTRACT_FILE: &str = "contract.rs";
+
+/// Name of descriptor set of contracts, kept for comparing revisions
+pub const DESCRIPTOR_FILE: &str = "contracts.bin";
+
+/// File with `version = ..` and `commit = ..` lines of vendored contracts
+const VERSION_FILE: &str = "VERSION";
+
+/// Path to typed enumeration value used in generated accessors
+const ENUM_VALUE: &str = "crate::api::v1::EnumValue";
+
+/// Path to serde helpers used in generated attributes
+const SERDE_HELPERS: &str = "crate::api::v1::serialization";
+
+/// Messages with hand-written serde implementations
+const SERDE_SKIP: &[&str] = &["Quotation", "MoneyValue"];
+
+/// Returns `true` if all contract files are present in directory
+pub fn has_contracts(contracts_dir: &Path) -> bool {
+    missing_contracts(contracts_dir).is_empty()
+}
+
+/// Returns contract files missing in directory
+pub fn missing_contracts(contracts_dir: &Path) -> Vec<&'static str> {
+    PROTO_FILES
+        .iter()
+        .copied()
+        .filter(|file| !contracts_dir.join(file).is_file())
+        .collect()
+}
+
+/// Parses contract files into descriptor set
+pub fn descriptor(contracts_dir: &Path) -> Result<FileDescriptorSet> {
+    proto::parse_files(contracts_dir, PROTO_FILES)
+}
+
+/// Generates `protos.rs` with messages and clients into output directory
+pub fn generate_protos(descriptor: &FileDescriptorSet, out_dir: &Path) -> Result<()> {
+    tonic_build::configure()
+        .build_client(true)
+        .build_server(false)
+        .out_dir(out_dir)
+        .compile_fds(descriptor.clone())?;
+
+    let generated: PathBuf = out_dir.join(format!("{PACKAGE}.rs"));
+    let protos = fs::read_to_string(&generated)?;
+    fs::remove_file(generated)?;
+
+    // Well-known types are used from `prost_types`, their module is empty
+    for file in descriptor.file.iter().filter(|f| f.package() != PACKAGE) {
+        let _ = fs::remove_file(out_dir.join(format!("{}.rs", file.package())));
+    }
+
+    fs::write(out_dir.join(PROTOS_FILE), post_process(&protos))?;
+
+    Ok(())
+}
+
+/// Generates `modules.rs`, `contract.rs` and `contracts.bin` into output directory
+///
+/// `contracts.bin` is descriptor set of contracts, for comparing revisions with `contracts_diff` example.
+pub fn generate_modules(descriptor: &FileDescriptorSet, out_dir: &Path) -> Result<()> {
+    fs::write(out_dir.join(MODULES_FILE), modules(descriptor))?;
+    fs::write(out_dir.join(CONTRACT_FILE), contract())?;
+    fs::write(out_dir.join(DESCRIPTOR_FILE), descriptor.encode_to_vec())?;
+
+    Ok(())
+}
+
+/// Environment variables set by `build.rs` with version and commit of vendored contracts
+pub const VERSION_ENV: &str = "TINKOFF_CONTRACT_VERSION";
+pub const COMMIT_ENV: &str = "TINKOFF_CONTRACT_COMMIT";
+
+/// Returns version and commit from `VERSION` file content, `#` starts comment line
+pub fn contract_version(version: &str) -> Result<(String, String)> {
+    let value = |key: &str| {
+        version
+            .lines()
+            .filter(|line| !line.trim_start().starts_with('#'))
+            .filter_map(|line| line.split_once('='))
+            .find(|(k, _)| k.trim() == key)
+            .map(|(_, v)| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+            .ok_or_else(|| format!("'{key}' is missing in contracts {VERSION_FILE} file"))
+    };
+
+    Ok((value("version")?, value("commit")?))
+}
+
+/// Reads `VERSION` file of contracts directory, fails if it is missing
+pub fn read_version(contracts_dir: &Path) -> Result<(String, String)> {
+    let path = contracts_dir.join(VERSION_FILE);
+    let version = fs::read_to_string(&path).map_err(|err| {
+        format!(
+            "Failed to read '{}', record revision of vendored contracts: {err}",
+            path.display()
+        )
+    })?;
+
+    contract_version(&version)
+}
+
+/// Generates contract version constants, values are set by `build.rs` from `VERSION` file
+pub fn contract() -> String {
+    format!(
+        "// This file is @generated from proto contracts.\n\
+        /// Version of proto contracts the API was generated from.\n\
+        pub const CONTRACT_VERSION: &str = env!({VERSION_ENV:?});\n\
+        /// Commit of proto contracts the API was generated from.\n\
+        pub const CONTRACT_COMMIT: &str = env!({COMMIT_ENV:?});\n",
+    )
+}
+
+/// Applies all post-processing steps to generated `protos.rs`
+pub fn post_process(protos: &str) -> String {
+    let protos = annotate_serde(protos);
+
+    add_enum_accessors(&protos)
+}
+
+// region:    --- Modules
+
+/// Generates one module per contract file, re-exporting items generated from it
+pub fn modules(descriptor: &FileDescriptorSet) -> String {
+    let mut output = vec![String::from(
+        "// This file is @generated from proto contracts, one module per contract file.",
+    )];
+
+    for file in descriptor.file.iter().filter(|f| f.package() == PACKAGE) {
+        let name = file.name();
+        let module = Path::new(name)
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or(name);
+
+        let mut items = Vec::new();
+
+        for message in &file.message_type {
+            items.push(message.name().to_upper_camel_case());
+
+            if has_nested_module(message) {
+                items.push(message.name().to_snake_case());
+            }
+        }
+
+        items.extend(
+            file.enum_type
+                .iter()
+                .map(|e| e.name().to_upper_camel_case()),
+        );
+        items.extend(
+            file.service
+                .iter()
+                .map(|s| format!("{}_client", s.name().to_snake_case())),
+        );
+
+        output.push(format!("/// Items generated from `{name}`."));
+        output.push(format!("pub mod {module} {{"));
+        output.push(String::from("    pub use super::protos::{"));
+        output.extend(items.iter().map(|item| format!("        {item},")));
+        output.push(String::from("    };"));
+        output.push(String::from("}"));
+    }
+
+    output.join("\n") + "\n"
+}
+
+/// Returns `true` if prost generates nested module for message
+fn has_nested_module(message: &DescriptorProto) -> bool {
+    let nested_messages = message
+        .nested_type
+        .iter()
+        .any(|nested| !nested.options.as_ref().is_some_and(|o| o.map_entry()));
+
+    // Synthetic oneofs of proto3 `optional` fields are not generated
+    let oneofs = (0..message.oneof_decl.len() as i32).any(|index| {
+        message
+            .field
+            .iter()
+            .any(|field| field.oneof_index == Some(index) && !field.proto3_optional())
+    });
+
+    nested_messages || !message.enum_type.is_empty() || oneofs
+}
+
+// endregion: --- Modules
+
+// region:    --- Serde Annotations
+
+/// Adds `serde` feature gated attributes to generated code, following proto3 JSON mapping
+fn annotate_serde(source: &str) -> String {
+    if source.contains("feature = \"serde\"") {
+        return source.to_string();
+    }
+
+    let lines: Vec<&str> = source.lines().collect();
+    let mut output: Vec<String> = Vec::with_capacity(lines.len());
+    let mut enumerations: Vec<String> = Vec::new();
+    let mut index = 0;
+
+    while index < lines.len() {
+        let line = lines[index];
+        let trimmed = line.trim();
+        let indent = &line[..line.len() - line.trim_start().len()];
+
+        // -- Derives
+        if trimmed.starts_with("#[derive(") || trimmed == "#[derive(" {
+            let (derive, next) = join_until(&lines, index, ")]");
+            output.extend(lines[index..next].iter().map(|l| l.to_string()));
+            index = next;
+
+            let item = lines.get(index).map(|l| l.trim()).unwrap_or_default();
+
+            if derive.contains("::prost::Message") {
+                let name = item_name(item, "pub struct ");
+
+                if SERDE_SKIP.contains(&name) {
+                    let close = format!("{indent}}}");
+
+                    while index < lines.len() && lines[index] != close {
+                        output.push(lines[index].to_string());
+                        index += 1;
+                    }
+
+                    continue;
+                }
+
+                output.push(format!("{indent}#[cfg_attr(feature = \"serde\", derive(::serde::Serialize, ::serde::Deserialize))]"));
+                output.push(format!("{indent}#[cfg_attr(feature = \"serde\", serde(rename_all = \"camelCase\", default))]"));
+            } else if derive.contains("::prost::Oneof") {
+                output.push(format!("{indent}#[cfg_attr(feature = \"serde\", derive(::serde::Serialize, ::serde::Deserialize))]"));
+                output.push(format!(
+                    "{indent}#[cfg_attr(feature = \"serde\", serde(rename_all = \"camelCase\"))]"
+                ));
+            } else if derive.contains("::prost::Enumeration") {
+                let item = lines
+                    .iter()
+                    .skip(index)
+                    .map(|l| l.trim())
+                    .find(|l| l.starts_with("pub enum "))
+                    .unwrap_or_default();
+
+                enumerations.push(item_name(item, "pub enum ").to_string());
+            }
+
+            continue;
+        }
+
+        // -- Fields
+        if trimmed.starts_with("#[prost(") {
+            let (attribute, next) = join_until(&lines, index, ")]");
+            let (field, _) = join_until(&lines, next, ",");
+
+            output.extend(lines[index..next].iter().map(|l| l.to_string()));
+            index = next;
+
+            if let Some(serde) = field_attribute(&attribute, &field) {
+                output.push(format!("{indent}#[cfg_attr(feature = \"serde\", {serde})]"));
+            }
+
+            continue;
+        }
+
+        output.push(line.to_string());
+        index += 1;
+
+        // -- Enumeration names, placed after `impl Enum { .. }` block
+        if let Some(name) = trimmed
+            .strip_prefix("impl ")
+            .and_then(|l| l.strip_suffix(" {"))
+            .filter(|name| enumerations.iter().any(|e| e == name))
+        {
+            let close = format!("{indent}}}");
+
+            while index < lines.len() && lines[index] != close {
+                output.push(lines[index].to_string());
+                index += 1;
+            }
+
+            output.push(close);
+            index += 1;
+
+            output.push(format!("{indent}#[cfg(feature = \"serde\")]"));
+            output.push(format!(
+                "{indent}impl {SERDE_HELPERS}::ProtoEnum for {name} {{"
+            ));
+            output.push(format!(
+                "{indent}    fn proto_name(&self) -> &'static str {{"
+            ));
+            output.push(format!("{indent}        self.as_str_name()"));
+            output.push(format!("{indent}    }}"));
+            output.push(format!(
+                "{indent}    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {{"
+            ));
+            output.push(format!("{indent}        Self::from_str_name(value)"));
+            output.push(format!("{indent}    }}"));
+            output.push(format!("{indent}}}"));
+        }
+    }
+
+    output.join("\n") + "\n"
+}
+
+/// Returns serde attribute for field with given prost attribute
+fn field_attribute(attribute: &str, field: &str) -> Option<String> {
+    let field_type = field.split_once(':').map(|(_, t)| t).unwrap_or(field);
+
+    if attribute.contains("oneof = ") {
+        return Some("serde(flatten)".to_string());
+    }
+
+    if let Some(enumeration) = attribute
+        .split("enumeration = \"")
+        .nth(1)
+        .and_then(|rest| rest.split('"').next())
+    {
+        let helper = if field_type.contains("Option<") {
+            "enumeration_option"
+        } else if field_type.contains("Vec<") {
+            "enumeration_vec"
+        } else {
+            "enumeration"
+        };
+
+        return Some(format!(
+            "serde(serialize_with = \"{SERDE_HELPERS}::{helper}::serialize::<{enumeration}, _>\", \
+            deserialize_with = \"{SERDE_HELPERS}::{helper}::deserialize::<{enumeration}, _>\")"
+        ));
+    }
+
+    if field_type.contains("::prost_types::Timestamp") {
+        let helper = if field_type.contains("Vec<") {
+            "timestamp_vec"
+        } else {
+            "timestamp"
+        };
+
+        return Some(format!("serde(with = \"{SERDE_HELPERS}::{helper}\")"));
+    }
+
+    if attribute.starts_with("#[prost(int64") {
+        return Some(format!("serde(with = \"{SERDE_HELPERS}::int64\")"));
+    }
+
+    None
+}
+
+/// Joins lines starting from `start` until line ending with `end`, returns joined text and next index
+fn join_until(lines: &[&str], start: usize, end: &str) -> (String, usize) {
+    let mut joined = String::new();
+    let mut index = start;
+
+    while index < lines.len() {
+        joined.push_str(lines[index].trim());
+        index += 1;
+
+        if joined.ends_with(end) {
+            break;
+        }
+    }
+
+    (joined, index)
+}
+
+/// Extracts item name from `pub struct Name {` like line
+fn item_name<'a>(line: &'a str, prefix: &str) -> &'a str {
+    line.trim_start_matches(prefix)
+        .split(|c: char| !c.is_alphanumeric() && c != '_')
+        .next()
+        .unwrap_or_default()
+}
+
+// endregion: --- Serde Annotations
+
+// region:    --- Enum Accessors
+
+/// Shape of enumeration field
+enum Shape {
+    Single,
+    Optional,
+    Repeated,
+}
+
+/// Enumeration field of message
+struct EnumField {
+    name: String,
+    enumeration: String,
+    shape: Shape,
+}
+
+/// Adds typed `<field>_value` getters and `with_<field>` builders for enumeration fields
+fn add_enum_accessors(source: &str) -> String {
+    if source.contains(ENUM_VALUE) {
+        return source.to_string();
+    }
+
+    let lines: Vec<&str> = source.lines().collect();
+    let mut output: Vec<String> = Vec::with_capacity(lines.len());
+    let mut message: Option<(String, String, Vec<EnumField>)> = None;
+
+    for (index, line) in lines.iter().enumerate() {
+        let trimmed = line.trim();
+        let indent = &line[..line.len() - line.trim_start().len()];
+
+        output.push(line.to_string());
+
+        if trimmed.starts_with("pub struct ") && trimmed.ends_with('{') {
+            let name = item_name(trimmed, "pub struct ").to_string();
+            message = Some((name, format!("{indent}}}"), Vec::new()));
+
+            continue;
+        }
+
+        let Some((name, close, fields)) = &mut message else {
+            continue;
+        };
+
+        if trimmed.starts_with("#[prost(") {
+            let (attribute, next) = join_until(&lines, index, ")]");
+
+            let Some(enumeration) = attribute
+                .split("enumeration = \"")
+                .nth(1)
+                .and_then(|rest| rest.split('"').next())
+            else {
+                continue;
+            };
+
+            let Some(field) = lines[next..]
+                .iter()
+                .map(|l| l.trim())
+                .find(|l| !l.starts_with("#["))
+            else {
+                continue;
+            };
+
+            let Some((field_name, field_type)) = field.trim_start_matches("pub ").split_once(": ")
+            else {
+                continue;
+            };
+
+            let shape = if field_type.contains("Option<") {
+                Shape::Optional
+            } else if field_type.contains("Vec<") {
+                Shape::Repeated
+            } else {
+                Shape::Single
+            };
+
+            fields.push(EnumField {
+                name: field_name.to_string(),
+                enumeration: enumeration.to_string(),
+                shape,
+            });
+        } else if *line == close.as_str() {
+            if !fields.is_empty() {
+                output.extend(enum_accessors(name, indent, fields));
+            }
+
+            message = None;
+        }
+    }
+
+    output.join("\n") + "\n"
+}
+
+/// Generates `impl` block with accessors for enumeration fields of message
+fn enum_accessors(message: &str, indent: &str, fields: &[EnumField]) -> Vec<String> {
+    let mut output = vec![format!("{indent}impl {message} {{")];
+
+    for field in fields {
+        let EnumField {
+            name, enumeration, ..
+        } = field;
+        let plain = name.trim_start_matches("r#");
+
+        let (value_type, value_body, with_argument, with_body) = match field.shape {
+            Shape::Single => (
+                format!("{ENUM_VALUE}<{enumeration}>"),
+                format!("{ENUM_VALUE}::from(self.{name})"),
+                format!("value: {enumeration}"),
+                format!("self.{name} = value as i32;"),
+            ),
+            Shape::Optional => (
+                format!("::core::option::Option<{ENUM_VALUE}<{enumeration}>>"),
+                format!("self.{name}.map({ENUM_VALUE}::from)"),
+                format!("value: {enumeration}"),
+                format!("self.{name} = ::core::option::Option::Some(value as i32);"),
+            ),
+            Shape::Repeated => (
+                format!("::prost::alloc::vec::Vec<{ENUM_VALUE}<{enumeration}>>"),
+                format!("self.{name}.iter().copied().map({ENUM_VALUE}::from).collect()"),
+                format!("values: impl ::core::iter::IntoIterator<Item = {enumeration}>"),
+                format!("self.{name} = values.into_iter().map(|value| value as i32).collect();"),
+            ),
+        };
+
+        output.extend([
+            format!(
+                "{indent}    /// Returns typed `{plain}`, keeping values unknown to the contracts."
+            ),
+            format!("{indent}    pub fn {plain}_value(&self) -> {value_type} {{"),
+            format!("{indent}        {value_body}"),
+            format!("{indent}    }}"),
+            format!("{indent}    /// Sets `{plain}` and returns message."),
+            format!("{indent}    pub fn with_{plain}(mut self, {with_argument}) -> Self {{"),
+            format!("{indent}        {with_body}"),
+            format!("{indent}        self"),
+            format!("{indent}    }}"),
+        ]);
+    }
+
+    output.push(format!("{indent}}}"));
+    output
+}
+
+// endregion: --- Enum Accessors
@@ -0,0 +1,983 @@
+//! Parser of proto3 contracts into descriptors, so clients are generated without `protoc`
+//!
+//! Covers what investAPI contracts use: nested messages and enums, `optional`, `repeated` and
+//! `oneof` fields, streaming methods, imports and options. Of options only `deprecated` is kept,
+//! others are skipped. Leading and trailing comments are kept for generated docs.
+
+#![allow(dead_code)]
+
+use prost_types::{
+    field_descriptor_proto::{Label, Type},
+    source_code_info::Location,
+    DescriptorProto, EnumDescriptorProto, EnumOptions, EnumValueDescriptorProto, EnumValueOptions,
+    FieldDescriptorProto, FieldOptions, FileDescriptorProto, FileDescriptorSet, MessageOptions,
+    MethodDescriptorProto, MethodOptions, OneofDescriptorProto, ServiceDescriptorProto,
+    ServiceOptions, SourceCodeInfo,
+};
+use std::{collections::HashMap, fs, path::Path};
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+/// Well-known import provided without a file, as `protoc` does
+const TIMESTAMP_FILE: &str = "google/protobuf/timestamp.proto";
+
+/// Prefix of imports skipped when missing, they only define options, e.g. `google.api.field_behavior`
+const OPTIONS_IMPORT: &str = "google/";
+
+/// Parses contract files and their imports from directory into descriptor set
+///
+/// Imports go before files importing them, like `protoc --include_imports` output.
+pub fn parse_files(dir: &Path, files: &[&str]) -> Result<FileDescriptorSet> {
+    let mut set = FileDescriptorSet::default();
+
+    for file in files {
+        add_file(dir, file, &mut set)?;
+    }
+
+    resolve_types(&mut set)?;
+
+    Ok(set)
+}
+
+/// Adds file after its imports, skipping already added ones
+fn add_file(dir: &Path, name: &str, set: &mut FileDescriptorSet) -> Result<()> {
+    if set.file.iter().any(|file| file.name() == name) {
+        return Ok(());
+    }
+
+    let path = dir.join(name);
+    let file = if path.is_file() {
+        let source = fs::read_to_string(&path)
+            .map_err(|err| format!("Failed to read '{}': {err}", path.display()))?;
+
+        parse_file(name, &source).map_err(|err| format!("{name}: {err}"))?
+    } else if name == TIMESTAMP_FILE {
+        timestamp_file()
+    } else if name.starts_with(OPTIONS_IMPORT) {
+        return Ok(());
+    } else {
+        return Err(format!("Import '{name}' not found in '{}'", dir.display()).into());
+    };
+
+    for import in &file.dependency {
+        add_file(dir, import, set)?;
+    }
+
+    set.file.push(file);
+
+    Ok(())
+}
+
+/// Descriptor of `google.protobuf.Timestamp`
+fn timestamp_file() -> FileDescriptorProto {
+    let field = |name: &str, number, r#type: Type| FieldDescriptorProto {
+        name: Some(name.to_string()),
+        number: Some(number),
+        label: Some(Label::Optional as i32),
+        r#type: Some(r#type as i32),
+        json_name: Some(name.to_string()),
+        ..Default::default()
+    };
+
+    FileDescriptorProto {
+        name: Some(TIMESTAMP_FILE.to_string()),
+        package: Some(String::from("google.protobuf")),
+        message_type: vec![DescriptorProto {
+            name: Some(String::from("Timestamp")),
+            field: vec![
+                field("seconds", 1, Type::Int64),
+                field("nanos", 2, Type::Int32),
+            ],
+            ..Default::default()
+        }],
+        syntax: Some(String::from("proto3")),
+        ..Default::default()
+    }
+}
+
+// region:    --- Tokens
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Int(i64),
+    Str(String),
+    Symbol(char),
+}
+
+struct Spanned {
+    token: Token,
+    line: usize,
+}
+
+/// Comments of source by line
+#[derive(Default)]
+struct Comments {
+    /// Blocks of `//` comments on own lines, by line after the block
+    leading: HashMap<usize, String>,
+    /// Comments after tokens, by line
+    trailing: HashMap<usize, String>,
+}
+
+fn tokenize(source: &str) -> Result<(Vec<Spanned>, Comments)> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens: Vec<Spanned> = Vec::new();
+    let mut comments = Comments::default();
+    let mut block: Option<(usize, String)> = None;
+    let (mut index, mut line) = (0, 1);
+
+    while index < chars.len() {
+        let c = chars[index];
+
+        if c == '\n' {
+            line += 1;
+            index += 1;
+
+            continue;
+        }
+        if c.is_whitespace() {
+            index += 1;
+
+            continue;
+        }
+
+        // -- Comments
+        if c == '/' && chars.get(index + 1) == Some(&'/') {
+            let start = index + 2;
+            let end = chars[start..]
+                .iter()
+                .position(|&c| c == '\n')
+                .map_or(chars.len(), |position| start + position);
+            let text = format!("{}\n", chars[start..end].iter().collect::<String>());
+
+            if tokens.last().is_some_and(|token| token.line == line) {
+                comments.trailing.insert(line, text);
+            } else {
+                match &mut block {
+                    Some((last, lines)) if *last + 1 == line => {
+                        lines.push_str(&text);
+                        *last = line;
+                    }
+                    _ => block = Some((line, text)),
+                }
+            }
+
+            index = end;
+
+            continue;
+        }
+        if c == '/' && chars.get(index + 1) == Some(&'*') {
+            let start = index + 2;
+            let end = (start..chars.len().saturating_sub(1))
+                .find(|&i| chars[i] == '*' && chars[i + 1] == '/')
+                .ok_or_else(|| format!("line {line}: unterminated comment"))?;
+
+            line += chars[start..end].iter().filter(|&&c| c == '\n').count();
+            index = end + 2;
+
+            continue;
+        }
+
+        // Comment block directly above token is leading for it
+        if let Some((last, text)) = block.take() {
+            if last + 1 == line {
+                comments.leading.insert(line, text);
+            }
+        }
+
+        let token = if c.is_alphabetic()
+            || c == '_'
+            || c == '.' && chars.get(index + 1).is_some_and(|c| c.is_alphabetic())
+        {
+            let start = index;
+            index += 1;
+
+            while chars
+                .get(index)
+                .is_some_and(|&c| c.is_alphanumeric() || c == '_' || c == '.')
+            {
+                index += 1;
+            }
+
+            Token::Ident(chars[start..index].iter().collect())
+        } else if c.is_ascii_digit()
+            || c == '-' && chars.get(index + 1).is_some_and(char::is_ascii_digit)
+        {
+            let start = index;
+            index += 1;
+
+            while chars
+                .get(index)
+                .is_some_and(|&c| c.is_ascii_alphanumeric() || c == '.')
+            {
+                index += 1;
+            }
+
+            let text: String = chars[start..index].iter().collect();
+            let (negative, digits) = match text.strip_prefix('-') {
+                Some(digits) => (true, digits),
+                None => (false, text.as_str()),
+            };
+            let value = match digits
+                .strip_prefix("0x")
+                .or_else(|| digits.strip_prefix("0X"))
+            {
+                Some(hex) => i64::from_str_radix(hex, 16).ok(),
+                None => digits.parse().ok(),
+            };
+
+            match value {
+                Some(value) if negative => Token::Int(-value),
+                Some(value) => Token::Int(value),
+                // Floats only appear in skipped option values
+                None => Token::Ident(text),
+            }
+        } else if c == '"' || c == '\'' {
+            let mut value = String::new();
+            index += 1;
+
+            loop {
+                match chars.get(index) {
+                    None | Some('\n') => {
+                        return Err(format!("line {line}: unterminated string").into())
+                    }
+                    Some(&quote) if quote == c => break,
+                    Some('\\') => {
+                        index += 1;
+                        match chars.get(index) {
+                            Some('n') => value.push('\n'),
+                            Some('t') => value.push('\t'),
+                            Some(&escaped) => value.push(escaped),
+                            None => {}
+                        }
+                    }
+                    Some(&other) => value.push(other),
+                }
+
+                index += 1;
+            }
+
+            index += 1;
+
+            Token::Str(value)
+        } else {
+            index += 1;
+
+            Token::Symbol(c)
+        };
+
+        tokens.push(Spanned { token, line });
+    }
+
+    Ok((tokens, comments))
+}
+
+// endregion: --- Tokens
+
+// region:    --- Parser
+
+// Field numbers of descriptors, used in source code info paths
+const FILE_MESSAGE: i32 = 4;
+const FILE_ENUM: i32 = 5;
+const FILE_SERVICE: i32 = 6;
+const MESSAGE_FIELD: i32 = 2;
+const MESSAGE_NESTED: i32 = 3;
+const MESSAGE_ENUM: i32 = 4;
+const MESSAGE_ONEOF: i32 = 8;
+const ENUM_VALUE: i32 = 2;
+const SERVICE_METHOD: i32 = 2;
+
+struct Parser {
+    tokens: Vec<Spanned>,
+    comments: Comments,
+    position: usize,
+    locations: Vec<Location>,
+}
+
+/// Parses one contract file
+pub fn parse_file(name: &str, source: &str) -> Result<FileDescriptorProto> {
+    let (tokens, comments) = tokenize(source)?;
+    let mut parser = Parser {
+        tokens,
+        comments,
+        position: 0,
+        locations: Vec::new(),
+    };
+
+    let mut file = FileDescriptorProto {
+        name: Some(name.to_string()),
+        ..Default::default()
+    };
+
+    while parser.peek().is_some() {
+        let start = parser.position;
+
+        match parser.ident()?.as_str() {
+            "syntax" => {
+                parser.symbol('=')?;
+                let syntax = parser.string()?;
+                if syntax != "proto3" {
+                    return Err(format!("unsupported syntax '{syntax}'").into());
+                }
+                parser.symbol(';')?;
+                file.syntax = Some(syntax);
+            }
+            "package" => {
+                file.package = Some(parser.ident()?);
+                parser.symbol(';')?;
+            }
+            "import" => {
+                parser.eat_ident("public");
+                parser.eat_ident("weak");
+                file.dependency.push(parser.string()?);
+                parser.symbol(';')?;
+            }
+            "option" | "extend" => parser.skip_statement()?,
+            "message" => {
+                let path = vec![FILE_MESSAGE, file.message_type.len() as i32];
+                file.message_type.push(parser.message(start, path)?);
+            }
+            "enum" => {
+                let path = vec![FILE_ENUM, file.enum_type.len() as i32];
+                file.enum_type.push(parser.enumeration(start, path)?);
+            }
+            "service" => {
+                let path = vec![FILE_SERVICE, file.service.len() as i32];
+                file.service.push(parser.service(start, path)?);
+            }
+            other => return Err(parser.error(format!("unexpected '{other}'"))),
+        }
+
+        parser.eat_symbol(';');
+    }
+
+    file.syntax.get_or_insert_with(|| String::from("proto3"));
+    file.source_code_info = Some(SourceCodeInfo {
+        location: parser.locations,
+    });
+
+    Ok(file)
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position).map(|spanned| &spanned.token)
+    }
+
+    fn next(&mut self) -> Result<Token> {
+        let token = self
+            .tokens
+            .get(self.position)
+            .map(|spanned| spanned.token.clone())
+            .ok_or("unexpected end of file")?;
+
+        self.position += 1;
+
+        Ok(token)
+    }
+
+    fn error(&self, message: String) -> Box<dyn std::error::Error> {
+        let line = self
+            .tokens
+            .get(self.position.saturating_sub(1))
+            .map_or(0, |spanned| spanned.line);
+
+        format!("line {line}: {message}").into()
+    }
+
+    fn ident(&mut self) -> Result<String> {
+        match self.next()? {
+            Token::Ident(ident) => Ok(ident),
+            other => Err(self.error(format!("expected identifier, found {other:?}"))),
+        }
+    }
+
+    fn string(&mut self) -> Result<String> {
+        match self.next()? {
+            Token::Str(value) => Ok(value),
+            other => Err(self.error(format!("expected string, found {other:?}"))),
+        }
+    }
+
+    fn int(&mut self) -> Result<i32> {
+        match self.next()? {
+            Token::Int(value) => i32::try_from(value)
+                .map_err(|_| self.error(format!("number {value} is out of range"))),
+            other => Err(self.error(format!("expected number, found {other:?}"))),
+        }
+    }
+
+    fn symbol(&mut self, symbol: char) -> Result<()> {
+        match self.next()? {
+            Token::Symbol(c) if c == symbol => Ok(()),
+            other => Err(self.error(format!("expected '{symbol}', found {other:?}"))),
+        }
+    }
+
+    fn eat_symbol(&mut self, symbol: char) -> bool {
+        let matches = self.peek() == Some(&Token::Symbol(symbol));
+        if matches {
+            self.position += 1;
+        }
+
+        matches
+    }
+
+    fn eat_ident(&mut self, ident: &str) -> bool {
+        let matches = matches!(self.peek(), Some(Token::Ident(value)) if value == ident);
+        if matches {
+            self.position += 1;
+        }
+
+        matches
+    }
+
+    /// Skips tokens up to `;` or balanced `{ .. }` block
+    fn skip_statement(&mut self) -> Result<()> {
+        let mut depth = 0;
+
+        loop {
+            match self.next()? {
+                Token::Symbol('{') => depth += 1,
+                Token::Symbol('}') => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Ok(());
+                    }
+                }
+                Token::Symbol(';') if depth == 0 => return Ok(()),
+                _ => {}
+            }
+        }
+    }
+
+    /// Parses `[name = value, ..]`, returns `true` for `deprecated = true`
+    fn field_options(&mut self) -> Result<bool> {
+        let mut deprecated = false;
+
+        if !self.eat_symbol('[') {
+            return Ok(false);
+        }
+
+        loop {
+            let name = match self.next()? {
+                Token::Ident(name) => name,
+                // Custom option, e.g. `(google.api.field_behavior)`
+                Token::Symbol('(') => {
+                    let name = self.ident()?;
+                    self.symbol(')')?;
+                    self.eat_ident_suffix();
+                    name
+                }
+                other => return Err(self.error(format!("expected option, found {other:?}"))),
+            };
+
+            self.symbol('=')?;
+            let value = self.option_value()?;
+            deprecated |= name == "deprecated" && value == "true";
+
+            if !self.eat_symbol(',') {
+                break;
+            }
+        }
+
+        self.symbol(']')?;
+
+        Ok(deprecated)
+    }
+
+    /// Skips `.field` after custom option name
+    fn eat_ident_suffix(&mut self) {
+        if let Some(Token::Ident(suffix)) = self.peek() {
+            if suffix.starts_with('.') {
+                self.position += 1;
+            }
+        }
+    }
+
+    /// Returns option value as text, aggregate values are skipped
+    fn option_value(&mut self) -> Result<String> {
+        if self.peek() == Some(&Token::Symbol('{')) {
+            let mut depth = 0;
+
+            loop {
+                match self.next()? {
+                    Token::Symbol('{') => depth += 1,
+                    Token::Symbol('}') => {
+                        depth -= 1;
+                        if depth == 0 {
+                            return Ok(String::new());
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        Ok(match self.next()? {
+            Token::Ident(value) | Token::Str(value) => value,
+            Token::Int(value) => value.to_string(),
+            Token::Symbol(c) => c.to_string(),
+        })
+    }
+
+    /// Parses `option name = value;` inside block, returns `true` for `deprecated = true`
+    fn block_option(&mut self) -> Result<bool> {
+        let name = if self.eat_symbol('(') {
+            let name = self.ident()?;
+            self.symbol(')')?;
+            self.eat_ident_suffix();
+            name
+        } else {
+            self.ident()?
+        };
+
+        self.symbol('=')?;
+        let value = self.option_value()?;
+        self.symbol(';')?;
+
+        Ok(name == "deprecated" && value == "true")
+    }
+
+    /// Records comments of declaration from token `start` to the last parsed token
+    fn locate(&mut self, path: Vec<i32>, start: usize, end: usize) {
+        let line = |index: usize| self.tokens.get(index).map_or(0, |spanned| spanned.line);
+
+        self.locations.push(Location {
+            leading_comments: self.comments.leading.get(&line(start)).cloned(),
+            trailing_comments: self.comments.trailing.get(&line(end)).cloned(),
+            path,
+            ..Default::default()
+        });
+    }
+
+    /// Parses message after `message` keyword
+    fn message(&mut self, start: usize, path: Vec<i32>) -> Result<DescriptorProto> {
+        let mut message = DescriptorProto {
+            name: Some(self.ident()?),
+            ..Default::default()
+        };
+
+        self.symbol('{')?;
+        self.locate(path.clone(), start, self.position - 1);
+
+        let nested_path = |kind: i32, index: usize| {
+            let mut nested = path.clone();
+            nested.extend([kind, index as i32]);
+            nested
+        };
+
+        // Synthetic oneofs of `optional` fields go after declared ones
+        let mut optional = Vec::new();
+
+        while !self.eat_symbol('}') {
+            let start = self.position;
+
+            if self.eat_symbol(';') {
+                continue;
+            }
+
+            match self.ident()?.as_str() {
+                "message" => {
+                    let path = nested_path(MESSAGE_NESTED, message.nested_type.len());
+                    message.nested_type.push(self.message(start, path)?);
+                }
+                "enum" => {
+                    let path = nested_path(MESSAGE_ENUM, message.enum_type.len());
+                    message.enum_type.push(self.enumeration(start, path)?);
+                }
+                "oneof" => {
+                    let index = message.oneof_decl.len() as i32;
+                    message.oneof_decl.push(OneofDescriptorProto {
+                        name: Some(self.ident()?),
+                        ..Default::default()
+                    });
+
+                    self.symbol('{')?;
+                    self.locate(
+                        nested_path(MESSAGE_ONEOF, index as usize),
+                        start,
+                        self.position - 1,
+                    );
+
+                    while !self.eat_symbol('}') {
+                        let start = self.position;
+
+                        if self.eat_ident("option") {
+                            self.block_option()?;
+                            continue;
+                        }
+
+                        let mut field = self.field(Label::Optional)?;
+                        field.oneof_index = Some(index);
+
+                        self.locate(
+                            nested_path(MESSAGE_FIELD, message.field.len()),
+                            start,
+                            self.position - 1,
+                        );
+                        message.field.push(field);
+                    }
+                }
+                "option" => {
+                    if self.block_option()? {
+                        message
+                            .options
+                            .get_or_insert_with(MessageOptions::default)
+                            .deprecated = Some(true);
+                    }
+                }
+                "reserved" | "extensions" => self.skip_statement()?,
+                "map" => return Err(self.error(String::from("map fields are not supported"))),
+                label => {
+                    let field = match label {
+                        "repeated" => self.field(Label::Repeated)?,
+                        "optional" => {
+                            let mut field = self.field(Label::Optional)?;
+                            field.proto3_optional = Some(true);
+                            optional.push(message.field.len());
+                            field
+                        }
+                        _ => {
+                            // Field without label, type was taken as label
+                            self.position -= 1;
+                            self.field(Label::Optional)?
+                        }
+                    };
+
+                    self.locate(
+                        nested_path(MESSAGE_FIELD, message.field.len()),
+                        start,
+                        self.position - 1,
+                    );
+                    message.field.push(field);
+                }
+            }
+        }
+
+        for index in optional {
+            let field = &mut message.field[index];
+
+            field.oneof_index = Some(message.oneof_decl.len() as i32);
+            message.oneof_decl.push(OneofDescriptorProto {
+                name: Some(format!("_{}", field.name())),
+                ..Default::default()
+            });
+        }
+
+        Ok(message)
+    }
+
+    /// Parses `type name = number [options];`, type is resolved later
+    fn field(&mut self, label: Label) -> Result<FieldDescriptorProto> {
+        let type_name = self.ident()?;
+        let name = self.ident()?;
+        self.symbol('=')?;
+        let number = self.int()?;
+        let deprecated = self.field_options()?;
+        self.symbol(';')?;
+
+        let mut field = FieldDescriptorProto {
+            json_name: Some(json_name(&name)),
+            name: Some(name),
+            number: Some(number),
+            label: Some(label as i32),
+            ..Default::default()
+        };
+
+        match scalar(&type_name) {
+            Some(r#type) => field.r#type = Some(r#type as i32),
+            None => field.type_name = Some(type_name),
+        }
+
+        if deprecated {
+            field.options = Some(FieldOptions {
+                deprecated: Some(true),
+                ..Default::default()
+            });
+        }
+
+        Ok(field)
+    }
+
+    /// Parses enum after `enum` keyword
+    fn enumeration(&mut self, start: usize, path: Vec<i32>) -> Result<EnumDescriptorProto> {
+        let mut enumeration = EnumDescriptorProto {
+            name: Some(self.ident()?),
+            ..Default::default()
+        };
+
+        self.symbol('{')?;
+        self.locate(path.clone(), start, self.position - 1);
+
+        while !self.eat_symbol('}') {
+            let start = self.position;
+
+            if self.eat_symbol(';') {
+                continue;
+            }
+
+            match self.ident()?.as_str() {
+                "option" => {
+                    if self.block_option()? {
+                        enumeration
+                            .options
+                            .get_or_insert_with(EnumOptions::default)
+                            .deprecated = Some(true);
+                    }
+                }
+                "reserved" => self.skip_statement()?,
+                name => {
+                    let name = name.to_string();
+                    self.symbol('=')?;
+                    let number = self.int()?;
+                    let deprecated = self.field_options()?;
+                    self.symbol(';')?;
+
+                    let mut value_path = path.clone();
+                    value_path.extend([ENUM_VALUE, enumeration.value.len() as i32]);
+                    self.locate(value_path, start, self.position - 1);
+
+                    enumeration.value.push(EnumValueDescriptorProto {
+                        name: Some(name),
+                        number: Some(number),
+                        options: deprecated.then(|| EnumValueOptions {
+                            deprecated: Some(true),
+                            ..Default::default()
+                        }),
+                    });
+                }
+            }
+        }
+
+        Ok(enumeration)
+    }
+
+    /// Parses service after `service` keyword
+    fn service(&mut self, start: usize, path: Vec<i32>) -> Result<ServiceDescriptorProto> {
+        let mut service = ServiceDescriptorProto {
+            name: Some(self.ident()?),
+            ..Default::default()
+        };
+
+        self.symbol('{')?;
+        self.locate(path.clone(), start, self.position - 1);
+
+        while !self.eat_symbol('}') {
+            let start = self.position;
+
+            if self.eat_symbol(';') {
+                continue;
+            }
+
+            match self.ident()?.as_str() {
+                "option" => {
+                    if self.block_option()? {
+                        service
+                            .options
+                            .get_or_insert_with(ServiceOptions::default)
+                            .deprecated = Some(true);
+                    }
+                }
+                "rpc" => {
+                    let mut method = MethodDescriptorProto {
+                        name: Some(self.ident()?),
+                        ..Default::default()
+                    };
+
+                    self.symbol('(')?;
+                    method.client_streaming = Some(self.eat_ident("stream"));
+                    method.input_type = Some(self.ident()?);
+                    self.symbol(')')?;
+
+                    if self.ident()? != "returns" {
+                        return Err(self.error(String::from("expected 'returns'")));
+                    }
+
+                    self.symbol('(')?;
+                    method.server_streaming = Some(self.eat_ident("stream"));
+                    method.output_type = Some(self.ident()?);
+                    self.symbol(')')?;
+
+                    let mut method_path = path.clone();
+                    method_path.extend([SERVICE_METHOD, service.method.len() as i32]);
+
+                    if self.eat_symbol('{') {
+                        self.locate(method_path, start, self.position - 1);
+
+                        while !self.eat_symbol('}') {
+                            if self.eat_symbol(';') {
+                                continue;
+                            }
+
+                            if self.ident()? != "option" {
+                                return Err(self.error(String::from("expected 'option'")));
+                            }
+
+                            if self.block_option()? {
+                                method
+                                    .options
+                                    .get_or_insert_with(MethodOptions::default)
+                                    .deprecated = Some(true);
+                            }
+                        }
+                    } else {
+                        self.symbol(';')?;
+                        self.locate(method_path, start, self.position - 1);
+                    }
+
+                    service.method.push(method);
+                }
+                other => return Err(self.error(format!("unexpected '{other}'"))),
+            }
+        }
+
+        Ok(service)
+    }
+}
+
+/// Returns scalar type by name, `None` for message and enum types
+fn scalar(name: &str) -> Option<Type> {
+    Some(match name {
+        "double" => Type::Double,
+        "float" => Type::Float,
+        "int64" => Type::Int64,
+        "uint64" => Type::Uint64,
+        "int32" => Type::Int32,
+        "fixed64" => Type::Fixed64,
+        "fixed32" => Type::Fixed32,
+        "bool" => Type::Bool,
+        "string" => Type::String,
+        "bytes" => Type::Bytes,
+        "uint32" => Type::Uint32,
+        "sfixed32" => Type::Sfixed32,
+        "sfixed64" => Type::Sfixed64,
+        "sint32" => Type::Sint32,
+        "sint64" => Type::Sint64,
+        _ => return None,
+    })
+}
+
+/// Returns lowerCamelCase JSON name of field, as `protoc` does
+fn json_name(name: &str) -> String {
+    let mut json = String::with_capacity(name.len());
+    let mut upper = false;
+
+    for c in name.chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            json.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            json.push(c);
+        }
+    }
+
+    json
+}
+
+// endregion: --- Parser
+
+// region:    --- Types
+
+/// Replaces type names of fields and methods with fully qualified ones
+fn resolve_types(set: &mut FileDescriptorSet) -> Result<()> {
+    let mut types = HashMap::new();
+
+    for file in &set.file {
+        let package = format!(".{}", file.package());
+
+        for message in &file.message_type {
+            collect_message(&package, message, &mut types);
+        }
+        for enumeration in &file.enum_type {
+            types.insert(format!("{package}.{}", enumeration.name()), Type::Enum);
+        }
+    }
+
+    for file in &mut set.file {
+        let package = format!(".{}", file.package());
+        let name = file.name().to_string();
+
+        for message in &mut file.message_type {
+            resolve_message(&package, message, &types).map_err(|err| format!("{name}: {err}"))?;
+        }
+
+        for service in &mut file.service {
+            for method in &mut service.method {
+                for type_name in [&mut method.input_type, &mut method.output_type] {
+                    let (resolved, _) =
+                        resolve(&package, type_name.as_deref().unwrap_or_default(), &types)
+                            .map_err(|err| format!("{name}: {err}"))?;
+                    *type_name = Some(resolved);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn collect_message(scope: &str, message: &DescriptorProto, types: &mut HashMap<String, Type>) {
+    let name = format!("{scope}.{}", message.name());
+
+    for nested in &message.nested_type {
+        collect_message(&name, nested, types);
+    }
+    for enumeration in &message.enum_type {
+        types.insert(format!("{name}.{}", enumeration.name()), Type::Enum);
+    }
+
+    types.insert(name, Type::Message);
+}
+
+fn resolve_message(
+    scope: &str,
+    message: &mut DescriptorProto,
+    types: &HashMap<String, Type>,
+) -> Result<()> {
+    let name = format!("{scope}.{}", message.name());
+
+    for field in &mut message.field {
+        if let Some(type_name) = &field.type_name {
+            let (resolved, r#type) = resolve(&name, type_name, types)?;
+
+            field.type_name = Some(resolved);
+            field.r#type = Some(r#type as i32);
+        }
+    }
+
+    for nested in &mut message.nested_type {
+        resolve_message(&name, nested, types)?;
+    }
+
+    Ok(())
+}
+
+/// Looks type up from innermost scope outwards
+fn resolve(scope: &str, name: &str, types: &HashMap<String, Type>) -> Result<(String, Type)> {
+    if name.starts_with('.') {
+        return types
+            .get(name)
+            .map(|r#type| (name.to_string(), *r#type))
+            .ok_or_else(|| format!("unknown type '{name}'").into());
+    }
+
+    let mut scope = scope;
+
+    loop {
+        let candidate = format!("{scope}.{name}");
+
+        if let Some(r#type) = types.get(&candidate) {
+            return Ok((candidate, *r#type));
+        }
+
+        match scope.rfind('.') {
+            Some(position) => scope = &scope[..position],
+            None => return Err(format!("unknown type '{name}'").into()),
+        }
+    }
+}
+
+// endregion: --- Types
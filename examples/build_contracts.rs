@@ -1,29 +1,28 @@
-use std::fs;
+//! Regenerates pre-generated `src/api/v1/protos.rs` from contracts in `contracts/`,
+//! used instead of generated code when `TINKOFF_PREGENERATED_PROTOS` is set.
+
+#[path = "../build/contracts.rs"]
+mod contracts;
+
+use std::{fs, path::Path};
+
+const OUT_DIR: &str = "src/api/v1";
+const CONTRACTS_DIR: &str = "contracts";
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    tonic_build::configure()
-        .build_client(true)
-        .build_server(false)
-        .out_dir("src/api/v1")
-        .compile_protos(
-            &[
-                "common.proto",
-                "instruments.proto",
-                "marketdata.proto",
-                "operations.proto",
-                "orders.proto",
-                "sandbox.proto",
-                "signals.proto",
-                "stoporders.proto",
-                "users.proto",
-            ],
-            &["investAPI/src/docs/contracts/"],
-        )?;
-
-    fs::rename(
-        "src/api/v1/tinkoff.public.invest.api.contract.v1.rs",
-        "src/api/v1/protos.rs",
-    )?;
+    let out_dir = Path::new(OUT_DIR);
+
+    // Pass `--annotate-only` to post-process already generated protos
+    if std::env::args().any(|arg| arg == "--annotate-only") {
+        let path = out_dir.join(contracts::PROTOS_FILE);
+        let protos = fs::read_to_string(&path)?;
+
+        fs::write(path, contracts::post_process(&protos))?;
+    } else {
+        let descriptor = contracts::descriptor(Path::new(CONTRACTS_DIR))?;
+
+        contracts::generate_protos(&descriptor, out_dir)?;
+    }
 
     Ok(())
 }
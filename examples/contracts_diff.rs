@@ -0,0 +1,36 @@
+//! Compares two contract revisions before upgrading
+//!
+//! ```sh
+//! cargo run --example contracts_diff -- contracts investAPI/src/docs/contracts
+//! ```
+
+#[path = "../build/contracts.rs"]
+mod contracts;
+
+use investments_tinkoff::api::compatibility::ContractDiff;
+use std::{env, path::Path};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut args = env::args().skip(1);
+
+    let (Some(old), Some(new)) = (args.next(), args.next()) else {
+        return Err("Usage: contracts_diff <old contracts dir> <new contracts dir>".into());
+    };
+
+    let old = contracts::descriptor(Path::new(&old))?;
+    let new = contracts::descriptor(Path::new(&new))?;
+
+    let diff = ContractDiff::between(&old, &new);
+
+    if diff.is_empty() {
+        println!("Contracts are identical");
+    } else {
+        print!("{diff}");
+    }
+
+    if diff.is_breaking() {
+        println!("Upgrade is breaking");
+    }
+
+    Ok(())
+}
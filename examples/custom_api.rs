@@ -1,8 +1,11 @@
+#![allow(clippy::result_large_err)]
+
 use investments_tinkoff::api::v1::{
     GetAccountsRequest, InterceptorData, InvestApiTrait, TinkoffInterceptor,
 };
 use investments_tinkoff::channel::ChannelBuilder;
-use investments_tinkoff::{config, Result};
+use investments_tinkoff::{config, Result, SecretToken};
+use std::sync::Arc;
 
 // region:    --- Custom Api
 
@@ -12,6 +15,11 @@ pub struct CustomApi {
 }
 
 impl CustomApi {
+    /// Creates new CustomApi with token from config
+    pub fn new() -> Result<Self> {
+        Ok(Self::with_token(config()?.token.expose()))
+    }
+
     /// Sets application name
     pub fn with_app_name(mut self, app_name: impl Into<String>) -> Self {
         self.app_name = Some(app_name.into());
@@ -19,13 +27,6 @@ impl CustomApi {
     }
 }
 
-impl Default for CustomApi {
-    /// Creates new CustomApi with default token from config
-    fn default() -> Self {
-        Self::with_token(&config().TINKOFF_TOKEN)
-    }
-}
-
 impl InvestApiTrait<InterceptorData, TinkoffInterceptor> for CustomApi {
     // region:    --- Constructors
 
@@ -43,7 +44,7 @@ impl InvestApiTrait<InterceptorData, TinkoffInterceptor> for CustomApi {
 
     fn interceptor_data(&self) -> InterceptorData {
         InterceptorData {
-            token: self.token.clone(),
+            token: Arc::new(SecretToken::new(self.token.clone())),
             app_name: self.app_name.clone(),
         }
     }
@@ -56,7 +57,7 @@ impl InvestApiTrait<InterceptorData, TinkoffInterceptor> for CustomApi {
 #[tokio::main]
 async fn main() -> Result<()> {
     // -- Create api
-    let api = CustomApi::default().with_app_name("Grapple228.rust-investments-tinkoff");
+    let api = CustomApi::new()?.with_app_name("Grapple228.rust-investments-tinkoff");
 
     // -- Create channel
     let channel = ChannelBuilder::default()?.connect().await?;
@@ -1,17 +1,18 @@
-use investments_tinkoff::api::v1::{GetAccountsRequest, InvestApiTrait};
+#![allow(clippy::result_large_err)]
+
+use investments_tinkoff::api::v1::{
+    GetAccountsRequest, InvestApi, InvestApiTrait, TracingInterceptor,
+};
 use investments_tinkoff::channel::ChannelBuilder;
-use investments_tinkoff::{api::v1::IntercemptorWithNew, extensions::MetadataExt};
-use investments_tinkoff::{config, Result};
+use investments_tinkoff::{extensions::MetadataExt, Result};
 use tonic::service::Interceptor;
 
 // region:    --- Custom Interceptor
 
-pub struct CustomInterceptorData {
-    token: String,
-}
-
+/// Adds own header, auth and request id are set by interceptors added before it
+#[derive(Clone)]
 pub struct CustomInterceptor {
-    data: CustomInterceptorData,
+    client_id: String,
 }
 
 impl Interceptor for CustomInterceptor {
@@ -24,66 +25,24 @@ impl Interceptor for CustomInterceptor {
 
         // Do something with request
 
-        metadata.safe_append(
-            "authorization",
-            format!("Bearer {}", self.data.token),
-            "Failed to insert token",
-        );
+        metadata.safe_append("x-client-id", &self.client_id, "Failed to insert client id");
 
         Ok(request)
     }
 }
 
-impl IntercemptorWithNew<CustomInterceptorData> for CustomInterceptor {
-    fn new(data: CustomInterceptorData) -> Self {
-        Self { data }
-    }
-}
-
 // endregion: --- Custom Interceptor
 
-// region:    --- Custom Api
-
-pub struct CustomApi {
-    token: String,
-}
-
-impl Default for CustomApi {
-    /// Creates new CustomApi with default token from config
-    fn default() -> Self {
-        Self::with_token(&config().TINKOFF_TOKEN)
-    }
-}
-
-impl InvestApiTrait<CustomInterceptorData, CustomInterceptor> for CustomApi {
-    // region:    --- Constructors
-
-    /// Creates new CustomApi with token
-    fn with_token(token: impl Into<String>) -> Self {
-        Self {
-            token: token.into(),
-        }
-    }
-
-    // endregion: --- Constructors
-
-    // region:    --- Getters
-
-    fn interceptor_data(&self) -> CustomInterceptorData {
-        CustomInterceptorData {
-            token: self.token.clone(),
-        }
-    }
-
-    // endregion: --- Getters
-}
-
-// endregion: --- Custom Api
-
 #[tokio::main]
 async fn main() -> Result<()> {
-    // -- Create api
-    let api = CustomApi::default();
+    // -- Create api with auth, request id and app name interceptors, then add own ones
+    let api = InvestApi::new()?
+        .with_app_name("Grapple228.rust-investments-tinkoff")
+        .layered()
+        .with(TracingInterceptor)
+        .with(CustomInterceptor {
+            client_id: "example".to_string(),
+        });
 
     // -- Create channel
     let channel = ChannelBuilder::default()?.connect().await?;
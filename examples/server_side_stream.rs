@@ -1,3 +1,5 @@
+#![allow(clippy::result_large_err)]
+
 use investments_tinkoff::{
     api::v1::{
         CandleInstrument, InvestApi, InvestApiTrait, MarketDataServerSideStreamRequest,
@@ -10,7 +12,7 @@ use investments_tinkoff::{
 #[tokio::main]
 async fn main() -> Result<()> {
     // -- Create api
-    let api = InvestApi::default().with_app_name("Grapple228.rust-investments-tinkoff");
+    let api = InvestApi::new()?.with_app_name("Grapple228.rust-investments-tinkoff");
 
     // -- Create channel
     let channel = ChannelBuilder::default()?.connect().await?;
@@ -20,20 +22,21 @@ async fn main() -> Result<()> {
 
     // -- Create request
 
-    let request = SubscribeCandlesRequest {
-        subscription_action: SubscriptionAction::Subscribe as i32,
-
-        #[allow(deprecated)]
-        instruments: vec![CandleInstrument {
-            // figi is deprecated, so just pass empty string
-            figi: String::new(),
-            interval: SubscriptionInterval::OneMinute as i32,
-            instrument_id: "BBG000B9XRY4".to_string(),
-        }],
+    #[allow(deprecated)]
+    let instrument = CandleInstrument {
+        // figi is deprecated, so just pass empty string
+        figi: String::new(),
+        instrument_id: "BBG000B9XRY4".to_string(),
+        ..Default::default()
+    }
+    .with_interval(SubscriptionInterval::OneMinute);
 
+    let request = SubscribeCandlesRequest {
+        instruments: vec![instrument],
         waiting_close: true,
-        candle_source_type: None,
-    };
+        ..Default::default()
+    }
+    .with_subscription_action(SubscriptionAction::Subscribe);
 
     let request = MarketDataServerSideStreamRequest {
         subscribe_candles_request: Some(request),
@@ -1,3 +1,5 @@
+#![allow(clippy::result_large_err)]
+
 use investments_tinkoff::{
     api::v1::{GetAccountsRequest, InvestApi, InvestApiTrait},
     channel::ChannelBuilder,
@@ -7,7 +9,7 @@ use investments_tinkoff::{
 #[tokio::main]
 async fn main() -> Result<()> {
     // -- Create api
-    let api = InvestApi::default().with_app_name("Grapple228.rust-investments-tinkoff");
+    let api = InvestApi::new()?.with_app_name("Grapple228.rust-investments-tinkoff");
 
     // -- Create channel
     let channel = ChannelBuilder::default()?.connect().await?;
@@ -0,0 +1,191 @@
+//! Instrument data joined with positions and operations
+
+use crate::api::v1::{Bond, Currency, Etf, Future, Instrument, MoneyValue, Quotation, Share};
+use std::collections::HashMap;
+
+/// Instrument attributes used by analytics
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InstrumentInfo {
+    pub uid: String,
+    pub figi: String,
+    pub ticker: String,
+    pub name: String,
+    /// `share`, `bond`, `etf`, `currency` or `futures`
+    pub instrument_type: String,
+    /// Trading currency, lowercase
+    pub currency: String,
+    /// Currency the instrument is exposed to, ISO name for currencies, trading currency otherwise
+    pub exposure_currency: String,
+    pub sector: String,
+    /// Country of risk as ISO code
+    pub country: String,
+    /// Number of instruments in lot
+    pub lot: i32,
+    /// Price step, zero if unknown
+    pub min_price_increment: f64,
+    /// Amount of price step in currency for futures priced in points, zero if unknown
+    pub min_price_increment_amount: f64,
+    /// Nominal of bond in its currency, zero for other instruments or if unknown
+    pub nominal: f64,
+}
+
+impl InstrumentInfo {
+    /// Converts futures price in points to currency by amount of price step, `None` if it is unknown
+    pub fn points_to_amount(&self, points: f64) -> Option<f64> {
+        (self.min_price_increment > 0.0 && self.min_price_increment_amount > 0.0)
+            .then(|| points / self.min_price_increment * self.min_price_increment_amount)
+    }
+}
+
+// region:    --- Conversions
+
+macro_rules! impl_from_instrument {
+    ($message:ident, $instrument_type:literal) => {
+        impl_from_instrument!($message, $instrument_type, |_| 0.0, |_| 0.0);
+    };
+    ($message:ident, $instrument_type:literal, $increment_amount:expr, $nominal:expr) => {
+        impl From<$message> for InstrumentInfo {
+            fn from(instrument: $message) -> Self {
+                let currency = instrument.currency.to_lowercase();
+                let min_price_increment_amount = ($increment_amount)(&instrument);
+                let nominal = ($nominal)(&instrument);
+
+                Self {
+                    uid: instrument.uid,
+                    figi: instrument.figi,
+                    ticker: instrument.ticker,
+                    name: instrument.name,
+                    instrument_type: $instrument_type.to_string(),
+                    exposure_currency: currency.clone(),
+                    currency,
+                    sector: instrument.sector,
+                    country: instrument.country_of_risk,
+                    lot: instrument.lot,
+                    min_price_increment: increment(&instrument.min_price_increment),
+                    min_price_increment_amount,
+                    nominal,
+                }
+            }
+        }
+    };
+}
+
+impl_from_instrument!(Share, "share");
+impl_from_instrument!(Bond, "bond", |_| 0.0, |bond: &Bond| {
+    bond.nominal.as_ref().map_or(0.0, MoneyValue::to_f64)
+});
+impl_from_instrument!(Etf, "etf");
+impl_from_instrument!(
+    Future,
+    "futures",
+    |future: &Future| increment(&future.min_price_increment_amount),
+    |_| 0.0
+);
+
+impl From<Currency> for InstrumentInfo {
+    fn from(instrument: Currency) -> Self {
+        Self {
+            uid: instrument.uid,
+            figi: instrument.figi,
+            ticker: instrument.ticker,
+            name: instrument.name,
+            instrument_type: "currency".to_string(),
+            currency: instrument.currency.to_lowercase(),
+            exposure_currency: instrument.iso_currency_name.to_lowercase(),
+            sector: String::new(),
+            country: instrument.country_of_risk,
+            lot: instrument.lot,
+            min_price_increment: increment(&instrument.min_price_increment),
+            min_price_increment_amount: 0.0,
+            nominal: 0.0,
+        }
+    }
+}
+
+/// `Instrument` has no sector, nominal and price step amount
+impl From<Instrument> for InstrumentInfo {
+    fn from(instrument: Instrument) -> Self {
+        let currency = instrument.currency.to_lowercase();
+
+        Self {
+            uid: instrument.uid,
+            figi: instrument.figi,
+            ticker: instrument.ticker,
+            name: instrument.name,
+            instrument_type: instrument.instrument_type,
+            exposure_currency: currency.clone(),
+            currency,
+            sector: String::new(),
+            country: instrument.country_of_risk,
+            lot: instrument.lot,
+            min_price_increment: increment(&instrument.min_price_increment),
+            min_price_increment_amount: 0.0,
+            nominal: 0.0,
+        }
+    }
+}
+
+fn increment(value: &Option<Quotation>) -> f64 {
+    value.as_ref().map_or(0.0, Quotation::to_f64)
+}
+
+// endregion: --- Conversions
+
+// region:    --- Catalog
+
+/// Instruments by uid and figi, e.g. from `shares`, `bonds`, `etfs` and `currencies` responses
+#[derive(Debug, Clone, Default)]
+pub struct InstrumentCatalog {
+    instruments: HashMap<String, InstrumentInfo>,
+    figis: HashMap<String, String>,
+}
+
+impl InstrumentCatalog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds instrument, replacing one with the same uid
+    pub fn insert(&mut self, instrument: impl Into<InstrumentInfo>) {
+        let instrument = instrument.into();
+
+        if !instrument.figi.is_empty() {
+            self.figis
+                .insert(instrument.figi.clone(), instrument.uid.clone());
+        }
+        self.instruments.insert(instrument.uid.clone(), instrument);
+    }
+
+    /// Adds instruments, e.g. `catalog.extend(shares.instruments)`
+    pub fn extend<I>(&mut self, instruments: impl IntoIterator<Item = I>)
+    where
+        I: Into<InstrumentInfo>,
+    {
+        for instrument in instruments {
+            self.insert(instrument);
+        }
+    }
+
+    pub fn get(&self, uid: &str) -> Option<&InstrumentInfo> {
+        self.instruments.get(uid)
+    }
+
+    pub fn by_figi(&self, figi: &str) -> Option<&InstrumentInfo> {
+        self.figis.get(figi).and_then(|uid| self.get(uid))
+    }
+
+    /// Looks up by uid, then by figi
+    pub fn find(&self, uid: &str, figi: &str) -> Option<&InstrumentInfo> {
+        self.get(uid).or_else(|| self.by_figi(figi))
+    }
+
+    pub fn len(&self) -> usize {
+        self.instruments.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.instruments.is_empty()
+    }
+}
+
+// endregion: --- Catalog
@@ -0,0 +1,786 @@
+//! Realized PnL by tax lots from operations history
+//!
+//! Operations from `get_operations_by_cursor` are replayed in date order, buys open lots and sells
+//! close them with FIFO, LIFO or average cost. Sells without open lots open short lots.
+//! Amounts are summed exactly in nano units, `Quotation::to_f64` can be used for presentation.
+
+use crate::api::v1::{
+    EnumValue, MoneyValue, OperationItem, OperationState, OperationType, Quotation,
+};
+use crate::{DateTime, TimeZone};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+// region:    --- Types
+
+/// Order of closing lots
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum LotMethod {
+    /// Oldest lots are closed first
+    #[default]
+    Fifo,
+    /// Newest lots are closed first
+    Lifo,
+    /// Lots are merged into one with average cost
+    Average,
+}
+
+/// Open position part, amounts are in position currency
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpenLot {
+    pub instrument_uid: String,
+    pub figi: String,
+    pub currency: String,
+    pub opened_at: DateTime,
+    /// Negative for short lots
+    pub quantity: i64,
+    /// Amount paid for long lot or received for short lot
+    pub amount: Quotation,
+    pub commission: Quotation,
+}
+
+/// Lot or its part closed by trade
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClosedLot {
+    pub instrument_uid: String,
+    pub figi: String,
+    pub currency: String,
+    pub opened_at: DateTime,
+    pub closed_at: DateTime,
+    pub quantity: i64,
+    pub short: bool,
+    /// Amount of opening trade for closed quantity
+    pub open_amount: Quotation,
+    /// Amount of closing trade for closed quantity
+    pub close_amount: Quotation,
+    /// Commissions of opening and closing trades for closed quantity
+    pub commission: Quotation,
+    pub realized_pnl: Quotation,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IncomeKind {
+    Dividend,
+    Coupon,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Income {
+    pub instrument_uid: String,
+    pub figi: String,
+    pub kind: IncomeKind,
+    pub date: DateTime,
+    pub currency: String,
+    pub amount: Quotation,
+}
+
+/// Totals per year, instrument and currency, account fees have empty `instrument_uid`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct YearlyGain {
+    pub year: i32,
+    pub instrument_uid: String,
+    pub currency: String,
+    pub realized_pnl: Quotation,
+    /// Commissions of closed lots, already included in `realized_pnl`, and account fees
+    pub commissions: Quotation,
+    pub dividends: Quotation,
+    pub coupons: Quotation,
+    /// Withheld taxes, refunds are negative
+    pub taxes: Quotation,
+}
+
+/// Result of `TaxLotEngine::report`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TaxLotReport {
+    pub closed_lots: Vec<ClosedLot>,
+    pub open_lots: Vec<OpenLot>,
+    pub incomes: Vec<Income>,
+    /// Sorted by year, instrument and currency
+    pub yearly: Vec<YearlyGain>,
+}
+
+// endregion: --- Types
+
+// region:    --- Engine
+
+/// Collects operations and computes closed lots and realized gains
+///
+/// Operations can be ingested page by page in any order, operation with the same id replaces
+/// earlier ingested one, e.g. when its date or state changed. Operations without id are
+/// always added.
+/// Only executed operations are used. Commissions are taken from `BrokerFee` operations
+/// of the trade, or from `commission` of the trade if there are none.
+#[derive(Debug, Clone)]
+pub struct TaxLotEngine {
+    method: LotMethod,
+    zone: TimeZone,
+    operations: HashMap<String, OperationItem>,
+    /// Operations without id, which cannot be deduplicated
+    unidentified: Vec<OperationItem>,
+}
+
+impl Default for TaxLotEngine {
+    fn default() -> Self {
+        Self::new(LotMethod::default())
+    }
+}
+
+impl TaxLotEngine {
+    /// Creates engine with years in Moscow time
+    pub fn new(method: LotMethod) -> Self {
+        Self {
+            method,
+            zone: TimeZone::Moscow,
+            operations: HashMap::new(),
+            unidentified: Vec::new(),
+        }
+    }
+
+    /// Sets time zone of tax years
+    pub fn with_time_zone(mut self, zone: TimeZone) -> Self {
+        self.zone = zone;
+        self
+    }
+
+    /// Adds operations, e.g. `items` of `GetOperationsByCursorResponse`
+    pub fn ingest<'a>(&mut self, operations: impl IntoIterator<Item = &'a OperationItem>) {
+        for operation in operations {
+            if operation.id.is_empty() {
+                self.unidentified.push(operation.clone());
+            } else {
+                self.operations
+                    .insert(operation.id.clone(), operation.clone());
+            }
+        }
+    }
+
+    /// Returns number of ingested operations
+    pub fn len(&self) -> usize {
+        self.operations.len() + self.unidentified.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty() && self.unidentified.is_empty()
+    }
+
+    /// Replays operations in date order
+    pub fn report(&self) -> TaxLotReport {
+        let mut executed: Vec<&OperationItem> = self
+            .operations
+            .values()
+            .chain(&self.unidentified)
+            .filter(|operation| EnumValue::from(operation.state) == OperationState::Executed)
+            .collect();
+        executed.sort_by_key(|operation| {
+            let date = operation.date.unwrap_or_default();
+            (date.seconds, date.nanos, &operation.id)
+        });
+
+        let mut fees: HashMap<&str, i128> = HashMap::new();
+        for operation in &executed {
+            if is_trade_fee(operation) {
+                *fees.entry(&operation.parent_operation_id).or_default() +=
+                    nanos(&operation.payment).abs();
+            }
+        }
+
+        let mut replay = Replay {
+            method: self.method,
+            zone: self.zone,
+            positions: BTreeMap::new(),
+            report: TaxLotReport::default(),
+            yearly: BTreeMap::new(),
+        };
+
+        for operation in executed {
+            let Some(kind) = EnumValue::<OperationType>::from(operation.r#type).known() else {
+                continue;
+            };
+            let commission = fees
+                .get(operation.id.as_str())
+                .copied()
+                .unwrap_or_else(|| nanos(&operation.commission).abs());
+
+            replay.apply(operation, kind, commission, is_trade_fee(operation));
+        }
+
+        replay.finish()
+    }
+}
+
+// endregion: --- Engine
+
+// region:    --- Replay
+
+#[derive(Debug, Clone)]
+struct Lot {
+    opened_at: DateTime,
+    quantity: i64,
+    amount: i128,
+    commission: i128,
+}
+
+#[derive(Debug, Default)]
+struct Position {
+    figi: String,
+    currency: String,
+    short: bool,
+    lots: VecDeque<Lot>,
+}
+
+impl Position {
+    fn quantity(&self) -> i64 {
+        self.lots.iter().map(|lot| lot.quantity).sum()
+    }
+}
+
+/// Trade side
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Side {
+    Buy,
+    Sell,
+}
+
+/// Trade with commission of executed quantity, amounts are in nano units
+struct Trade {
+    side: Side,
+    quantity: i64,
+    amount: i128,
+    commission: i128,
+    at: DateTime,
+}
+
+struct Replay {
+    method: LotMethod,
+    zone: TimeZone,
+    positions: BTreeMap<String, Position>,
+    report: TaxLotReport,
+    yearly: BTreeMap<(i32, String, String), Totals>,
+}
+
+/// Yearly totals in nano units
+#[derive(Debug, Default)]
+struct Totals {
+    realized_pnl: i128,
+    commissions: i128,
+    dividends: i128,
+    coupons: i128,
+    taxes: i128,
+}
+
+impl Replay {
+    fn apply(
+        &mut self,
+        operation: &OperationItem,
+        kind: OperationType,
+        commission: i128,
+        trade_fee: bool,
+    ) {
+        use OperationType as Op;
+
+        let at = DateTime::from_timestamp(operation.date.unwrap_or_default());
+        let key = instrument_key(operation);
+        let currency = currency(operation);
+        let payment = nanos(&operation.payment);
+
+        let quantity = if operation.quantity_done > 0 {
+            operation.quantity_done
+        } else {
+            operation.quantity - operation.quantity_rest
+        };
+        let trade_amount = if payment != 0 {
+            payment.abs()
+        } else {
+            nanos(&operation.price) * quantity as i128
+        };
+
+        match kind {
+            Op::Buy | Op::BuyCard | Op::BuyMargin | Op::DeliveryBuy | Op::InputSecurities => {
+                let trade = Trade {
+                    side: Side::Buy,
+                    quantity,
+                    amount: trade_amount,
+                    commission,
+                    at,
+                };
+                self.trade(operation, key, currency, trade);
+            }
+            Op::Sell | Op::SellCard | Op::SellMargin | Op::DeliverySell | Op::BondRepaymentFull => {
+                let trade = Trade {
+                    side: Side::Sell,
+                    quantity: self.sell_quantity(&key, kind, quantity),
+                    amount: trade_amount,
+                    commission,
+                    at,
+                };
+                self.trade(operation, key, currency, trade);
+            }
+            Op::OutputSecurities => self.transfer_out(&key, quantity),
+            Op::BondRepayment => self.amortize(&key, payment),
+            Op::Dividend | Op::DivExt | Op::Coupon => {
+                let kind = if kind == Op::Coupon {
+                    IncomeKind::Coupon
+                } else {
+                    IncomeKind::Dividend
+                };
+
+                let year = self.yearly_entry(&at, &key, &currency);
+                match kind {
+                    IncomeKind::Dividend => year.dividends += payment,
+                    IncomeKind::Coupon => year.coupons += payment,
+                }
+
+                self.report.incomes.push(Income {
+                    instrument_uid: key,
+                    figi: operation.figi.clone(),
+                    kind,
+                    date: at,
+                    currency,
+                    amount: Quotation::from_nanos(payment),
+                });
+            }
+            _ if is_tax(kind) => self.yearly_entry(&at, &key, &currency).taxes -= payment,
+            _ if is_fee(kind) && !trade_fee => {
+                self.yearly_entry(&at, &key, &currency).commissions += payment.abs()
+            }
+            _ => {}
+        }
+    }
+
+    /// Full bond repayment may come without quantity, all bonds are closed then
+    fn sell_quantity(&self, key: &str, kind: OperationType, quantity: i64) -> i64 {
+        match self.positions.get(key) {
+            Some(position) if kind == OperationType::BondRepaymentFull && quantity == 0 => {
+                position.quantity()
+            }
+            _ => quantity,
+        }
+    }
+
+    fn trade(&mut self, operation: &OperationItem, key: String, currency: String, trade: Trade) {
+        if trade.quantity <= 0 {
+            return;
+        }
+
+        let method = self.method;
+        let position = self.positions.entry(key.clone()).or_default();
+        position.figi.clone_from(&operation.figi);
+        if position.currency.is_empty() {
+            position.currency = currency;
+        }
+
+        let closes = !position.lots.is_empty() && position.short == (trade.side == Side::Buy);
+        let mut remaining = trade.quantity;
+        let mut closed = Vec::new();
+
+        if closes {
+            while remaining > 0 {
+                let lot = match method {
+                    LotMethod::Lifo => position.lots.back_mut(),
+                    LotMethod::Fifo | LotMethod::Average => position.lots.front_mut(),
+                };
+                let Some(lot) = lot else {
+                    break;
+                };
+
+                let quantity = remaining.min(lot.quantity);
+
+                let open_amount = share(lot.amount, quantity, lot.quantity);
+                let lot_commission = share(lot.commission, quantity, lot.quantity);
+                let close_amount = share(trade.amount, quantity, trade.quantity);
+                let commission = lot_commission + share(trade.commission, quantity, trade.quantity);
+                let gross = if position.short {
+                    open_amount - close_amount
+                } else {
+                    close_amount - open_amount
+                };
+
+                closed.push(ClosedLot {
+                    instrument_uid: key.clone(),
+                    figi: position.figi.clone(),
+                    currency: position.currency.clone(),
+                    opened_at: lot.opened_at.clone(),
+                    closed_at: trade.at.clone(),
+                    quantity,
+                    short: position.short,
+                    open_amount: Quotation::from_nanos(open_amount),
+                    close_amount: Quotation::from_nanos(close_amount),
+                    commission: Quotation::from_nanos(commission),
+                    realized_pnl: Quotation::from_nanos(gross - commission),
+                });
+
+                lot.quantity -= quantity;
+                lot.amount -= open_amount;
+                lot.commission -= lot_commission;
+                if lot.quantity == 0 {
+                    match method {
+                        LotMethod::Lifo => position.lots.pop_back(),
+                        LotMethod::Fifo | LotMethod::Average => position.lots.pop_front(),
+                    };
+                }
+                remaining -= quantity;
+            }
+        }
+
+        if remaining > 0 {
+            let lot = Lot {
+                opened_at: trade.at.clone(),
+                quantity: remaining,
+                amount: share(trade.amount, remaining, trade.quantity),
+                commission: share(trade.commission, remaining, trade.quantity),
+            };
+
+            position.short = trade.side == Side::Sell;
+            match (method, position.lots.front_mut()) {
+                (LotMethod::Average, Some(average)) => {
+                    average.quantity += lot.quantity;
+                    average.amount += lot.amount;
+                    average.commission += lot.commission;
+                }
+                _ => position.lots.push_back(lot),
+            }
+        }
+
+        for lot in closed {
+            let year = self.yearly_entry(&lot.closed_at, &lot.instrument_uid, &lot.currency);
+            year.realized_pnl += lot.realized_pnl.to_nanos();
+            year.commissions += lot.commission.to_nanos();
+            self.report.closed_lots.push(lot);
+        }
+    }
+
+    /// Removes transferred lots without realizing PnL, in the same order as sells close them
+    fn transfer_out(&mut self, key: &str, mut quantity: i64) {
+        let method = self.method;
+        let Some(position) = self.positions.get_mut(key) else {
+            return;
+        };
+
+        while quantity > 0 {
+            let lot = match method {
+                LotMethod::Lifo => position.lots.back_mut(),
+                LotMethod::Fifo | LotMethod::Average => position.lots.front_mut(),
+            };
+            let Some(lot) = lot else {
+                break;
+            };
+            let removed = quantity.min(lot.quantity);
+
+            lot.amount -= share(lot.amount, removed, lot.quantity);
+            lot.commission -= share(lot.commission, removed, lot.quantity);
+            lot.quantity -= removed;
+            if lot.quantity == 0 {
+                match method {
+                    LotMethod::Lifo => position.lots.pop_back(),
+                    LotMethod::Fifo | LotMethod::Average => position.lots.pop_front(),
+                };
+            }
+            quantity -= removed;
+        }
+    }
+
+    /// Partial bond repayment returns part of cost of open lots
+    fn amortize(&mut self, key: &str, payment: i128) {
+        let Some(position) = self.positions.get_mut(key) else {
+            return;
+        };
+        let quantity = position.quantity();
+        if quantity <= 0 || position.short {
+            return;
+        }
+
+        // Last lot gets rounding remainder, so whole payment is returned
+        let mut left = payment;
+        let last = position.lots.len() - 1;
+        for (index, lot) in position.lots.iter_mut().enumerate() {
+            let part = if index == last {
+                left
+            } else {
+                share(payment, lot.quantity, quantity)
+            };
+            lot.amount -= part;
+            left -= part;
+        }
+    }
+
+    fn yearly_entry(&mut self, at: &DateTime, key: &str, currency: &str) -> &mut Totals {
+        let (year, _, _) = at.ymd(self.zone);
+
+        self.yearly
+            .entry((year, key.to_string(), currency.to_string()))
+            .or_default()
+    }
+
+    fn finish(mut self) -> TaxLotReport {
+        for (key, position) in self.positions {
+            let sign = if position.short { -1 } else { 1 };
+
+            self.report
+                .open_lots
+                .extend(position.lots.into_iter().map(|lot| OpenLot {
+                    instrument_uid: key.clone(),
+                    figi: position.figi.clone(),
+                    currency: position.currency.clone(),
+                    opened_at: lot.opened_at,
+                    quantity: lot.quantity * sign,
+                    amount: Quotation::from_nanos(lot.amount),
+                    commission: Quotation::from_nanos(lot.commission),
+                }));
+        }
+        self.report.yearly = self
+            .yearly
+            .into_iter()
+            .map(|((year, instrument_uid, currency), totals)| YearlyGain {
+                year,
+                instrument_uid,
+                currency,
+                realized_pnl: Quotation::from_nanos(totals.realized_pnl),
+                commissions: Quotation::from_nanos(totals.commissions),
+                dividends: Quotation::from_nanos(totals.dividends),
+                coupons: Quotation::from_nanos(totals.coupons),
+                taxes: Quotation::from_nanos(totals.taxes),
+            })
+            .collect();
+
+        self.report
+    }
+}
+
+// endregion: --- Replay
+
+// region:    --- Helpers
+
+fn nanos(value: &Option<MoneyValue>) -> i128 {
+    value.as_ref().map_or(0, MoneyValue::to_nanos)
+}
+
+/// Returns `part` of `whole` quantity of amount, rounded half away from zero to nano
+fn share(amount: i128, part: i64, whole: i64) -> i128 {
+    if whole == 0 {
+        return 0;
+    }
+
+    let scaled = amount * part as i128;
+    let whole = whole as i128;
+    let rounded = (scaled.abs() + whole.abs() / 2) / whole.abs();
+
+    if (scaled < 0) != (whole < 0) {
+        -rounded
+    } else {
+        rounded
+    }
+}
+
+fn instrument_key(operation: &OperationItem) -> String {
+    if operation.instrument_uid.is_empty() {
+        operation.figi.clone()
+    } else {
+        operation.instrument_uid.clone()
+    }
+}
+
+fn currency(operation: &OperationItem) -> String {
+    [&operation.payment, &operation.price, &operation.commission]
+        .into_iter()
+        .flatten()
+        .map(|value| value.currency.to_lowercase())
+        .find(|currency| !currency.is_empty())
+        .unwrap_or_default()
+}
+
+/// Broker fee of trade, counted in lots of the trade
+fn is_trade_fee(operation: &OperationItem) -> bool {
+    EnumValue::from(operation.r#type) == OperationType::BrokerFee
+        && !operation.parent_operation_id.is_empty()
+}
+
+fn is_fee(kind: OperationType) -> bool {
+    use OperationType as Op;
+
+    matches!(
+        kind,
+        Op::BrokerFee
+            | Op::ServiceFee
+            | Op::MarginFee
+            | Op::SuccessFee
+            | Op::TrackMfee
+            | Op::TrackPfee
+            | Op::CashFee
+            | Op::OutFee
+            | Op::OutStampDuty
+            | Op::AdviceFee
+    )
+}
+
+fn is_tax(kind: OperationType) -> bool {
+    use OperationType as Op;
+
+    matches!(
+        kind,
+        Op::Tax
+            | Op::BondTax
+            | Op::DividendTax
+            | Op::BenefitTax
+            | Op::TaxCorrection
+            | Op::TaxProgressive
+            | Op::BondTaxProgressive
+            | Op::DividendTaxProgressive
+            | Op::BenefitTaxProgressive
+            | Op::TaxCorrectionProgressive
+            | Op::TaxRepoProgressive
+            | Op::TaxRepo
+            | Op::TaxRepoHold
+            | Op::TaxRepoRefund
+            | Op::TaxRepoHoldProgressive
+            | Op::TaxRepoRefundProgressive
+            | Op::TaxCorrectionCoupon
+    )
+}
+
+// endregion: --- Helpers
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    type Error = Box<dyn std::error::Error>;
+    type Result<T> = core::result::Result<T, Error>; // For tests.
+
+    use super::*;
+
+    fn operation(
+        id: &str,
+        date: &str,
+        kind: OperationType,
+        quantity: i64,
+        payment: f64,
+    ) -> OperationItem {
+        OperationItem {
+            id: id.to_string(),
+            date: Some(date.parse::<DateTime>().unwrap().timestamp()),
+            state: OperationState::Executed as i32,
+            r#type: kind as i32,
+            instrument_uid: "sber".to_string(),
+            quantity,
+            quantity_done: quantity,
+            payment: Some(MoneyValue::from_f64("rub", payment)),
+            ..Default::default()
+        }
+    }
+
+    fn history() -> Vec<OperationItem> {
+        let fee = OperationItem {
+            parent_operation_id: "buy-2".to_string(),
+            ..operation("fee-2", "2023-02-01", OperationType::BrokerFee, 0, -3.0)
+        };
+
+        vec![
+            // Newest first, as returned by cursor
+            operation("div", "2024-07-20", OperationType::Dividend, 0, 330.0),
+            operation("sell", "2024-03-01", OperationType::Sell, 15, 4500.0),
+            fee,
+            operation("buy-2", "2023-02-01", OperationType::Buy, 10, -2500.0),
+            operation("buy-1", "2023-01-10", OperationType::Buy, 10, -2000.0),
+        ]
+    }
+
+    #[test]
+    fn test_tax_lots_fifo_ok() -> Result<()> {
+        let mut engine = TaxLotEngine::new(LotMethod::Fifo);
+        engine.ingest(&history());
+        engine.ingest(&history()[..2]);
+        // Same operation with corrected date is not counted twice
+        engine.ingest(&[operation(
+            "div",
+            "2024-07-21",
+            OperationType::Dividend,
+            0,
+            330.0,
+        )]);
+        assert_eq!(engine.len(), 5);
+
+        let report = engine.report();
+
+        // 10 from first lot and 5 from second one
+        assert_eq!(report.closed_lots.len(), 2);
+        assert_eq!(report.closed_lots[0].realized_pnl, Quotation::new(1000, 0));
+        assert_eq!(
+            report.closed_lots[1].realized_pnl,
+            Quotation::new(248, 500_000_000)
+        );
+
+        assert_eq!(report.open_lots[0].quantity, 5);
+        assert_eq!(report.open_lots[0].amount, Quotation::new(1250, 0));
+        assert_eq!(report.open_lots[0].commission.to_f64(), 1.5);
+
+        assert_eq!(report.yearly.len(), 1);
+        let year = &report.yearly[0];
+        assert_eq!(year.year, 2024);
+        assert_eq!(year.realized_pnl.to_decimal_string(), "1248.5");
+        assert_eq!(year.commissions.to_decimal_string(), "1.5");
+        assert_eq!(year.dividends.to_decimal_string(), "330");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_tax_lots_average_and_short_ok() -> Result<()> {
+        let mut engine = TaxLotEngine::new(LotMethod::Average);
+        engine.ingest(&history());
+        engine.ingest(&[operation(
+            "short",
+            "2024-04-01",
+            OperationType::Sell,
+            10,
+            3000.0,
+        )]);
+        engine.ingest(&[operation(
+            "cover",
+            "2024-05-01",
+            OperationType::Buy,
+            10,
+            -2800.0,
+        )]);
+
+        let report = engine.report();
+
+        // Average cost is (2000 + 2500 + 3) / 20 = 225.15 per share
+        assert_eq!(report.closed_lots[0].quantity, 15);
+        assert_eq!(
+            report.closed_lots[0].realized_pnl.to_decimal_string(),
+            "1122.75"
+        );
+
+        // 5 shares are closed by sell, other 5 open short
+        assert!(!report.closed_lots[1].short);
+        assert!(report.closed_lots[2].short);
+        assert_eq!(report.closed_lots[2].realized_pnl, Quotation::new(100, 0));
+        assert_eq!(report.open_lots.len(), 1);
+        assert_eq!(report.open_lots[0].quantity, 5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_tax_lots_lifo_transfer_ok() -> Result<()> {
+        let mut engine = TaxLotEngine::new(LotMethod::Lifo);
+        engine.ingest(&history()[3..]);
+        // Operations without id are not merged
+        engine.ingest(&[
+            operation("", "2023-03-01", OperationType::OutputSecurities, 5, 0.0),
+            operation("", "2023-03-02", OperationType::OutputSecurities, 5, 0.0),
+        ]);
+        assert_eq!(engine.len(), 4);
+
+        let report = engine.report();
+
+        // Newest lot is transferred
+        assert!(report.closed_lots.is_empty());
+        assert_eq!(report.open_lots.len(), 1);
+        assert_eq!(report.open_lots[0].quantity, 10);
+        assert_eq!(report.open_lots[0].amount, Quotation::new(2000, 0));
+
+        Ok(())
+    }
+}
+
+// endregion: --- Tests
@@ -0,0 +1,25 @@
+//! Portfolio analytics over API responses
+//!
+//! Analytics work on responses already received, so they can be used with any client setup.
+
+// region:    --- Modules
+
+mod catalog;
+#[cfg(any(feature = "datetime", feature = "datetime-time"))]
+mod lots;
+mod portfolio;
+mod rebalance;
+
+pub use catalog::{InstrumentCatalog, InstrumentInfo};
+#[cfg(any(feature = "datetime", feature = "datetime-time"))]
+pub use lots::{
+    ClosedLot, Income, IncomeKind, LotMethod, OpenLot, TaxLotEngine, TaxLotReport, YearlyGain,
+};
+pub use portfolio::{
+    Allocation, Concentration, PortfolioAnalyzer, PortfolioReport, PositionReport, UNKNOWN,
+};
+pub use rebalance::{
+    Constraint, MaxLots, RebalanceInputs, RebalanceLine, RebalancePlan, Rebalancer,
+};
+
+// endregion: --- Modules
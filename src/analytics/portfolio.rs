@@ -0,0 +1,470 @@
+//! Allocation, unrealized PnL and concentration of portfolio
+
+use super::{InstrumentCatalog, InstrumentInfo};
+use crate::api::v1::{MoneyValue, PortfolioPosition, PortfolioResponse};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// Group name for positions without sector or country in catalog
+pub const UNKNOWN: &str = "unknown";
+
+// region:    --- Report
+
+/// Result of `PortfolioAnalyzer::analyze`
+///
+/// Weights are shares of gross value `sum(|value_base|)`, so short positions increase concentration too.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PortfolioReport {
+    pub base_currency: String,
+    /// Net value of priced positions in base currency
+    pub total_value: f64,
+    /// Unrealized PnL of priced positions in base currency
+    pub unrealized_pnl: f64,
+    pub positions: Vec<PositionReport>,
+    pub by_instrument_type: Vec<Allocation>,
+    /// By currency instruments are exposed to, e.g. `usd` for USD/RUB position
+    pub by_currency: Vec<Allocation>,
+    pub by_sector: Vec<Allocation>,
+    pub by_country: Vec<Allocation>,
+    pub concentration: Concentration,
+    /// Price currencies without rate, their positions have no base values
+    pub missing_rates: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PositionReport {
+    pub instrument_uid: String,
+    pub figi: String,
+    pub ticker: String,
+    pub instrument_type: String,
+    /// Currency of prices, lowercase
+    pub currency: String,
+    pub quantity: f64,
+    pub average_price: f64,
+    /// Price in currency, futures price in points is converted by amount of price step
+    pub current_price: f64,
+    /// Market value with accrued coupon in position currency
+    pub value: f64,
+    pub value_base: Option<f64>,
+    /// `(current_price - average_price) * quantity` in position currency
+    pub unrealized_pnl: f64,
+    pub unrealized_pnl_base: Option<f64>,
+    /// PnL to position cost in percents
+    pub unrealized_pnl_percent: Option<f64>,
+    pub weight: f64,
+}
+
+/// Value of positions in group
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Allocation {
+    pub key: String,
+    /// Net value in base currency
+    pub value: f64,
+    pub weight: f64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Concentration {
+    pub largest_weight: f64,
+    pub top5_weight: f64,
+    /// Herfindahl-Hirschman index, sum of squared weights
+    pub herfindahl_index: f64,
+    /// Number of equal positions with the same index, `1 / herfindahl_index`
+    pub effective_positions: f64,
+}
+
+// endregion: --- Report
+
+// region:    --- Analyzer
+
+/// Joins portfolio with instrument catalog and currency rates
+///
+/// Rates of currencies held in portfolio are taken from their prices, if currency instruments are in catalog.
+#[derive(Debug, Clone)]
+pub struct PortfolioAnalyzer {
+    catalog: InstrumentCatalog,
+    base_currency: String,
+    rates: HashMap<String, f64>,
+}
+
+impl Default for PortfolioAnalyzer {
+    fn default() -> Self {
+        Self::new(InstrumentCatalog::default())
+    }
+}
+
+impl PortfolioAnalyzer {
+    /// Creates analyzer with `rub` base currency
+    pub fn new(catalog: InstrumentCatalog) -> Self {
+        Self {
+            catalog,
+            base_currency: "rub".to_string(),
+            rates: HashMap::new(),
+        }
+    }
+
+    pub fn with_base_currency(mut self, currency: impl Into<String>) -> Self {
+        self.base_currency = currency.into().to_lowercase();
+        self
+    }
+
+    /// Sets price of one unit of `currency` in base currency
+    pub fn with_rate(mut self, currency: impl Into<String>, rate: f64) -> Self {
+        self.rates.insert(currency.into().to_lowercase(), rate);
+        self
+    }
+
+    pub fn catalog(&self) -> &InstrumentCatalog {
+        &self.catalog
+    }
+
+    pub fn base_currency(&self) -> &str {
+        &self.base_currency
+    }
+
+    pub fn analyze(&self, portfolio: &PortfolioResponse) -> PortfolioReport {
+        let rates = self.rates_for(portfolio);
+        let mut missing_rates = BTreeSet::new();
+
+        let mut positions: Vec<PositionReport> = portfolio
+            .positions
+            .iter()
+            .map(|position| {
+                let report = self.position_report(position, &rates);
+                if report.value_base.is_none() {
+                    missing_rates.insert(report.currency.clone());
+                }
+                report
+            })
+            .collect();
+
+        let gross: f64 = positions
+            .iter()
+            .filter_map(|position| position.value_base)
+            .map(f64::abs)
+            .sum();
+        let weight = |value: f64| {
+            if gross > 0.0 {
+                value.abs() / gross
+            } else {
+                0.0
+            }
+        };
+
+        for position in positions.iter_mut() {
+            position.weight = position.value_base.map_or(0.0, weight);
+        }
+
+        let group = |key: &dyn Fn(&PositionReport, Option<&InstrumentInfo>) -> String| {
+            let mut groups: BTreeMap<String, (f64, f64)> = BTreeMap::new();
+
+            for (position, source) in positions.iter().zip(&portfolio.positions) {
+                let Some(value) = position.value_base else {
+                    continue;
+                };
+                let instrument = self.catalog.find(&source.instrument_uid, &source.figi);
+                let (net, gross) = groups.entry(key(position, instrument)).or_default();
+                *net += value;
+                *gross += value.abs();
+            }
+
+            allocations(groups, &weight)
+        };
+
+        let known = |value: Option<&String>| match value {
+            Some(value) if !value.is_empty() => value.clone(),
+            _ => UNKNOWN.to_string(),
+        };
+
+        let by_instrument_type = group(&|position, _| position.instrument_type.clone());
+        let by_currency = group(&|position, instrument| {
+            instrument
+                .map(|instrument| instrument.exposure_currency.clone())
+                .filter(|currency| !currency.is_empty())
+                .unwrap_or_else(|| position.currency.clone())
+        });
+        let by_sector = group(&|_, instrument| known(instrument.map(|i| &i.sector)));
+        let by_country = group(&|_, instrument| known(instrument.map(|i| &i.country)));
+
+        PortfolioReport {
+            base_currency: self.base_currency.clone(),
+            total_value: positions.iter().filter_map(|p| p.value_base).sum(),
+            unrealized_pnl: positions.iter().filter_map(|p| p.unrealized_pnl_base).sum(),
+            concentration: concentration(&positions),
+            positions,
+            by_instrument_type,
+            by_currency,
+            by_sector,
+            by_country,
+            missing_rates: missing_rates.into_iter().collect(),
+        }
+    }
+
+    fn position_report(
+        &self,
+        position: &PortfolioPosition,
+        rates: &HashMap<String, f64>,
+    ) -> PositionReport {
+        let instrument = self.catalog.find(&position.instrument_uid, &position.figi);
+
+        let quantity = position.quantity.as_ref().map_or(0.0, |q| q.to_f64());
+        let average_price = amount(&position.average_position_price);
+        let accrued = amount(&position.current_nkd);
+
+        // Futures are priced in points, average price is in currency
+        let futures_price = instrument
+            .filter(|instrument| instrument.instrument_type == "futures")
+            .and_then(|instrument| instrument.points_to_amount(amount(&position.current_price)));
+        let current_price = futures_price.unwrap_or_else(|| amount(&position.current_price));
+
+        let currency = position
+            .current_price
+            .as_ref()
+            .filter(|_| futures_price.is_none())
+            .map(|price| price.currency.to_lowercase())
+            .filter(|currency| !currency.is_empty())
+            .or_else(|| instrument.map(|instrument| instrument.currency.clone()))
+            .unwrap_or_default();
+        let rate = rates.get(&currency).copied();
+
+        let value = (current_price + accrued) * quantity;
+        let unrealized_pnl = (current_price - average_price) * quantity;
+        let cost = (average_price * quantity).abs();
+
+        PositionReport {
+            instrument_uid: position.instrument_uid.clone(),
+            figi: position.figi.clone(),
+            ticker: instrument.map(|i| i.ticker.clone()).unwrap_or_default(),
+            instrument_type: position.instrument_type.clone(),
+            currency,
+            quantity,
+            average_price,
+            current_price,
+            value,
+            value_base: rate.map(|rate| value * rate),
+            unrealized_pnl,
+            unrealized_pnl_base: rate.map(|rate| unrealized_pnl * rate),
+            unrealized_pnl_percent: (cost > 0.0).then(|| unrealized_pnl / cost * 100.0),
+            weight: 0.0,
+        }
+    }
+
+    /// Configured rates, then rates from currency positions priced in base currency
+    pub(super) fn rates_for(&self, portfolio: &PortfolioResponse) -> HashMap<String, f64> {
+        let mut rates = self.rates.clone();
+        rates.insert(self.base_currency.clone(), 1.0);
+
+        for position in &portfolio.positions {
+            let Some(instrument) = self.catalog.find(&position.instrument_uid, &position.figi)
+            else {
+                continue;
+            };
+            let Some(price) = &position.current_price else {
+                continue;
+            };
+
+            if instrument.instrument_type == "currency"
+                && price.currency.eq_ignore_ascii_case(&self.base_currency)
+                && !instrument.exposure_currency.is_empty()
+            {
+                rates
+                    .entry(instrument.exposure_currency.clone())
+                    .or_insert_with(|| price.to_f64());
+            }
+        }
+
+        rates
+    }
+}
+
+// endregion: --- Analyzer
+
+// region:    --- Helpers
+
+fn amount(value: &Option<MoneyValue>) -> f64 {
+    value.as_ref().map_or(0.0, MoneyValue::to_f64)
+}
+
+/// Sorts groups by weight, largest first
+fn allocations(
+    groups: BTreeMap<String, (f64, f64)>,
+    weight: &dyn Fn(f64) -> f64,
+) -> Vec<Allocation> {
+    let mut allocations: Vec<_> = groups
+        .into_iter()
+        .map(|(key, (value, gross))| Allocation {
+            key,
+            value,
+            weight: weight(gross),
+        })
+        .collect();
+
+    allocations.sort_by(|left, right| right.weight.total_cmp(&left.weight));
+    allocations
+}
+
+fn concentration(positions: &[PositionReport]) -> Concentration {
+    let mut weights: Vec<f64> = positions.iter().map(|p| p.weight).collect();
+    weights.sort_by(|left, right| right.total_cmp(left));
+
+    let herfindahl_index: f64 = weights.iter().map(|weight| weight * weight).sum();
+
+    Concentration {
+        largest_weight: weights.first().copied().unwrap_or_default(),
+        top5_weight: weights.iter().take(5).sum(),
+        herfindahl_index,
+        effective_positions: if herfindahl_index > 0.0 {
+            1.0 / herfindahl_index
+        } else {
+            0.0
+        },
+    }
+}
+
+// endregion: --- Helpers
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    type Error = Box<dyn std::error::Error>;
+    type Result<T> = core::result::Result<T, Error>; // For tests.
+
+    use super::*;
+    use crate::api::v1::{Currency, Future, Quotation, Share};
+
+    fn position(
+        uid: &str,
+        kind: &str,
+        quantity: i64,
+        average: f64,
+        price: MoneyValue,
+    ) -> PortfolioPosition {
+        PortfolioPosition {
+            instrument_uid: uid.to_string(),
+            instrument_type: kind.to_string(),
+            quantity: Some(Quotation::new(quantity, 0)),
+            average_position_price: Some(MoneyValue::from_f64(&price.currency, average)),
+            current_price: Some(price),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_portfolio_analyzer_ok() -> Result<()> {
+        let mut catalog = InstrumentCatalog::new();
+        catalog.insert(Share {
+            uid: "sber".to_string(),
+            ticker: "SBER".to_string(),
+            currency: "rub".to_string(),
+            sector: "financial".to_string(),
+            country_of_risk: "RU".to_string(),
+            ..Default::default()
+        });
+        catalog.insert(Share {
+            uid: "aapl".to_string(),
+            currency: "usd".to_string(),
+            sector: "it".to_string(),
+            country_of_risk: "US".to_string(),
+            ..Default::default()
+        });
+        catalog.insert(Currency {
+            uid: "usdrub".to_string(),
+            currency: "rub".to_string(),
+            iso_currency_name: "usd".to_string(),
+            ..Default::default()
+        });
+
+        let portfolio = PortfolioResponse {
+            positions: vec![
+                position(
+                    "sber",
+                    "share",
+                    100,
+                    250.0,
+                    MoneyValue::from_f64("rub", 300.0),
+                ),
+                position(
+                    "aapl",
+                    "share",
+                    2,
+                    200.0,
+                    MoneyValue::from_f64("usd", 150.0),
+                ),
+                position(
+                    "usdrub",
+                    "currency",
+                    100,
+                    90.0,
+                    MoneyValue::from_f64("rub", 100.0),
+                ),
+                position(
+                    "unknown",
+                    "share",
+                    1,
+                    10.0,
+                    MoneyValue::from_f64("hkd", 10.0),
+                ),
+            ],
+            ..Default::default()
+        };
+
+        let report = PortfolioAnalyzer::new(catalog).analyze(&portfolio);
+
+        // 30000 + 300 * 100 + 10000 rub
+        assert_eq!(report.total_value, 70000.0);
+        assert_eq!(report.unrealized_pnl, 5000.0 - 10000.0 + 1000.0);
+        assert_eq!(report.missing_rates, ["hkd"]);
+
+        let aapl = &report.positions[1];
+        assert_eq!(aapl.value_base, Some(30000.0));
+        assert_eq!(aapl.unrealized_pnl_percent, Some(-25.0));
+
+        assert_eq!(report.by_currency[0].key, "usd");
+        assert_eq!(report.by_currency[0].weight, 40000.0 / 70000.0);
+        assert_eq!(
+            report.by_sector.last().map(|a| a.key.as_str()),
+            Some(UNKNOWN)
+        );
+        assert_eq!(report.concentration.largest_weight, 30000.0 / 70000.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_portfolio_analyzer_futures_ok() -> Result<()> {
+        let mut catalog = InstrumentCatalog::new();
+        catalog.insert(Future {
+            uid: "ri".to_string(),
+            currency: "rub".to_string(),
+            min_price_increment: Some(Quotation::new(10, 0)),
+            min_price_increment_amount: Some(Quotation::new(13, 500_000_000)),
+            ..Default::default()
+        });
+
+        // Current price in points, average price in rub
+        let portfolio = PortfolioResponse {
+            positions: vec![position(
+                "ri",
+                "futures",
+                2,
+                130_000.0,
+                MoneyValue::from_f64("rub", 100_000.0),
+            )],
+            ..Default::default()
+        };
+
+        let report = PortfolioAnalyzer::new(catalog).analyze(&portfolio);
+
+        // 100000 points in steps of 10 points at 13.5 rub
+        let ri = &report.positions[0];
+        assert_eq!(ri.current_price, 135_000.0);
+        assert_eq!(ri.currency, "rub");
+        assert_eq!(ri.value, 270_000.0);
+        assert_eq!(ri.unrealized_pnl, 10_000.0);
+        assert_eq!(report.total_value, 270_000.0);
+
+        Ok(())
+    }
+}
+
+// endregion: --- Tests
@@ -0,0 +1,822 @@
+//! Lot-rounded orders moving portfolio to target weights
+
+#![allow(clippy::result_large_err)]
+
+use super::{InstrumentInfo, PortfolioAnalyzer, PositionReport};
+use crate::api::v1::{
+    GetMaxLotsRequest, GetMaxLotsResponse, GetOrderPriceRequest, GetOrderPriceResponse,
+    IntercemptorWithNew, InvestApiTrait, OrderDirection, OrderType, PortfolioRequest,
+    PortfolioResponse, PostOrderRequest, PriceType, Quotation, WithdrawLimitsRequest,
+    WithdrawLimitsResponse,
+};
+use crate::{Error, Result};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
+use tonic::transport::Channel;
+use uuid::Uuid;
+
+// region:    --- Inputs
+
+/// Order limits of instrument from `get_max_lots`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MaxLots {
+    /// Lots available for limit buy
+    pub buy: i64,
+    /// Lots available for market buy
+    pub buy_market: i64,
+    pub sell: i64,
+}
+
+impl From<&GetMaxLotsResponse> for MaxLots {
+    fn from(response: &GetMaxLotsResponse) -> Self {
+        let buy = response.buy_limits.as_ref();
+
+        Self {
+            buy: buy.map_or(0, |limits| limits.buy_max_lots),
+            buy_market: buy.map_or(0, |limits| limits.buy_max_market_lots),
+            sell: response
+                .sell_limits
+                .as_ref()
+                .map_or(0, |limits| limits.sell_max_lots),
+        }
+    }
+}
+
+/// Account limits and order estimates used by `Rebalancer::plan`
+///
+/// Instruments without estimates are priced from portfolio and have no lot limits.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RebalanceInputs {
+    /// Cash available for buys in base currency
+    pub cash: f64,
+    /// Prices of one instrument in its currency by uid, override portfolio prices
+    pub prices: HashMap<String, f64>,
+    /// Amounts of one lot in instrument currency by uid, override prices
+    pub lot_amounts: HashMap<String, f64>,
+    /// Commissions as fraction of order amount by uid
+    pub commission_rates: HashMap<String, f64>,
+    pub max_lots: HashMap<String, MaxLots>,
+}
+
+impl RebalanceInputs {
+    pub fn new(cash: f64) -> Self {
+        Self {
+            cash,
+            ..Default::default()
+        }
+    }
+
+    /// Takes cash in `currency` from money available for withdrawal
+    pub fn from_withdraw_limits(limits: &WithdrawLimitsResponse, currency: &str) -> Self {
+        let cash = limits
+            .money
+            .iter()
+            .filter(|money| money.currency.eq_ignore_ascii_case(currency))
+            .map(|money| money.to_f64())
+            .sum();
+
+        Self::new(cash)
+    }
+
+    pub fn with_price(mut self, uid: impl Into<String>, price: f64) -> Self {
+        self.prices.insert(uid.into(), price);
+        self
+    }
+
+    pub fn with_max_lots(mut self, uid: impl Into<String>, response: &GetMaxLotsResponse) -> Self {
+        self.max_lots.insert(uid.into(), response.into());
+        self
+    }
+
+    /// Takes lot amount and commission rate from order estimate
+    pub fn with_order_price(
+        mut self,
+        uid: impl Into<String>,
+        response: &GetOrderPriceResponse,
+    ) -> Self {
+        let uid = uid.into();
+        let amount = response
+            .initial_order_amount
+            .as_ref()
+            .map_or(0.0, |amount| amount.to_f64());
+
+        if amount > 0.0 && response.lots_requested > 0 {
+            self.lot_amounts
+                .insert(uid.clone(), amount / response.lots_requested as f64);
+
+            if let Some(commission) = &response.executed_commission {
+                self.commission_rates
+                    .insert(uid, commission.to_f64() / amount);
+            }
+        }
+
+        self
+    }
+}
+
+// endregion: --- Inputs
+
+// region:    --- Plan
+
+/// Reason order is smaller than needed to reach target
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Constraint {
+    /// No price or currency rate, instrument is not traded
+    NoPrice,
+    /// Sell is limited by held lots
+    Holdings,
+    MaxLots,
+    Cash,
+}
+
+/// Planned trade of one instrument, `lots` is zero if nothing is traded
+#[derive(Debug, Clone, PartialEq)]
+pub struct RebalanceLine {
+    pub instrument_uid: String,
+    pub ticker: String,
+    pub direction: OrderDirection,
+    pub lots: i64,
+    /// Number of instruments, `lots * lot`
+    pub quantity: i64,
+    /// Price of one instrument in its currency
+    pub price: f64,
+    /// Order amount in base currency
+    pub amount: f64,
+    /// Estimated commission in base currency
+    pub commission: f64,
+    pub current_weight: f64,
+    pub target_weight: f64,
+    /// Weight after order is executed at `price`
+    pub planned_weight: f64,
+    pub constraint: Option<Constraint>,
+}
+
+/// Result of `Rebalancer::plan`, `Display` prints dry-run report
+#[derive(Debug, Clone, PartialEq)]
+pub struct RebalancePlan {
+    pub base_currency: String,
+    /// Net portfolio value in base currency, weights are taken of it
+    pub total_value: f64,
+    pub cash_before: f64,
+    /// Cash left after all orders, commissions included
+    pub cash_after: f64,
+    pub estimated_commission: f64,
+    /// Sells, then buys, then instruments without trades
+    pub lines: Vec<RebalanceLine>,
+    /// Ready to send orders in the same order as lines
+    pub orders: Vec<PostOrderRequest>,
+}
+
+impl fmt::Display for RebalancePlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Portfolio {:.2} {}, cash {:.2} -> {:.2}, commission {:.2}",
+            self.total_value,
+            self.base_currency,
+            self.cash_before,
+            self.cash_after,
+            self.estimated_commission
+        )?;
+        writeln!(
+            f,
+            "{:<4} {:<12} {:>8} {:>12} {:>14} {:>8} {:>8} {:>8}",
+            "side", "ticker", "lots", "price", "amount", "weight", "planned", "target"
+        )?;
+
+        for line in &self.lines {
+            let side = match line.direction {
+                OrderDirection::Buy => "buy",
+                OrderDirection::Sell => "sell",
+                OrderDirection::Unspecified => "-",
+            };
+            let ticker = if line.ticker.is_empty() {
+                &line.instrument_uid
+            } else {
+                &line.ticker
+            };
+
+            write!(
+                f,
+                "{side:<4} {ticker:<12} {:>8} {:>12.2} {:>14.2} {:>7.2}% {:>7.2}% {:>7.2}%",
+                line.lots,
+                line.price,
+                line.amount,
+                line.current_weight * 100.0,
+                line.planned_weight * 100.0,
+                line.target_weight * 100.0,
+            )?;
+            if let Some(constraint) = line.constraint {
+                write!(f, " {constraint:?}")?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+// endregion: --- Plan
+
+// region:    --- Rebalancer
+
+/// Computes orders moving portfolio to target weights
+///
+/// Targets are weights of net portfolio value, the rest stays in cash. Instruments are traded
+/// in whole lots, rounded towards current holdings, sells go first and free cash for buys.
+#[derive(Debug, Clone)]
+pub struct Rebalancer {
+    analyzer: PortfolioAnalyzer,
+    account_id: String,
+    targets: BTreeMap<String, f64>,
+    commission_rate: f64,
+    min_order_value: f64,
+    limit_slippage: Option<f64>,
+    sell_untargeted: bool,
+}
+
+impl Rebalancer {
+    /// Creates rebalancer valuing portfolio with `analyzer`, which catalog must have lot sizes
+    pub fn new(account_id: impl Into<String>, analyzer: PortfolioAnalyzer) -> Self {
+        Self {
+            analyzer,
+            account_id: account_id.into(),
+            targets: BTreeMap::new(),
+            commission_rate: 0.0,
+            min_order_value: 0.0,
+            limit_slippage: None,
+            sell_untargeted: false,
+        }
+    }
+
+    /// Sets target weight of instrument by uid, see `with_targets`
+    pub fn with_target(self, uid: impl Into<String>, weight: f64) -> Result<Self> {
+        self.with_targets([(uid, weight)])
+    }
+
+    /// Sets target weights by uid
+    ///
+    /// Weights must not be negative and all targets must sum up to 1.0 at most, otherwise
+    /// targets are left unchanged and error is returned.
+    pub fn with_targets<S: Into<String>>(
+        mut self,
+        targets: impl IntoIterator<Item = (S, f64)>,
+    ) -> Result<Self> {
+        let mut updated = self.targets.clone();
+        updated.extend(
+            targets
+                .into_iter()
+                .map(|(uid, weight)| (uid.into(), weight)),
+        );
+
+        validate_targets(&updated)?;
+        self.targets = updated;
+
+        Ok(self)
+    }
+
+    /// Sets commission for instruments without estimate, as fraction of order amount
+    pub fn with_commission_rate(mut self, rate: f64) -> Self {
+        self.commission_rate = rate;
+        self
+    }
+
+    /// Skips trades smaller than `value` in base currency
+    pub fn with_min_order_value(mut self, value: f64) -> Self {
+        self.min_order_value = value;
+        self
+    }
+
+    /// Sends limit orders priced `slippage` away from price, e.g. `0.005`
+    ///
+    /// Prices are rounded to `min_price_increment`. Bonds and futures are priced in points, so
+    /// they are still sent as market orders.
+    pub fn with_limit_orders(mut self, slippage: f64) -> Self {
+        self.limit_slippage = Some(slippage);
+        self
+    }
+
+    /// Sells held instruments without target, by default they are kept
+    pub fn with_sell_untargeted(mut self, sell: bool) -> Self {
+        self.sell_untargeted = sell;
+        self
+    }
+
+    pub fn targets(&self) -> &BTreeMap<String, f64> {
+        &self.targets
+    }
+
+    /// Plans orders without sending them
+    pub fn plan(&self, portfolio: &PortfolioResponse, inputs: &RebalanceInputs) -> RebalancePlan {
+        let report = self.analyzer.analyze(portfolio);
+        let rates = self.analyzer.rates_for(portfolio);
+        let total_value = report.total_value;
+
+        let mut uids: BTreeSet<&str> = self.targets.keys().map(String::as_str).collect();
+        if self.sell_untargeted {
+            uids.extend(
+                report
+                    .positions
+                    .iter()
+                    .filter(|position| position.instrument_type != "currency")
+                    .map(|position| position.instrument_uid.as_str()),
+            );
+        }
+
+        let mut sells = Vec::new();
+        let mut buys = Vec::new();
+        let mut idle = Vec::new();
+
+        for uid in uids {
+            let draft = self.draft(uid, &report.positions, &rates, inputs, total_value);
+
+            match draft.wanted {
+                lots if lots < 0 => sells.push(draft),
+                lots if lots > 0 => buys.push(draft),
+                _ => idle.push(draft),
+            }
+        }
+
+        let mut cash = inputs.cash;
+        let mut lines = Vec::new();
+        let mut orders = Vec::new();
+
+        for mut draft in sells {
+            let mut lots = -draft.wanted;
+            if lots > draft.held_lots {
+                lots = draft.held_lots;
+                draft.line.constraint = Some(Constraint::Holdings);
+            }
+            if let Some(max) = inputs.max_lots.get(&draft.line.instrument_uid) {
+                if lots > max.sell {
+                    lots = max.sell;
+                    draft.line.constraint = Some(Constraint::MaxLots);
+                }
+            }
+
+            let amount = lots as f64 * draft.lot_value;
+            cash += amount * (1.0 - draft.commission_rate);
+            self.fill(
+                &mut draft,
+                OrderDirection::Sell,
+                lots,
+                amount,
+                total_value,
+                &mut orders,
+            );
+            lines.push(draft.line);
+        }
+
+        // Largest deficits are bought first
+        buys.sort_by(|left, right| right.deficit.total_cmp(&left.deficit));
+
+        for mut draft in buys {
+            let mut lots = draft.wanted;
+            let max = inputs.max_lots.get(&draft.line.instrument_uid).map(|max| {
+                if self.is_limit(draft.instrument) {
+                    max.buy
+                } else {
+                    max.buy_market
+                }
+            });
+            if let Some(max) = max.filter(|max| lots > *max) {
+                lots = max;
+                draft.line.constraint = Some(Constraint::MaxLots);
+            }
+
+            // Limit buys may be filled up to their price
+            let slippage = if self.is_limit(draft.instrument) {
+                self.limit_slippage.unwrap_or_default()
+            } else {
+                0.0
+            };
+            let lot_cost = draft.lot_value * (1.0 + slippage) * (1.0 + draft.commission_rate);
+            let affordable = (cash.max(0.0) / lot_cost).floor() as i64;
+            if lots > affordable {
+                lots = affordable;
+                draft.line.constraint = Some(Constraint::Cash);
+            }
+
+            let amount = lots as f64 * draft.lot_value;
+            cash -= amount * (1.0 + draft.commission_rate);
+            self.fill(
+                &mut draft,
+                OrderDirection::Buy,
+                lots,
+                amount,
+                total_value,
+                &mut orders,
+            );
+            lines.push(draft.line);
+        }
+
+        lines.extend(idle.into_iter().map(|draft| draft.line));
+
+        let estimated_commission = lines.iter().map(|line| line.commission).sum();
+
+        RebalancePlan {
+            base_currency: self.analyzer.base_currency().to_string(),
+            total_value,
+            cash_before: inputs.cash,
+            cash_after: cash,
+            estimated_commission,
+            lines,
+            orders,
+        }
+    }
+
+    /// Requests portfolio, withdraw limits, max lots and order estimates, then plans orders
+    ///
+    /// Makes two `OrdersService` requests per instrument traded in plan without estimates,
+    /// estimates are requested with direction and lots of that plan.
+    pub async fn plan_with_api<D, I>(
+        &self,
+        api: &impl InvestApiTrait<D, I>,
+        channel: &Channel,
+    ) -> Result<RebalancePlan>
+    where
+        I: IntercemptorWithNew<D>,
+    {
+        let mut operations = api.operations(channel)?;
+        let portfolio = operations
+            .get_portfolio(PortfolioRequest {
+                account_id: self.account_id.clone(),
+                currency: None,
+            })
+            .await?
+            .into_inner();
+        let limits = operations
+            .get_withdraw_limits(WithdrawLimitsRequest {
+                account_id: self.account_id.clone(),
+            })
+            .await?
+            .into_inner();
+
+        let mut inputs =
+            RebalanceInputs::from_withdraw_limits(&limits, self.analyzer.base_currency());
+        let draft = self.plan(&portfolio, &inputs);
+        let mut orders = api.orders(channel)?;
+
+        for line in draft.lines.iter().filter(|line| line.lots > 0) {
+            let uid = &line.instrument_uid;
+
+            let price = orders
+                .get_order_price(GetOrderPriceRequest {
+                    account_id: self.account_id.clone(),
+                    instrument_id: uid.clone(),
+                    price: None,
+                    direction: line.direction as i32,
+                    quantity: line.lots,
+                })
+                .await?
+                .into_inner();
+            let max_lots = orders
+                .get_max_lots(GetMaxLotsRequest {
+                    account_id: self.account_id.clone(),
+                    instrument_id: uid.clone(),
+                    price: None,
+                })
+                .await?
+                .into_inner();
+
+            inputs = inputs
+                .with_order_price(uid, &price)
+                .with_max_lots(uid, &max_lots);
+        }
+
+        Ok(self.plan(&portfolio, &inputs))
+    }
+}
+
+/// Instrument valued before cash and limits are applied
+struct Draft<'a> {
+    instrument: Option<&'a InstrumentInfo>,
+    line: RebalanceLine,
+    lot: i64,
+    held_lots: i64,
+    current_value: f64,
+    /// Value of one lot in base currency
+    lot_value: f64,
+    commission_rate: f64,
+    /// Signed lots to reach target
+    wanted: i64,
+    deficit: f64,
+}
+
+impl Rebalancer {
+    fn draft<'a>(
+        &'a self,
+        uid: &str,
+        positions: &[PositionReport],
+        rates: &HashMap<String, f64>,
+        inputs: &RebalanceInputs,
+        total_value: f64,
+    ) -> Draft<'a> {
+        let instrument = self.analyzer.catalog().get(uid);
+        let position = positions.iter().find(|p| p.instrument_uid == uid);
+
+        let lot = instrument.map_or(1, |instrument| instrument.lot.max(1)) as i64;
+        let held = position.map_or(0.0, |position| position.quantity);
+        let current_value = position.and_then(|p| p.value_base).unwrap_or_default();
+        let target_weight = self.targets.get(uid).copied().unwrap_or_default();
+        let current_weight = if total_value > 0.0 {
+            current_value / total_value
+        } else {
+            0.0
+        };
+
+        let currency = position
+            .map(|position| position.currency.as_str())
+            .filter(|currency| !currency.is_empty())
+            .or(instrument.map(|instrument| instrument.currency.as_str()))
+            .unwrap_or_default();
+        let price = inputs
+            .prices
+            .get(uid)
+            .copied()
+            .or(position.map(|position| position.current_price))
+            .unwrap_or_default();
+        let lot_amount = inputs
+            .lot_amounts
+            .get(uid)
+            .copied()
+            .unwrap_or(price * lot as f64);
+        let lot_value = rates.get(currency).map_or(0.0, |rate| lot_amount * rate);
+
+        let deficit = target_weight * total_value - current_value;
+        let mut constraint = None;
+        let wanted = if lot_value <= 0.0 {
+            constraint = Some(Constraint::NoPrice);
+            0
+        } else if deficit.abs() < self.min_order_value {
+            0
+        } else {
+            (deficit / lot_value).trunc() as i64
+        };
+
+        Draft {
+            instrument,
+            line: RebalanceLine {
+                instrument_uid: uid.to_string(),
+                ticker: instrument.map(|i| i.ticker.clone()).unwrap_or_default(),
+                direction: OrderDirection::Unspecified,
+                lots: 0,
+                quantity: 0,
+                price: lot_amount / lot as f64,
+                amount: 0.0,
+                commission: 0.0,
+                current_weight,
+                target_weight,
+                planned_weight: current_weight,
+                constraint,
+            },
+            lot,
+            held_lots: (held / lot as f64).floor().max(0.0) as i64,
+            current_value,
+            lot_value,
+            commission_rate: inputs
+                .commission_rates
+                .get(uid)
+                .copied()
+                .unwrap_or(self.commission_rate),
+            wanted,
+            deficit,
+        }
+    }
+
+    /// Writes trade to line and adds order if there is anything to trade
+    fn fill(
+        &self,
+        draft: &mut Draft,
+        direction: OrderDirection,
+        lots: i64,
+        amount: f64,
+        total_value: f64,
+        orders: &mut Vec<PostOrderRequest>,
+    ) {
+        if lots <= 0 {
+            return;
+        }
+
+        let signed = match direction {
+            OrderDirection::Sell => -amount,
+            _ => amount,
+        };
+        let line = &mut draft.line;
+        line.direction = direction;
+        line.lots = lots;
+        line.quantity = lots * draft.lot;
+        line.amount = amount;
+        line.commission = amount * draft.commission_rate;
+        if total_value > 0.0 {
+            line.planned_weight = (draft.current_value + signed) / total_value;
+        }
+
+        let limit = self
+            .limit_slippage
+            .filter(|_| self.is_limit(draft.instrument))
+            .map(|slippage| {
+                let increment = draft.instrument.map_or(0.0, |i| i.min_price_increment);
+                limit_price(line.price, slippage, increment, direction)
+            });
+
+        orders.push(PostOrderRequest {
+            quantity: lots,
+            price: limit.map(Quotation::from_f64),
+            direction: direction as i32,
+            account_id: self.account_id.clone(),
+            order_type: if limit.is_some() {
+                OrderType::Limit
+            } else {
+                OrderType::Market
+            } as i32,
+            order_id: Uuid::new_v4().to_string(),
+            instrument_id: line.instrument_uid.clone(),
+            price_type: if limit.is_some() {
+                PriceType::Currency
+            } else {
+                PriceType::Unspecified
+            } as i32,
+            ..Default::default()
+        });
+    }
+
+    fn is_limit(&self, instrument: Option<&InstrumentInfo>) -> bool {
+        self.limit_slippage.is_some()
+            && instrument.is_some_and(|instrument| {
+                matches!(
+                    instrument.instrument_type.as_str(),
+                    "share" | "etf" | "currency"
+                )
+            })
+    }
+}
+
+/// Checks that weights are not negative and sum up to 1.0 at most
+fn validate_targets(targets: &BTreeMap<String, f64>) -> Result<()> {
+    if let Some((uid, weight)) = targets
+        .iter()
+        .find(|(_, weight)| weight.is_nan() || **weight < 0.0)
+    {
+        return Err(Error::InvalidTargetWeights(format!(
+            "weight of {uid} is {weight}"
+        )));
+    }
+
+    // Tolerance for weights like 0.1 + 0.2 + 0.7
+    let sum: f64 = targets.values().sum();
+    if sum > 1.0 + 1e-9 {
+        return Err(Error::InvalidTargetWeights(format!(
+            "weights sum up to {sum}"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Moves price against order direction and rounds it to price step in the same direction
+fn limit_price(price: f64, slippage: f64, increment: f64, direction: OrderDirection) -> f64 {
+    let buy = direction == OrderDirection::Buy;
+    let price = if buy {
+        price * (1.0 + slippage)
+    } else {
+        price * (1.0 - slippage)
+    };
+
+    if increment <= 0.0 {
+        return price;
+    }
+
+    // Tolerance keeps prices already on step from moving
+    let steps = price / increment;
+    let steps = if buy {
+        (steps - 1e-9).ceil()
+    } else {
+        (steps + 1e-9).floor()
+    };
+
+    steps * increment
+}
+
+// endregion: --- Rebalancer
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    type Error = Box<dyn std::error::Error>;
+    type Result<T> = core::result::Result<T, Error>; // For tests.
+
+    use super::*;
+    use crate::analytics::InstrumentCatalog;
+    use crate::api::v1::{
+        get_max_lots_response::BuyLimitsView, Currency, MoneyValue, PortfolioPosition, Share,
+    };
+
+    fn share(uid: &str, lot: i32, increment: f64) -> Share {
+        Share {
+            uid: uid.to_string(),
+            ticker: uid.to_uppercase(),
+            currency: "rub".to_string(),
+            lot,
+            min_price_increment: Some(Quotation::from_f64(increment)),
+            ..Default::default()
+        }
+    }
+
+    fn position(uid: &str, kind: &str, quantity: i64, price: f64) -> PortfolioPosition {
+        PortfolioPosition {
+            instrument_uid: uid.to_string(),
+            instrument_type: kind.to_string(),
+            quantity: Some(Quotation::new(quantity, 0)),
+            current_price: Some(MoneyValue::from_f64("rub", price)),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_rebalancer_plan_ok() -> Result<()> {
+        let mut catalog = InstrumentCatalog::new();
+        catalog.insert(share("sber", 10, 0.01));
+        catalog.insert(share("gazp", 10, 1.0));
+        catalog.insert(Currency {
+            uid: "rub".to_string(),
+            currency: "rub".to_string(),
+            iso_currency_name: "rub".to_string(),
+            ..Default::default()
+        });
+
+        // 30000 in shares and 10000 in cash
+        let portfolio = PortfolioResponse {
+            positions: vec![
+                position("sber", "share", 100, 300.0),
+                position("rub", "currency", 10000, 1.0),
+            ],
+            ..Default::default()
+        };
+        let rebalancer = Rebalancer::new("account", PortfolioAnalyzer::new(catalog))
+            .with_targets([("sber", 0.5), ("gazp", 0.5)])?;
+        let inputs = RebalanceInputs::new(10000.0).with_price("gazp", 150.0);
+
+        let plan = rebalancer.plan(&portfolio, &inputs);
+
+        // Sell 3 of 3.33 lots, then buy 12 of 13.33 lots for 19000 of cash
+        assert_eq!(plan.orders.len(), 2);
+        assert_eq!(plan.orders[0].direction, OrderDirection::Sell as i32);
+        assert_eq!(plan.orders[0].quantity, 3);
+        assert_eq!(plan.orders[1].instrument_id, "gazp");
+        assert_eq!(plan.orders[1].quantity, 12);
+        assert_eq!(plan.orders[1].order_type, OrderType::Market as i32);
+        assert_eq!(plan.lines[1].constraint, Some(Constraint::Cash));
+        assert_eq!(plan.lines[1].quantity, 120);
+        assert_eq!(plan.cash_after, 1000.0);
+
+        let max_lots = GetMaxLotsResponse {
+            buy_limits: Some(BuyLimitsView {
+                buy_max_lots: 10,
+                buy_max_market_lots: 10,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let inputs = inputs.with_max_lots("gazp", &max_lots);
+        let plan = rebalancer.with_limit_orders(0.01).plan(&portfolio, &inputs);
+
+        assert_eq!(plan.orders[1].quantity, 10);
+        assert_eq!(plan.lines[1].constraint, Some(Constraint::MaxLots));
+        // 151.5 rounded up to step of 1
+        assert_eq!(
+            plan.orders[1].price.as_ref().map(Quotation::to_f64),
+            Some(152.0)
+        );
+        assert_eq!(
+            plan.orders[0].price.as_ref().map(Quotation::to_f64),
+            Some(297.0)
+        );
+        assert!(plan.to_string().contains("MaxLots"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rebalancer_targets_err() -> Result<()> {
+        let rebalancer =
+            Rebalancer::new("account", PortfolioAnalyzer::new(InstrumentCatalog::new()))
+                .with_targets([("sber", 0.1), ("gazp", 0.2), ("lkoh", 0.7)])?;
+
+        assert!(matches!(
+            rebalancer.clone().with_target("sber", -0.1),
+            Err(crate::Error::InvalidTargetWeights(_))
+        ));
+        assert!(matches!(
+            rebalancer.clone().with_target("ydex", 0.01),
+            Err(crate::Error::InvalidTargetWeights(_))
+        ));
+        // Replacing weight keeps sum
+        assert_eq!(rebalancer.with_target("lkoh", 0.6)?.targets()["lkoh"], 0.6);
+
+        Ok(())
+    }
+}
+
+// endregion: --- Tests
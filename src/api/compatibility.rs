@@ -0,0 +1,264 @@
+//! Comparison of proto contract revisions
+//!
+//! Compares two `FileDescriptorSet`s, e.g. parsed from contract directories by `contracts_diff` example,
+//! and reports added and removed RPCs and fields before upgrading contracts.
+
+// region:    --- Modules
+
+use prost_types::{DescriptorProto, FieldDescriptorProto, FileDescriptorSet};
+use std::collections::BTreeMap;
+
+// endregion: --- Modules
+
+/// Difference between two contract revisions
+///
+/// RPCs are named as `package.Service/Method`, fields as `package.Message.field`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ContractDiff {
+    pub added_rpcs: Vec<String>,
+    pub removed_rpcs: Vec<String>,
+    pub added_fields: Vec<String>,
+    pub removed_fields: Vec<String>,
+    /// Fields with the same name, but different number, type or label
+    pub changed_fields: Vec<String>,
+}
+
+impl ContractDiff {
+    /// Compares old and new contract revisions
+    pub fn between(old: &FileDescriptorSet, new: &FileDescriptorSet) -> Self {
+        let (old_rpcs, new_rpcs) = (rpcs(old), rpcs(new));
+        let (old_fields, new_fields) = (fields(old), fields(new));
+
+        let (added_rpcs, removed_rpcs, _) = compare(&old_rpcs, &new_rpcs);
+        let (added_fields, removed_fields, changed_fields) = compare(&old_fields, &new_fields);
+
+        Self {
+            added_rpcs,
+            removed_rpcs,
+            added_fields,
+            removed_fields,
+            changed_fields,
+        }
+    }
+
+    /// Returns `true` if revisions have the same RPCs and fields
+    pub fn is_empty(&self) -> bool {
+        self.added_rpcs.is_empty()
+            && self.removed_rpcs.is_empty()
+            && self.added_fields.is_empty()
+            && self.removed_fields.is_empty()
+            && self.changed_fields.is_empty()
+    }
+
+    /// Returns `true` if upgrade removes or changes anything existing code may use
+    pub fn is_breaking(&self) -> bool {
+        !self.removed_rpcs.is_empty()
+            || !self.removed_fields.is_empty()
+            || !self.changed_fields.is_empty()
+    }
+}
+
+impl core::fmt::Display for ContractDiff {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let sections = [
+            ("Added RPCs", &self.added_rpcs),
+            ("Removed RPCs", &self.removed_rpcs),
+            ("Added fields", &self.added_fields),
+            ("Removed fields", &self.removed_fields),
+            ("Changed fields", &self.changed_fields),
+        ];
+
+        for (title, items) in sections.into_iter().filter(|(_, items)| !items.is_empty()) {
+            writeln!(f, "{title}:")?;
+
+            for item in items {
+                writeln!(f, "  {item}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// region:    --- Helpers
+
+/// Returns added, removed and changed keys
+fn compare<V: PartialEq>(
+    old: &BTreeMap<String, V>,
+    new: &BTreeMap<String, V>,
+) -> (Vec<String>, Vec<String>, Vec<String>) {
+    let added = new.keys().filter(|key| !old.contains_key(*key)).cloned();
+    let removed = old.keys().filter(|key| !new.contains_key(*key)).cloned();
+    let changed = old
+        .iter()
+        .filter(|(key, value)| new.get(*key).is_some_and(|new| new != *value))
+        .map(|(key, _)| key.clone());
+
+    (added.collect(), removed.collect(), changed.collect())
+}
+
+/// RPCs with input and output types
+fn rpcs(set: &FileDescriptorSet) -> BTreeMap<String, (String, String)> {
+    let mut rpcs = BTreeMap::new();
+
+    for file in &set.file {
+        for service in &file.service {
+            for method in &service.method {
+                let name = format!(
+                    "{}/{}",
+                    qualified(file.package(), service.name()),
+                    method.name()
+                );
+                let types = (
+                    method.input_type().to_string(),
+                    method.output_type().to_string(),
+                );
+
+                rpcs.insert(name, types);
+            }
+        }
+    }
+
+    rpcs
+}
+
+/// Fields of all messages, including nested ones
+fn fields(set: &FileDescriptorSet) -> BTreeMap<String, FieldSignature> {
+    fn collect(
+        prefix: &str,
+        message: &DescriptorProto,
+        fields: &mut BTreeMap<String, FieldSignature>,
+    ) {
+        let name = qualified(prefix, message.name());
+
+        for field in &message.field {
+            fields.insert(
+                format!("{name}.{}", field.name()),
+                FieldSignature::from(field),
+            );
+        }
+
+        for nested in &message.nested_type {
+            collect(&name, nested, fields);
+        }
+    }
+
+    let mut fields = BTreeMap::new();
+
+    for file in &set.file {
+        for message in &file.message_type {
+            collect(file.package(), message, &mut fields);
+        }
+    }
+
+    fields
+}
+
+/// Parts of field definition affecting wire and generated code compatibility
+#[derive(PartialEq)]
+struct FieldSignature {
+    number: i32,
+    label: Option<i32>,
+    r#type: Option<i32>,
+    type_name: String,
+}
+
+impl From<&FieldDescriptorProto> for FieldSignature {
+    fn from(field: &FieldDescriptorProto) -> Self {
+        Self {
+            number: field.number(),
+            label: field.label,
+            r#type: field.r#type,
+            type_name: field.type_name().to_string(),
+        }
+    }
+}
+
+fn qualified(prefix: &str, name: &str) -> String {
+    if prefix.is_empty() {
+        name.to_string()
+    } else {
+        format!("{prefix}.{name}")
+    }
+}
+
+// endregion: --- Helpers
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    type Error = Box<dyn std::error::Error>;
+    type Result<T> = core::result::Result<T, Error>; // For tests.
+
+    use super::*;
+    use prost_types::{
+        field_descriptor_proto::Type, FileDescriptorProto, MethodDescriptorProto,
+        ServiceDescriptorProto,
+    };
+
+    fn field(name: &str, number: i32, r#type: Type) -> FieldDescriptorProto {
+        FieldDescriptorProto {
+            name: Some(name.to_string()),
+            number: Some(number),
+            r#type: Some(r#type as i32),
+            ..Default::default()
+        }
+    }
+
+    fn contract(methods: &[&str], fields: Vec<FieldDescriptorProto>) -> FileDescriptorSet {
+        FileDescriptorSet {
+            file: vec![FileDescriptorProto {
+                package: Some("api".to_string()),
+                message_type: vec![DescriptorProto {
+                    name: Some("Order".to_string()),
+                    field: fields,
+                    ..Default::default()
+                }],
+                service: vec![ServiceDescriptorProto {
+                    name: Some("OrdersService".to_string()),
+                    method: methods
+                        .iter()
+                        .map(|name| MethodDescriptorProto {
+                            name: Some(name.to_string()),
+                            ..Default::default()
+                        })
+                        .collect(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+        }
+    }
+
+    #[test]
+    fn test_contract_diff_ok() -> Result<()> {
+        let old = contract(
+            &["PostOrder", "CancelOrder"],
+            vec![field("id", 1, Type::String), field("lots", 2, Type::Int32)],
+        );
+        let new = contract(
+            &["PostOrder", "ReplaceOrder"],
+            vec![
+                field("id", 1, Type::String),
+                field("lots", 2, Type::Int64),
+                field("price", 3, Type::Double),
+            ],
+        );
+
+        let diff = ContractDiff::between(&old, &new);
+
+        assert_eq!(diff.added_rpcs, vec!["api.OrdersService/ReplaceOrder"]);
+        assert_eq!(diff.removed_rpcs, vec!["api.OrdersService/CancelOrder"]);
+        assert_eq!(diff.added_fields, vec!["api.Order.price"]);
+        assert!(diff.removed_fields.is_empty());
+        assert_eq!(diff.changed_fields, vec!["api.Order.lots"]);
+        assert!(diff.is_breaking());
+
+        assert!(ContractDiff::between(&new, &new).is_empty());
+
+        Ok(())
+    }
+}
+
+// endregion: --- Tests
@@ -1,2 +1,3 @@
+pub mod compatibility;
 #[cfg(feature = "v1")]
 pub mod v1;
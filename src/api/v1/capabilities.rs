@@ -0,0 +1,207 @@
+//! Token capabilities, for failing fast instead of getting `PERMISSION_DENIED` later
+
+#![allow(clippy::result_large_err)]
+
+// region:    --- Modules
+
+use super::{
+    users_service_client::UsersServiceClient, AccessLevel, Account, GetAccountsRequest,
+    GetAccountsResponse, GetInfoRequest, GetInfoResponse, GetUserTariffRequest,
+    GetUserTariffResponse, InvestApi, InvestApiTrait, StreamLimit, UnaryLimit,
+};
+use crate::{Error, Result};
+use tonic::body::BoxBody;
+use tonic::client::GrpcService;
+use tonic::codegen::{Body, Bytes, StdError};
+use tower_service::Service;
+
+// endregion: --- Modules
+
+/// Summary of what token is allowed to do
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TokenCapabilities {
+    pub qualified_investor: bool,
+    pub premium: bool,
+    /// Instruments and features available to user without testing
+    pub qualified_for_work_with: Vec<String>,
+    pub tariff: String,
+    /// Accounts accessible with token, see `Account::access_level_value`
+    pub accounts: Vec<Account>,
+    pub unary_limits: Vec<UnaryLimit>,
+    pub stream_limits: Vec<StreamLimit>,
+}
+
+impl TokenCapabilities {
+    /// Creates summary from `UsersService` responses
+    pub fn from_responses(
+        info: GetInfoResponse,
+        accounts: GetAccountsResponse,
+        tariff: GetUserTariffResponse,
+    ) -> Self {
+        Self {
+            qualified_investor: info.qual_status,
+            premium: info.prem_status,
+            qualified_for_work_with: info.qualified_for_work_with,
+            tariff: info.tariff,
+            accounts: accounts.accounts,
+            unary_limits: tariff.unary_limits,
+            stream_limits: tariff.stream_limits,
+        }
+    }
+
+    /// Returns access level of account, `None` if account is not accessible
+    pub fn access_level(&self, account_id: &str) -> Option<AccessLevel> {
+        self.accounts
+            .iter()
+            .find(|account| account.id == account_id)
+            .and_then(|account| account.access_level_value().known())
+    }
+
+    /// Returns `true` if orders can be placed on account
+    pub fn can_trade(&self, account_id: &str) -> bool {
+        self.access_level(account_id) == Some(AccessLevel::AccountAccessLevelFullAccess)
+    }
+
+    /// Returns `true` if token has no full access to any account
+    pub fn is_read_only(&self) -> bool {
+        !self.accounts.iter().any(|account| {
+            account.access_level_value() == AccessLevel::AccountAccessLevelFullAccess
+        })
+    }
+
+    /// Returns error if orders cannot be placed on account
+    pub fn ensure_can_trade(&self, account_id: &str) -> Result<()> {
+        if self.can_trade(account_id) {
+            Ok(())
+        } else {
+            Err(Error::AccountAccessDenied(account_id.to_string()))
+        }
+    }
+
+    /// Returns requests per minute limit of unary method, e.g. `OrdersService/PostOrder`
+    ///
+    /// Method is matched exactly, with or without package name.
+    pub fn limit_per_minute(&self, method: &str) -> Option<i32> {
+        self.unary_limits
+            .iter()
+            .find(|limit| {
+                limit.methods.iter().any(|name| {
+                    let short = name
+                        .rsplit_once('.')
+                        .map_or(name.as_str(), |(_, short)| short);
+                    name == method || short == method
+                })
+            })
+            .map(|limit| limit.limit_per_minute)
+    }
+
+    /// Requests user info, accounts and tariff with client over any transport
+    pub async fn inspect<T>(users: &mut UsersServiceClient<T>) -> Result<Self>
+    where
+        T: GrpcService<BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        let info = users.get_info(GetInfoRequest {}).await?.into_inner();
+        let accounts = users
+            .get_accounts(GetAccountsRequest { status: None })
+            .await?
+            .into_inner();
+        let tariff = users
+            .get_user_tariff(GetUserTariffRequest {})
+            .await?
+            .into_inner();
+
+        Ok(Self::from_responses(info, accounts, tariff))
+    }
+}
+
+impl InvestApi {
+    /// Requests user info, accounts and tariff to find out what token is allowed to do
+    ///
+    /// Service is channel or channel with layers, e.g. `OtelChannel` or `InFlightChannel`.
+    pub async fn inspect_token<T, B>(&self, service: T) -> Result<TokenCapabilities>
+    where
+        T: Service<http::Request<BoxBody>, Response = http::Response<B>>,
+        T::Error: Into<StdError>,
+        B: Body<Data = Bytes> + Default + Send + 'static,
+        B::Error: Into<StdError>,
+    {
+        let mut users = UsersServiceClient::new(self.intercept(service));
+
+        TokenCapabilities::inspect(&mut users).await
+    }
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    type Error = Box<dyn std::error::Error>;
+    type Result<T> = core::result::Result<T, Error>; // For tests.
+
+    use super::*;
+
+    #[test]
+    fn test_token_capabilities_ok() -> Result<()> {
+        let capabilities = TokenCapabilities::from_responses(
+            GetInfoResponse {
+                qual_status: true,
+                tariff: "investor".to_string(),
+                ..Default::default()
+            },
+            GetAccountsResponse {
+                accounts: vec![
+                    Account {
+                        id: "full".to_string(),
+                        ..Default::default()
+                    }
+                    .with_access_level(AccessLevel::AccountAccessLevelFullAccess),
+                    Account {
+                        id: "readonly".to_string(),
+                        ..Default::default()
+                    }
+                    .with_access_level(AccessLevel::AccountAccessLevelReadOnly),
+                ],
+            },
+            GetUserTariffResponse {
+                unary_limits: vec![UnaryLimit {
+                    limit_per_minute: 300,
+                    methods: vec![
+                        "tinkoff.public.invest.api.contract.v1.OrdersService/PostOrder".to_string(),
+                        "tinkoff.public.invest.api.contract.v1.StopOrdersService/PostStopOrder"
+                            .to_string(),
+                    ],
+                }],
+                stream_limits: vec![],
+            },
+        );
+
+        assert!(capabilities.qualified_investor);
+        assert!(!capabilities.is_read_only());
+        assert!(capabilities.can_trade("full"));
+        assert!(capabilities.ensure_can_trade("readonly").is_err());
+        assert_eq!(capabilities.access_level("missing"), None);
+        assert_eq!(
+            capabilities.limit_per_minute("OrdersService/PostOrder"),
+            Some(300)
+        );
+        assert_eq!(
+            capabilities.limit_per_minute(
+                "tinkoff.public.invest.api.contract.v1.StopOrdersService/PostStopOrder"
+            ),
+            Some(300)
+        );
+        // Suffix of other method is not matched
+        assert_eq!(capabilities.limit_per_minute("PostOrder"), None);
+        assert_eq!(
+            capabilities.limit_per_minute("OrdersService/PostStopOrder"),
+            None
+        );
+
+        Ok(())
+    }
+}
+
+// endregion: --- Tests
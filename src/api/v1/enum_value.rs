@@ -0,0 +1,98 @@
+//! Typed value of proto enumeration fields
+
+/// Value of proto enumeration field
+///
+/// Prost stores enumerations as `i32` and falls back to default value for numbers
+/// unknown to the contracts, `EnumValue` keeps such numbers as `Unknown`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EnumValue<E> {
+    /// Value known to the contracts
+    Known(E),
+    /// Value unknown to the contracts, e.g. added on server after generation
+    Unknown(i32),
+}
+
+impl<E> EnumValue<E> {
+    /// Returns known value or `None`
+    pub fn known(self) -> Option<E> {
+        match self {
+            Self::Known(value) => Some(value),
+            Self::Unknown(_) => None,
+        }
+    }
+
+    /// Returns `true` if value is unknown to the contracts
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Self::Unknown(_))
+    }
+}
+
+impl<E: Copy + Into<i32>> EnumValue<E> {
+    /// Returns raw value as sent over the wire
+    pub fn value(&self) -> i32 {
+        match self {
+            Self::Known(value) => (*value).into(),
+            Self::Unknown(value) => *value,
+        }
+    }
+}
+
+impl<E: TryFrom<i32>> From<i32> for EnumValue<E> {
+    fn from(value: i32) -> Self {
+        E::try_from(value)
+            .map(Self::Known)
+            .unwrap_or(Self::Unknown(value))
+    }
+}
+
+impl<E: PartialEq> PartialEq<E> for EnumValue<E> {
+    fn eq(&self, other: &E) -> bool {
+        matches!(self, Self::Known(value) if value == other)
+    }
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    type Error = Box<dyn std::error::Error>;
+    type Result<T> = core::result::Result<T, Error>; // For tests.
+
+    use super::*;
+    use crate::api::v1::{OrderDirection, OrderType, PostOrderRequest};
+
+    #[test]
+    fn test_enum_value_from_i32_ok() -> Result<()> {
+        let fx_known = EnumValue::<OrderDirection>::from(1);
+        let fx_unknown = EnumValue::<OrderDirection>::from(42);
+
+        assert_eq!(fx_known, OrderDirection::Buy);
+        assert_eq!(fx_unknown, EnumValue::Unknown(42));
+        assert_eq!(fx_unknown.value(), 42);
+        assert_eq!(fx_unknown.known(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_typed_accessors_ok() -> Result<()> {
+        let request = PostOrderRequest::default()
+            .with_direction(OrderDirection::Sell)
+            .with_order_type(OrderType::Market);
+
+        assert_eq!(request.direction, OrderDirection::Sell as i32);
+        assert_eq!(request.direction_value(), OrderDirection::Sell);
+        assert_eq!(request.order_type_value(), OrderType::Market);
+
+        let request = PostOrderRequest {
+            direction: 100,
+            ..request
+        };
+
+        assert_eq!(request.direction_value(), EnumValue::Unknown(100));
+
+        Ok(())
+    }
+}
+
+// endregion: --- Tests
@@ -1,9 +1,10 @@
 // region:    --- Modules
 
+use std::sync::Arc;
 use tonic::service::Interceptor;
-use uuid::Uuid;
 
-use crate::extensions::MetadataExt;
+use super::InterceptorStack;
+use crate::TokenProvider;
 
 // endregion: --- Modules
 
@@ -11,7 +12,8 @@ use crate::extensions::MetadataExt;
 
 /// Interceptor data for modifying request
 pub struct InterceptorData {
-    pub token: String,
+    /// Token is requested from provider on each request
+    pub token: Arc<dyn TokenProvider>,
     pub app_name: Option<String>,
 }
 
@@ -23,14 +25,27 @@ pub trait IntercemptorWithNew<D>: Interceptor {
     fn new(data: D) -> Self;
 }
 
-/// Custom implementation for Tinkoff Interceptor
+/// Default interceptor, `InterceptorStack::tinkoff` built from `InterceptorData`
+///
+/// Sets `authorization`, `x-request-id` and `x-app-name` headers with `AuthInterceptor`,
+/// `RequestIdInterceptor` and `AppNameInterceptor`.
+#[derive(Debug, Clone)]
 pub struct TinkoffInterceptor {
-    pub data: InterceptorData,
+    stack: InterceptorStack,
+}
+
+impl TinkoffInterceptor {
+    /// Returns stack for adding more interceptors
+    pub fn into_stack(self) -> InterceptorStack {
+        self.stack
+    }
 }
 
 impl IntercemptorWithNew<InterceptorData> for TinkoffInterceptor {
     fn new(data: InterceptorData) -> Self {
-        Self { data }
+        Self {
+            stack: InterceptorStack::tinkoff(data.token, data.app_name),
+        }
     }
 }
 
@@ -39,25 +54,6 @@ impl Interceptor for TinkoffInterceptor {
         &mut self,
         request: tonic::Request<()>,
     ) -> std::result::Result<tonic::Request<()>, tonic::Status> {
-        let mut request = request;
-        let metadata = request.metadata_mut();
-
-        metadata.safe_append(
-            "authorization",
-            format!("Bearer {}", self.data.token),
-            "Failed to insert token",
-        );
-
-        metadata.safe_append(
-            "x-request-id",
-            Uuid::new_v4().to_string(),
-            "Failed to insert request id",
-        );
-
-        if let Some(app_name) = &self.data.app_name {
-            metadata.safe_append("x-app-name", app_name, "Failed to insert app name");
-        }
-
-        Ok(request)
+        self.stack.call(request)
     }
 }
@@ -1,18 +1,45 @@
+#![allow(clippy::result_large_err)]
+
 // region:    --- Modules
 
 // Modules
 #[path = "google.api.rs"]
 pub mod google_api;
 
+mod capabilities;
+mod enum_value;
 mod interceptor;
+mod money;
+mod stack;
+#[cfg(not(generated_protos))]
+#[allow(clippy::large_enum_variant, clippy::tabs_in_doc_comments)]
 mod protos;
+#[cfg(generated_protos)]
+#[allow(clippy::large_enum_variant, clippy::tabs_in_doc_comments)]
+mod protos {
+    include!(concat!(env!("OUT_DIR"), "/protos.rs"));
+}
+#[cfg(feature = "serde")]
+pub mod serialization;
+
+// -- Modules per contract file, e.g. `instruments`, `marketdata`
+include!(concat!(env!("OUT_DIR"), "/modules.rs"));
+
+// -- Contract version, e.g. `CONTRACT_VERSION`
+include!(concat!(env!("OUT_DIR"), "/contract.rs"));
 
 // -- Flatten
+pub use capabilities::TokenCapabilities;
+pub use enum_value::EnumValue;
 pub use interceptor::{IntercemptorWithNew, InterceptorData, TinkoffInterceptor};
 pub use protos::*;
+pub use stack::{
+    AppNameInterceptor, AuthInterceptor, InterceptorStack, RequestIdInterceptor,
+    TracingInterceptor,
+};
 
 // -- Use
-use crate::{config, Result};
+use crate::{config, ApiConfig, Profile, Result, SecretToken, TokenProvider};
 use instruments_service_client::InstrumentsServiceClient;
 use market_data_service_client::MarketDataServiceClient;
 use market_data_stream_service_client::MarketDataStreamServiceClient;
@@ -21,6 +48,7 @@ use orders_service_client::OrdersServiceClient;
 use orders_stream_service_client::OrdersStreamServiceClient;
 use sandbox_service_client::SandboxServiceClient;
 use signal_service_client::SignalServiceClient;
+use std::sync::Arc;
 use stop_orders_service_client::StopOrdersServiceClient;
 use tonic::{service::interceptor::InterceptedService, transport::Channel};
 use users_service_client::UsersServiceClient;
@@ -71,6 +99,15 @@ pub trait InvestApiTrait<D, I: IntercemptorWithNew<D>> {
     /// Creates interceptor data for modifying request
     fn interceptor_data(&self) -> D;
 
+    /// Wraps service, e.g. channel with tower layers, into interceptor for creating clients
+    ///
+    /// ```ignore
+    /// let mut orders = OrdersServiceClient::new(api.intercept(otel_channel.clone()));
+    /// ```
+    fn intercept<S>(&self, service: S) -> InterceptedService<S, I> {
+        InterceptedService::new(service, I::new(self.interceptor_data()))
+    }
+
     // endregion: --- Getters
 
     // region:    --- Clients
@@ -139,28 +176,21 @@ pub trait InvestApiTrait<D, I: IntercemptorWithNew<D>> {
 
 /// Tinkoff API client to communicate with Tinkoff Invest API
 /// # Fields
-/// * `token` - API token
+/// * `token` - API token provider, queried on each request
 /// * `app_name` - application name
 #[derive(Debug, Clone)]
 pub struct InvestApi {
-    pub token: String,
+    pub token: Arc<dyn TokenProvider>,
     pub app_name: std::option::Option<String>,
 }
 
-impl Default for InvestApi {
-    /// Creates new TinkoffApi with default token from config
-    fn default() -> Self {
-        Self::with_token(&config().TINKOFF_TOKEN)
-    }
-}
-
 impl InvestApiTrait<InterceptorData, TinkoffInterceptor> for InvestApi {
     // region:    --- Constructors
 
     /// Creates new TinkoffApi with token
     fn with_token(token: impl Into<String>) -> Self {
         Self {
-            token: token.into(),
+            token: Arc::new(SecretToken::new(token)),
             app_name: None,
         }
     }
@@ -180,11 +210,97 @@ impl InvestApiTrait<InterceptorData, TinkoffInterceptor> for InvestApi {
 }
 
 impl InvestApi {
+    /// Creates new TinkoffApi with token and application name from global config
+    pub fn new() -> Result<Self> {
+        Ok(Self::from_config(config()?))
+    }
+
+    /// Creates new TinkoffApi with token and application name from config
+    pub fn from_config(config: &ApiConfig) -> Self {
+        Self {
+            token: Arc::new(config.token.clone()),
+            app_name: config.app_name.clone(),
+        }
+    }
+
+    /// Creates new TinkoffApi with token and application name from profile
+    pub fn from_profile(profile: &Profile) -> Self {
+        Self {
+            token: Arc::new(profile.token.clone()),
+            app_name: profile.app_name.clone(),
+        }
+    }
+
+    /// Creates new TinkoffApi with token provider, e.g. to rotate token without rebuilding clients
+    pub fn with_token_provider(provider: impl TokenProvider + 'static) -> Self {
+        Self {
+            token: Arc::new(provider),
+            app_name: None,
+        }
+    }
+
     /// Sets application name
     pub fn with_app_name(mut self, app_name: impl Into<String>) -> Self {
         self.app_name = Some(app_name.into());
         self
     }
+
+    /// Creates interceptor stack used by `TinkoffInterceptor`, for adding own interceptors
+    pub fn interceptor_stack(&self) -> InterceptorStack {
+        InterceptorStack::tinkoff(self.token.clone(), self.app_name.clone())
+    }
+
+    /// Creates api, which clients use interceptor stack
+    pub fn layered(&self) -> LayeredApi {
+        LayeredApi::new(self.interceptor_stack())
+    }
 }
 
 // endregion: --- Invest Api
+
+// region:    --- Layered Api
+
+/// Tinkoff API client with composable interceptors
+///
+/// ```ignore
+/// let api = InvestApi::new()?
+///     .layered()
+///     .with(TracingInterceptor)
+///     .with(|request: tonic::Request<()>| Ok(request));
+/// ```
+#[derive(Debug, Clone)]
+pub struct LayeredApi {
+    pub interceptors: InterceptorStack,
+}
+
+impl LayeredApi {
+    /// Creates api with interceptor stack
+    pub fn new(interceptors: InterceptorStack) -> Self {
+        Self { interceptors }
+    }
+
+    /// Adds interceptor to the end of the stack
+    pub fn with<I>(mut self, interceptor: I) -> Self
+    where
+        I: tonic::service::Interceptor + Clone + Send + Sync + 'static,
+    {
+        self.interceptors = self.interceptors.with(interceptor);
+        self
+    }
+}
+
+impl InvestApiTrait<InterceptorStack, InterceptorStack> for LayeredApi {
+    /// Creates new LayeredApi with token, request id and no application name
+    fn with_token(token: impl Into<String>) -> Self {
+        Self::new(InterceptorStack::tinkoff(
+            Arc::new(SecretToken::new(token)),
+            None,
+        ))
+    }
+
+    fn interceptor_data(&self) -> InterceptorStack {
+        self.interceptors.clone()
+    }
+}
+
+// endregion: --- Layered Api
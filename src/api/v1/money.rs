@@ -0,0 +1,241 @@
+//! Conversions for `Quotation` and `MoneyValue`
+
+use super::{MoneyValue, Quotation};
+use crate::{Error, Result};
+use std::str::FromStr;
+
+/// Number of nano units in one unit
+const NANO: i64 = 1_000_000_000;
+
+// region:    --- Quotation
+
+impl Quotation {
+    /// Creates a new `Quotation` from units and nano parts
+    pub fn new(units: i64, nano: i32) -> Self {
+        Self { units, nano }
+    }
+
+    /// Creates a new `Quotation` from `f64`, rounding to nano precision
+    pub fn from_f64(value: f64) -> Self {
+        let (units, nano) = split_f64(value);
+
+        Self { units, nano }
+    }
+
+    /// Converts `Quotation` to `f64`
+    pub fn to_f64(&self) -> f64 {
+        join_f64(self.units, self.nano)
+    }
+
+    /// Converts `Quotation` to exact decimal string, e.g. `-12.05`
+    pub fn to_decimal_string(&self) -> String {
+        decimal_string(self.units, self.nano)
+    }
+
+    /// Creates a new `Quotation` from number of nano units, units are saturated on overflow
+    pub fn from_nanos(nanos: i128) -> Self {
+        let units = (nanos / NANO as i128).clamp(i64::MIN as i128, i64::MAX as i128);
+
+        Self::new(units as i64, (nanos % NANO as i128) as i32)
+    }
+
+    /// Returns value in nano units, for exact arithmetic
+    pub fn to_nanos(&self) -> i128 {
+        self.units as i128 * NANO as i128 + self.nano as i128
+    }
+}
+
+impl core::fmt::Display for Quotation {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.to_decimal_string())
+    }
+}
+
+impl FromStr for Quotation {
+    type Err = Error;
+
+    /// Parses exact decimal string, e.g. `-12.05`, with up to 9 fractional digits
+    fn from_str(value: &str) -> Result<Self> {
+        let wrong_format = || Error::WrongDecimalFormat(value.to_string());
+
+        let (negative, digits) = match value.trim().strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, value.trim().trim_start_matches('+')),
+        };
+
+        let (units, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+
+        if units.is_empty() && fraction.is_empty()
+            || fraction.len() > 9
+            || !units
+                .chars()
+                .chain(fraction.chars())
+                .all(|c| c.is_ascii_digit())
+        {
+            return Err(wrong_format());
+        }
+
+        let units: i64 = if units.is_empty() {
+            0
+        } else {
+            units.parse().map_err(|_| wrong_format())?
+        };
+        let nano: i32 = if fraction.is_empty() {
+            0
+        } else {
+            format!("{fraction:0<9}")
+                .parse()
+                .map_err(|_| wrong_format())?
+        };
+
+        if negative {
+            Ok(Self::new(-units, -nano))
+        } else {
+            Ok(Self::new(units, nano))
+        }
+    }
+}
+
+// endregion: --- Quotation
+
+// region:    --- MoneyValue
+
+impl MoneyValue {
+    /// Creates a new `MoneyValue` from currency, units and nano parts
+    pub fn new(currency: impl Into<String>, units: i64, nano: i32) -> Self {
+        Self {
+            currency: currency.into(),
+            units,
+            nano,
+        }
+    }
+
+    /// Creates a new `MoneyValue` from `f64`, rounding to nano precision
+    pub fn from_f64(currency: impl Into<String>, value: f64) -> Self {
+        let (units, nano) = split_f64(value);
+
+        Self::new(currency, units, nano)
+    }
+
+    /// Converts amount to `f64`, currency is ignored
+    pub fn to_f64(&self) -> f64 {
+        join_f64(self.units, self.nano)
+    }
+
+    /// Converts amount to exact decimal string, currency is ignored
+    pub fn to_decimal_string(&self) -> String {
+        decimal_string(self.units, self.nano)
+    }
+
+    /// Returns amount as `Quotation`, dropping currency
+    pub fn to_quotation(&self) -> Quotation {
+        Quotation::new(self.units, self.nano)
+    }
+
+    /// Returns amount in nano units, currency is ignored
+    pub fn to_nanos(&self) -> i128 {
+        self.to_quotation().to_nanos()
+    }
+}
+
+impl core::fmt::Display for MoneyValue {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} {}", self.to_decimal_string(), self.currency)
+    }
+}
+
+// endregion: --- MoneyValue
+
+// region:    --- Helpers
+
+fn split_f64(value: f64) -> (i64, i32) {
+    let total = (value * NANO as f64).round() as i64;
+
+    (total / NANO, (total % NANO) as i32)
+}
+
+fn join_f64(units: i64, nano: i32) -> f64 {
+    units as f64 + nano as f64 / NANO as f64
+}
+
+fn decimal_string(units: i64, nano: i32) -> String {
+    let negative = units < 0 || nano < 0;
+    let units = units.unsigned_abs();
+    let nano = nano.unsigned_abs();
+    let sign = if negative { "-" } else { "" };
+
+    if nano == 0 {
+        return format!("{sign}{units}");
+    }
+
+    let fraction = format!("{nano:09}");
+
+    format!("{sign}{units}.{}", fraction.trim_end_matches('0'))
+}
+
+// endregion: --- Helpers
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    type Error = Box<dyn std::error::Error>;
+    type Result<T> = core::result::Result<T, Error>; // For tests.
+
+    use super::*;
+
+    #[test]
+    fn test_quotation_decimal_string_ok() -> Result<()> {
+        assert_eq!(
+            Quotation::new(114, 250_000_000).to_decimal_string(),
+            "114.25"
+        );
+        assert_eq!(Quotation::new(-1, -5_000_000).to_decimal_string(), "-1.005");
+        assert_eq!(Quotation::new(0, -10).to_decimal_string(), "-0.00000001");
+        assert_eq!(Quotation::new(7, 0).to_decimal_string(), "7");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_quotation_from_str_ok() -> Result<()> {
+        assert_eq!(
+            "114.25".parse::<Quotation>()?,
+            Quotation::new(114, 250_000_000)
+        );
+        assert_eq!(
+            "-0.5".parse::<Quotation>()?,
+            Quotation::new(0, -500_000_000)
+        );
+        assert_eq!("42".parse::<Quotation>()?, Quotation::new(42, 0));
+        assert!("1.2.3".parse::<Quotation>().is_err());
+        assert!("0.0000000001".parse::<Quotation>().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_quotation_nanos_ok() -> Result<()> {
+        let value = Quotation::new(-1, -5_000_000);
+
+        assert_eq!(value.to_nanos(), -1_005_000_000);
+        assert_eq!(Quotation::from_nanos(value.to_nanos()), value);
+        assert_eq!(Quotation::from_nanos(i128::MAX).units, i64::MAX);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_money_value_f64_roundtrip_ok() -> Result<()> {
+        let fx_value = MoneyValue::from_f64("rub", -12.35);
+
+        assert_eq!(fx_value.units, -12);
+        assert_eq!(fx_value.nano, -350_000_000);
+        assert_eq!(fx_value.to_f64(), -12.35);
+        assert_eq!(fx_value.to_string(), "-12.35 rub");
+
+        Ok(())
+    }
+}
+
+// endregion: --- Tests
@@ -23,12 +23,17 @@ pub struct Quotation {
     pub nano: i32,
 }
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct PingRequest {
     /// Время формирования запроса
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub time: ::core::option::Option<::prost_types::Timestamp>,
 }
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct PingDelaySettings {
     /// Задержка пинг сообщений milliseconds 5000-180000, default 120000
     #[prost(int32, optional, tag = "15")]
@@ -36,18 +41,24 @@ pub struct PingDelaySettings {
 }
 /// Проверка активности стрима.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct Ping {
     /// Время проверки.
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub time: ::core::option::Option<::prost_types::Timestamp>,
     /// Идентификатор соединения.
     #[prost(string, tag = "2")]
     pub stream_id: ::prost::alloc::string::String,
     /// Время формирования запроса
     #[prost(message, optional, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub ping_request_time: ::core::option::Option<::prost_types::Timestamp>,
 }
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct Page {
     /// Максимальное число возвращаемых записей.
     #[prost(int32, tag = "1")]
@@ -57,6 +68,8 @@ pub struct Page {
     pub page_number: i32,
 }
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct PageResponse {
     /// Максимальное число возвращаемых записей.
     #[prost(int32, tag = "1")]
@@ -69,15 +82,20 @@ pub struct PageResponse {
     pub total_count: i32,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct ResponseMetadata {
     /// Идентификатор трекинга.
     #[prost(string, tag = "42")]
     pub tracking_id: ::prost::alloc::string::String,
     /// Серверное время.
     #[prost(message, optional, tag = "43")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub server_time: ::core::option::Option<::prost_types::Timestamp>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct BrandData {
     /// Логотип инструмента. Имя файла для получения логотипа.
     #[prost(string, tag = "1")]
@@ -90,6 +108,8 @@ pub struct BrandData {
     pub text_color: ::prost::alloc::string::String,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct ErrorDetail {
     /// Код ошибки.
     #[prost(string, tag = "1")]
@@ -162,6 +182,15 @@ impl InstrumentType {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for InstrumentType {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Статус запрашиваемых инструментов.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -195,6 +224,15 @@ impl InstrumentStatus {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for InstrumentStatus {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Режим торгов инструмента
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -306,6 +344,15 @@ impl SecurityTradingStatus {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for SecurityTradingStatus {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Тип цены.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -339,6 +386,15 @@ impl PriceType {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for PriceType {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum ResultSubscriptionStatus {
@@ -371,21 +427,36 @@ impl ResultSubscriptionStatus {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for ResultSubscriptionStatus {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Запрос расписания торгов.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct TradingSchedulesRequest {
     /// Наименование биржи или расчетного календаря. </br>Если не передаётся, возвращается информация по всем доступным торговым площадкам.
     #[prost(string, optional, tag = "1")]
     pub exchange: ::core::option::Option<::prost::alloc::string::String>,
     /// Начало периода по UTC.
     #[prost(message, optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub from: ::core::option::Option<::prost_types::Timestamp>,
     /// Окончание периода по UTC.
     #[prost(message, optional, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub to: ::core::option::Option<::prost_types::Timestamp>,
 }
 /// Список торговых площадок.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct TradingSchedulesResponse {
     /// Список торговых площадок и режимов торгов.
     #[prost(message, repeated, tag = "1")]
@@ -393,6 +464,8 @@ pub struct TradingSchedulesResponse {
 }
 /// Данные по торговой площадке.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct TradingSchedule {
     /// Наименование торговой площадки.
     #[prost(string, tag = "1")]
@@ -403,53 +476,69 @@ pub struct TradingSchedule {
 }
 /// Информация о времени торгов.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct TradingDay {
     /// Дата.
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub date: ::core::option::Option<::prost_types::Timestamp>,
     /// Признак торгового дня на бирже.
     #[prost(bool, tag = "2")]
     pub is_trading_day: bool,
     /// Время начала торгов по UTC.
     #[prost(message, optional, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub start_time: ::core::option::Option<::prost_types::Timestamp>,
     /// Время окончания торгов по UTC.
     #[prost(message, optional, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub end_time: ::core::option::Option<::prost_types::Timestamp>,
     /// Время начала аукциона открытия по UTC.
     #[prost(message, optional, tag = "7")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub opening_auction_start_time: ::core::option::Option<::prost_types::Timestamp>,
     /// Время окончания аукциона закрытия по UTC.
     #[prost(message, optional, tag = "8")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub closing_auction_end_time: ::core::option::Option<::prost_types::Timestamp>,
     /// Время начала аукциона открытия вечерней сессии по UTC.
     #[prost(message, optional, tag = "9")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub evening_opening_auction_start_time: ::core::option::Option<
         ::prost_types::Timestamp,
     >,
     /// Время начала вечерней сессии по UTC.
     #[prost(message, optional, tag = "10")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub evening_start_time: ::core::option::Option<::prost_types::Timestamp>,
     /// Время окончания вечерней сессии по UTC.
     #[prost(message, optional, tag = "11")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub evening_end_time: ::core::option::Option<::prost_types::Timestamp>,
     /// Время начала основного клиринга по UTC.
     #[prost(message, optional, tag = "12")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub clearing_start_time: ::core::option::Option<::prost_types::Timestamp>,
     /// Время окончания основного клиринга по UTC.
     #[prost(message, optional, tag = "13")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub clearing_end_time: ::core::option::Option<::prost_types::Timestamp>,
     /// Время начала премаркета по UTC.
     #[prost(message, optional, tag = "14")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub premarket_start_time: ::core::option::Option<::prost_types::Timestamp>,
     /// Время окончания премаркета по UTC.
     #[prost(message, optional, tag = "15")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub premarket_end_time: ::core::option::Option<::prost_types::Timestamp>,
     /// Время начала аукциона закрытия по UTC.
     #[prost(message, optional, tag = "16")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub closing_auction_start_time: ::core::option::Option<::prost_types::Timestamp>,
     /// Время окончания аукциона открытия по UTC.
     #[prost(message, optional, tag = "17")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub opening_auction_end_time: ::core::option::Option<::prost_types::Timestamp>,
     /// Торговые интервалы.
     #[prost(message, repeated, tag = "18")]
@@ -457,9 +546,12 @@ pub struct TradingDay {
 }
 /// Запрос получения инструмента по идентификатору.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct InstrumentRequest {
     /// Тип идентификатора инструмента. Возможные значения — `figi`, `ticker`. [Подробнее об идентификации инструментов](<https://russianinvestments.github.io/investAPI/faq_identification/>).
     #[prost(enumeration = "InstrumentIdType", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<InstrumentIdType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<InstrumentIdType, _>"))]
     pub id_type: i32,
     /// Идентификатор `class_code`. Обязательный, если `id_type = ticker`.
     #[prost(string, optional, tag = "2")]
@@ -468,18 +560,55 @@ pub struct InstrumentRequest {
     #[prost(string, tag = "3")]
     pub id: ::prost::alloc::string::String,
 }
+impl InstrumentRequest {
+    /// Returns typed `id_type`, keeping values unknown to the contracts.
+    pub fn id_type_value(&self) -> crate::api::v1::EnumValue<InstrumentIdType> {
+        crate::api::v1::EnumValue::from(self.id_type)
+    }
+    /// Sets `id_type` and returns message.
+    pub fn with_id_type(mut self, value: InstrumentIdType) -> Self {
+        self.id_type = value as i32;
+        self
+    }
+}
 /// Запрос получения инструментов.
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct InstrumentsRequest {
     /// Статус запрашиваемых инструментов. [Возможные значения](#instrumentstatus).
     #[prost(enumeration = "InstrumentStatus", optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration_option::serialize::<InstrumentStatus, _>", deserialize_with = "crate::api::v1::serialization::enumeration_option::deserialize::<InstrumentStatus, _>"))]
     pub instrument_status: ::core::option::Option<i32>,
     /// Тип площадки торговли. [Возможные значения](#instrumentexchangetype).
     #[prost(enumeration = "InstrumentExchangeType", optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration_option::serialize::<InstrumentExchangeType, _>", deserialize_with = "crate::api::v1::serialization::enumeration_option::deserialize::<InstrumentExchangeType, _>"))]
     pub instrument_exchange: ::core::option::Option<i32>,
 }
+impl InstrumentsRequest {
+    /// Returns typed `instrument_status`, keeping values unknown to the contracts.
+    pub fn instrument_status_value(&self) -> ::core::option::Option<crate::api::v1::EnumValue<InstrumentStatus>> {
+        self.instrument_status.map(crate::api::v1::EnumValue::from)
+    }
+    /// Sets `instrument_status` and returns message.
+    pub fn with_instrument_status(mut self, value: InstrumentStatus) -> Self {
+        self.instrument_status = ::core::option::Option::Some(value as i32);
+        self
+    }
+    /// Returns typed `instrument_exchange`, keeping values unknown to the contracts.
+    pub fn instrument_exchange_value(&self) -> ::core::option::Option<crate::api::v1::EnumValue<InstrumentExchangeType>> {
+        self.instrument_exchange.map(crate::api::v1::EnumValue::from)
+    }
+    /// Sets `instrument_exchange` and returns message.
+    pub fn with_instrument_exchange(mut self, value: InstrumentExchangeType) -> Self {
+        self.instrument_exchange = ::core::option::Option::Some(value as i32);
+        self
+    }
+}
 /// Параметры фильтрации опционов.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct FilterOptionsRequest {
     /// Идентификатор базового актива опциона.  Обязательный параметр.
     #[prost(string, optional, tag = "1")]
@@ -490,6 +619,8 @@ pub struct FilterOptionsRequest {
 }
 /// Информация об облигации.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct BondResponse {
     /// Информация об облигации.
     #[prost(message, optional, tag = "1")]
@@ -497,6 +628,8 @@ pub struct BondResponse {
 }
 /// Список облигаций.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct BondsResponse {
     /// Массив облигаций.
     #[prost(message, repeated, tag = "1")]
@@ -504,6 +637,8 @@ pub struct BondsResponse {
 }
 /// Запрос купонов по облигации.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetBondCouponsRequest {
     /// FIGI-идентификатор инструмента.
     #[deprecated]
@@ -511,9 +646,11 @@ pub struct GetBondCouponsRequest {
     pub figi: ::prost::alloc::string::String,
     /// Начало запрашиваемого периода по UTC. Фильтрация по `coupon_date` — дата выплаты купона.
     #[prost(message, optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub from: ::core::option::Option<::prost_types::Timestamp>,
     /// Окончание запрашиваемого периода по UTC. Фильтрация по `coupon_date` — дата выплаты купона.
     #[prost(message, optional, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub to: ::core::option::Option<::prost_types::Timestamp>,
     /// Идентификатор инструмента — `figi` или `instrument_uid`.
     #[prost(string, tag = "4")]
@@ -521,26 +658,44 @@ pub struct GetBondCouponsRequest {
 }
 /// Купоны по облигации.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetBondCouponsResponse {
     #[prost(message, repeated, tag = "1")]
     pub events: ::prost::alloc::vec::Vec<Coupon>,
 }
 /// События по облигации.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetBondEventsRequest {
     /// Начало запрашиваемого периода по UTC.
     #[prost(message, optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub from: ::core::option::Option<::prost_types::Timestamp>,
     /// Окончание запрашиваемого периода по UTC.
     #[prost(message, optional, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub to: ::core::option::Option<::prost_types::Timestamp>,
     /// Идентификатор инструмента — `figi` или `instrument_uid`.
     #[prost(string, tag = "4")]
     pub instrument_id: ::prost::alloc::string::String,
     /// Тип события
     #[prost(enumeration = "get_bond_events_request::EventType", tag = "5")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<get_bond_events_request::EventType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<get_bond_events_request::EventType, _>"))]
     pub r#type: i32,
 }
+impl GetBondEventsRequest {
+    /// Returns typed `type`, keeping values unknown to the contracts.
+    pub fn type_value(&self) -> crate::api::v1::EnumValue<get_bond_events_request::EventType> {
+        crate::api::v1::EnumValue::from(self.r#type)
+    }
+    /// Sets `type` and returns message.
+    pub fn with_type(mut self, value: get_bond_events_request::EventType) -> Self {
+        self.r#type = value as i32;
+        self
+    }
+}
 /// Nested message and enum types in `GetBondEventsRequest`.
 pub mod get_bond_events_request {
     #[derive(
@@ -593,9 +748,20 @@ pub mod get_bond_events_request {
             }
         }
     }
+    #[cfg(feature = "serde")]
+    impl crate::api::v1::serialization::ProtoEnum for EventType {
+        fn proto_name(&self) -> &'static str {
+            self.as_str_name()
+        }
+        fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+            Self::from_str_name(value)
+        }
+    }
 }
 /// Объект передачи информации о событии облигации.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetBondEventsResponse {
     #[prost(message, repeated, tag = "1")]
     pub events: ::prost::alloc::vec::Vec<get_bond_events_response::BondEvent>,
@@ -603,6 +769,8 @@ pub struct GetBondEventsResponse {
 /// Nested message and enum types in `GetBondEventsResponse`.
 pub mod get_bond_events_response {
     #[derive(Clone, PartialEq, ::prost::Message)]
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
     pub struct BondEvent {
         /// Идентификатор инструмента.
         #[prost(string, tag = "2")]
@@ -612,27 +780,34 @@ pub mod get_bond_events_response {
         pub event_number: i32,
         /// Дата события.
         #[prost(message, optional, tag = "4")]
+        #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
         pub event_date: ::core::option::Option<::prost_types::Timestamp>,
         /// Тип события.
         #[prost(enumeration = "super::get_bond_events_request::EventType", tag = "5")]
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<super::get_bond_events_request::EventType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<super::get_bond_events_request::EventType, _>"))]
         pub event_type: i32,
         /// Полное количество бумаг, задействованных в событии.
         #[prost(message, optional, tag = "6")]
         pub event_total_vol: ::core::option::Option<super::Quotation>,
         /// Дата фиксации владельцев для участия в событии.
         #[prost(message, optional, tag = "7")]
+        #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
         pub fix_date: ::core::option::Option<::prost_types::Timestamp>,
         /// Дата определения даты или факта события.
         #[prost(message, optional, tag = "8")]
+        #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
         pub rate_date: ::core::option::Option<::prost_types::Timestamp>,
         /// Дата дефолта, если применимо.
         #[prost(message, optional, tag = "9")]
+        #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
         pub default_date: ::core::option::Option<::prost_types::Timestamp>,
         /// Дата реального исполнения обязательства.
         #[prost(message, optional, tag = "10")]
+        #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
         pub real_pay_date: ::core::option::Option<::prost_types::Timestamp>,
         /// Дата выплаты.
         #[prost(message, optional, tag = "11")]
+        #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
         pub pay_date: ::core::option::Option<::prost_types::Timestamp>,
         /// Выплата на одну облигацию.
         #[prost(message, optional, tag = "12")]
@@ -657,9 +832,11 @@ pub mod get_bond_events_response {
         pub convert_to_fin_tool_id: ::prost::alloc::string::String,
         /// Начало купонного периода.
         #[prost(message, optional, tag = "19")]
+        #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
         pub coupon_start_date: ::core::option::Option<::prost_types::Timestamp>,
         /// Окончание купонного периода.
         #[prost(message, optional, tag = "20")]
+        #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
         pub coupon_end_date: ::core::option::Option<::prost_types::Timestamp>,
         /// Купонный период.
         #[prost(int32, tag = "21")]
@@ -668,40 +845,72 @@ pub mod get_bond_events_response {
         #[prost(message, optional, tag = "22")]
         pub coupon_interest_rate: ::core::option::Option<super::Quotation>,
     }
+    impl BondEvent {
+        /// Returns typed `event_type`, keeping values unknown to the contracts.
+        pub fn event_type_value(&self) -> crate::api::v1::EnumValue<super::get_bond_events_request::EventType> {
+            crate::api::v1::EnumValue::from(self.event_type)
+        }
+        /// Sets `event_type` and returns message.
+        pub fn with_event_type(mut self, value: super::get_bond_events_request::EventType) -> Self {
+            self.event_type = value as i32;
+            self
+        }
+    }
 }
 /// Объект передачи информации о купоне облигации.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct Coupon {
     /// FIGI-идентификатор инструмента.
     #[prost(string, tag = "1")]
     pub figi: ::prost::alloc::string::String,
     /// Дата выплаты купона.
     #[prost(message, optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub coupon_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Номер купона.
     #[prost(int64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
     pub coupon_number: i64,
     /// Дата фиксации реестра для выплаты купона — опционально.
     #[prost(message, optional, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub fix_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Выплата на одну облигацию.
     #[prost(message, optional, tag = "5")]
     pub pay_one_bond: ::core::option::Option<MoneyValue>,
     /// Тип купона.
     #[prost(enumeration = "CouponType", tag = "6")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<CouponType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<CouponType, _>"))]
     pub coupon_type: i32,
     /// Начало купонного периода.
     #[prost(message, optional, tag = "7")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub coupon_start_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Окончание купонного периода.
     #[prost(message, optional, tag = "8")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub coupon_end_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Купонный период в днях.
     #[prost(int32, tag = "9")]
     pub coupon_period: i32,
 }
+impl Coupon {
+    /// Returns typed `coupon_type`, keeping values unknown to the contracts.
+    pub fn coupon_type_value(&self) -> crate::api::v1::EnumValue<CouponType> {
+        crate::api::v1::EnumValue::from(self.coupon_type)
+    }
+    /// Sets `coupon_type` and returns message.
+    pub fn with_coupon_type(mut self, value: CouponType) -> Self {
+        self.coupon_type = value as i32;
+        self
+    }
+}
 /// Данные по валюте.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct CurrencyResponse {
     /// Информация о валюте.
     #[prost(message, optional, tag = "1")]
@@ -709,6 +918,8 @@ pub struct CurrencyResponse {
 }
 /// Данные по валютам.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct CurrenciesResponse {
     /// Массив валют.
     #[prost(message, repeated, tag = "1")]
@@ -716,6 +927,8 @@ pub struct CurrenciesResponse {
 }
 /// Данные по фонду.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct EtfResponse {
     /// Информация о фонде.
     #[prost(message, optional, tag = "1")]
@@ -723,6 +936,8 @@ pub struct EtfResponse {
 }
 /// Данные по фондам.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct EtfsResponse {
     /// Массив фондов.
     #[prost(message, repeated, tag = "1")]
@@ -730,6 +945,8 @@ pub struct EtfsResponse {
 }
 /// Данные по фьючерсу.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct FutureResponse {
     /// Информация о фьючерсу.
     #[prost(message, optional, tag = "1")]
@@ -737,6 +954,8 @@ pub struct FutureResponse {
 }
 /// Данные по фьючерсам.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct FuturesResponse {
     /// Массив фьючерсов.
     #[prost(message, repeated, tag = "1")]
@@ -744,6 +963,8 @@ pub struct FuturesResponse {
 }
 /// Данные по опциону.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct OptionResponse {
     /// Информация по опциону.
     #[prost(message, optional, tag = "1")]
@@ -751,6 +972,8 @@ pub struct OptionResponse {
 }
 /// Данные по опционам.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct OptionsResponse {
     /// Массив данных по опциону.
     #[prost(message, repeated, tag = "1")]
@@ -758,6 +981,8 @@ pub struct OptionsResponse {
 }
 /// Опцион.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct Option {
     /// Уникальный идентификатор инструмента.
     #[prost(string, tag = "1")]
@@ -776,21 +1001,27 @@ pub struct Option {
     pub basic_asset_position_uid: ::prost::alloc::string::String,
     /// Текущий режим торгов инструмента.
     #[prost(enumeration = "SecurityTradingStatus", tag = "21")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<SecurityTradingStatus, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<SecurityTradingStatus, _>"))]
     pub trading_status: i32,
     /// Реальная площадка исполнения расчётов (биржа).
     #[prost(enumeration = "RealExchange", tag = "31")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<RealExchange, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<RealExchange, _>"))]
     pub real_exchange: i32,
     /// Направление опциона.
     #[prost(enumeration = "OptionDirection", tag = "41")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<OptionDirection, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<OptionDirection, _>"))]
     pub direction: i32,
     /// Тип расчётов по опциону.
     #[prost(enumeration = "OptionPaymentType", tag = "42")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<OptionPaymentType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<OptionPaymentType, _>"))]
     pub payment_type: i32,
     /// Стиль опциона.
     #[prost(enumeration = "OptionStyle", tag = "43")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<OptionStyle, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<OptionStyle, _>"))]
     pub style: i32,
     /// Способ исполнения опциона.
     #[prost(enumeration = "OptionSettlementType", tag = "44")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<OptionSettlementType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<OptionSettlementType, _>"))]
     pub settlement_type: i32,
     /// Название инструмента.
     #[prost(string, tag = "101")]
@@ -864,18 +1095,23 @@ pub struct Option {
     pub dshort_client: ::core::option::Option<Quotation>,
     /// Дата истечения срока в формате UTC.
     #[prost(message, optional, tag = "301")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub expiration_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Дата начала обращения контракта в формате UTC.
     #[prost(message, optional, tag = "311")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub first_trade_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Дата исполнения в формате UTC.
     #[prost(message, optional, tag = "312")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub last_trade_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Дата первой минутной свечи в формате UTC.
     #[prost(message, optional, tag = "321")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub first_1min_candle_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Дата первой дневной свечи в формате UTC.
     #[prost(message, optional, tag = "322")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub first_1day_candle_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Признак доступности для операций шорт.
     #[prost(bool, tag = "401")]
@@ -905,8 +1141,66 @@ pub struct Option {
     #[prost(bool, tag = "409")]
     pub api_trade_available_flag: bool,
 }
+impl Option {
+    /// Returns typed `trading_status`, keeping values unknown to the contracts.
+    pub fn trading_status_value(&self) -> crate::api::v1::EnumValue<SecurityTradingStatus> {
+        crate::api::v1::EnumValue::from(self.trading_status)
+    }
+    /// Sets `trading_status` and returns message.
+    pub fn with_trading_status(mut self, value: SecurityTradingStatus) -> Self {
+        self.trading_status = value as i32;
+        self
+    }
+    /// Returns typed `real_exchange`, keeping values unknown to the contracts.
+    pub fn real_exchange_value(&self) -> crate::api::v1::EnumValue<RealExchange> {
+        crate::api::v1::EnumValue::from(self.real_exchange)
+    }
+    /// Sets `real_exchange` and returns message.
+    pub fn with_real_exchange(mut self, value: RealExchange) -> Self {
+        self.real_exchange = value as i32;
+        self
+    }
+    /// Returns typed `direction`, keeping values unknown to the contracts.
+    pub fn direction_value(&self) -> crate::api::v1::EnumValue<OptionDirection> {
+        crate::api::v1::EnumValue::from(self.direction)
+    }
+    /// Sets `direction` and returns message.
+    pub fn with_direction(mut self, value: OptionDirection) -> Self {
+        self.direction = value as i32;
+        self
+    }
+    /// Returns typed `payment_type`, keeping values unknown to the contracts.
+    pub fn payment_type_value(&self) -> crate::api::v1::EnumValue<OptionPaymentType> {
+        crate::api::v1::EnumValue::from(self.payment_type)
+    }
+    /// Sets `payment_type` and returns message.
+    pub fn with_payment_type(mut self, value: OptionPaymentType) -> Self {
+        self.payment_type = value as i32;
+        self
+    }
+    /// Returns typed `style`, keeping values unknown to the contracts.
+    pub fn style_value(&self) -> crate::api::v1::EnumValue<OptionStyle> {
+        crate::api::v1::EnumValue::from(self.style)
+    }
+    /// Sets `style` and returns message.
+    pub fn with_style(mut self, value: OptionStyle) -> Self {
+        self.style = value as i32;
+        self
+    }
+    /// Returns typed `settlement_type`, keeping values unknown to the contracts.
+    pub fn settlement_type_value(&self) -> crate::api::v1::EnumValue<OptionSettlementType> {
+        crate::api::v1::EnumValue::from(self.settlement_type)
+    }
+    /// Sets `settlement_type` and returns message.
+    pub fn with_settlement_type(mut self, value: OptionSettlementType) -> Self {
+        self.settlement_type = value as i32;
+        self
+    }
+}
 /// Данные по акции.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct ShareResponse {
     /// Информация об акции.
     #[prost(message, optional, tag = "1")]
@@ -914,6 +1208,8 @@ pub struct ShareResponse {
 }
 /// Данные по акциям.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct SharesResponse {
     /// Массив акций.
     #[prost(message, repeated, tag = "1")]
@@ -921,6 +1217,8 @@ pub struct SharesResponse {
 }
 /// Объект передачи информации об облигации.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct Bond {
     /// FIGI-идентификатор инструмента.
     #[prost(string, tag = "1")]
@@ -976,6 +1274,7 @@ pub struct Bond {
     pub coupon_quantity_per_year: i32,
     /// Дата погашения облигации по UTC.
     #[prost(message, optional, tag = "18")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub maturity_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Номинал облигации.
     #[prost(message, optional, tag = "19")]
@@ -985,9 +1284,11 @@ pub struct Bond {
     pub initial_nominal: ::core::option::Option<MoneyValue>,
     /// Дата выпуска облигации по UTC.
     #[prost(message, optional, tag = "21")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub state_reg_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Дата размещения по UTC.
     #[prost(message, optional, tag = "22")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub placement_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Цена размещения.
     #[prost(message, optional, tag = "23")]
@@ -1009,12 +1310,15 @@ pub struct Bond {
     pub issue_kind: ::prost::alloc::string::String,
     /// Размер выпуска.
     #[prost(int64, tag = "29")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
     pub issue_size: i64,
     /// Плановый размер выпуска.
     #[prost(int64, tag = "30")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
     pub issue_size_plan: i64,
     /// Текущий режим торгов инструмента.
     #[prost(enumeration = "SecurityTradingStatus", tag = "31")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<SecurityTradingStatus, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<SecurityTradingStatus, _>"))]
     pub trading_status: i32,
     /// Флаг, используемый ранее для определения внебиржевых инструментов. На данный момент не используется для торгуемых через API инструментов. Может использоваться как фильтр для операций, совершавшихся некоторое время назад на ОТС площадке.
     #[prost(bool, tag = "32")]
@@ -1045,6 +1349,7 @@ pub struct Bond {
     pub uid: ::prost::alloc::string::String,
     /// Реальная площадка исполнения расчётов. (биржа)
     #[prost(enumeration = "RealExchange", tag = "41")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<RealExchange, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<RealExchange, _>"))]
     pub real_exchange: i32,
     /// Уникальный идентификатор позиции инструмента.
     #[prost(string, tag = "42")]
@@ -1072,21 +1377,26 @@ pub struct Bond {
     pub liquidity_flag: bool,
     /// Дата первой минутной свечи.
     #[prost(message, optional, tag = "61")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub first_1min_candle_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Дата первой дневной свечи.
     #[prost(message, optional, tag = "62")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub first_1day_candle_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Уровень риска.
     #[prost(enumeration = "RiskLevel", tag = "63")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<RiskLevel, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<RiskLevel, _>"))]
     pub risk_level: i32,
     /// Информация о бренде.
     #[prost(message, optional, tag = "64")]
     pub brand: ::core::option::Option<BrandData>,
     /// Тип облигации.
     #[prost(enumeration = "BondType", tag = "65")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<BondType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<BondType, _>"))]
     pub bond_type: i32,
     /// Дата погашения облигации.
     #[prost(message, optional, tag = "69")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub call_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Ставка риска в лонг, с учетом текущего уровня риска портфеля клиента. [Подробнее про ставки риска](<https://www.tbank.ru/invest/help/brokerage/account/margin/about/#q5>).
     #[prost(message, optional, tag = "90")]
@@ -1095,8 +1405,48 @@ pub struct Bond {
     #[prost(message, optional, tag = "91")]
     pub dshort_client: ::core::option::Option<Quotation>,
 }
+impl Bond {
+    /// Returns typed `trading_status`, keeping values unknown to the contracts.
+    pub fn trading_status_value(&self) -> crate::api::v1::EnumValue<SecurityTradingStatus> {
+        crate::api::v1::EnumValue::from(self.trading_status)
+    }
+    /// Sets `trading_status` and returns message.
+    pub fn with_trading_status(mut self, value: SecurityTradingStatus) -> Self {
+        self.trading_status = value as i32;
+        self
+    }
+    /// Returns typed `real_exchange`, keeping values unknown to the contracts.
+    pub fn real_exchange_value(&self) -> crate::api::v1::EnumValue<RealExchange> {
+        crate::api::v1::EnumValue::from(self.real_exchange)
+    }
+    /// Sets `real_exchange` and returns message.
+    pub fn with_real_exchange(mut self, value: RealExchange) -> Self {
+        self.real_exchange = value as i32;
+        self
+    }
+    /// Returns typed `risk_level`, keeping values unknown to the contracts.
+    pub fn risk_level_value(&self) -> crate::api::v1::EnumValue<RiskLevel> {
+        crate::api::v1::EnumValue::from(self.risk_level)
+    }
+    /// Sets `risk_level` and returns message.
+    pub fn with_risk_level(mut self, value: RiskLevel) -> Self {
+        self.risk_level = value as i32;
+        self
+    }
+    /// Returns typed `bond_type`, keeping values unknown to the contracts.
+    pub fn bond_type_value(&self) -> crate::api::v1::EnumValue<BondType> {
+        crate::api::v1::EnumValue::from(self.bond_type)
+    }
+    /// Sets `bond_type` and returns message.
+    pub fn with_bond_type(mut self, value: BondType) -> Self {
+        self.bond_type = value as i32;
+        self
+    }
+}
 /// Объект передачи информации о валюте.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct Currency {
     /// FIGI-идентификатор инструмента.
     #[prost(string, tag = "1")]
@@ -1158,6 +1508,7 @@ pub struct Currency {
     pub country_of_risk_name: ::prost::alloc::string::String,
     /// Текущий режим торгов инструмента.
     #[prost(enumeration = "SecurityTradingStatus", tag = "20")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<SecurityTradingStatus, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<SecurityTradingStatus, _>"))]
     pub trading_status: i32,
     /// Флаг, используемый ранее для определения внебиржевых инструментов. На данный момент не используется для торгуемых через API инструментов. Может использоваться как фильтр для операций, совершавшихся некоторое время назад на ОТС площадке.
     #[prost(bool, tag = "21")]
@@ -1182,6 +1533,7 @@ pub struct Currency {
     pub uid: ::prost::alloc::string::String,
     /// Реальная площадка исполнения расчётов (биржа).
     #[prost(enumeration = "RealExchange", tag = "28")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<RealExchange, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<RealExchange, _>"))]
     pub real_exchange: i32,
     /// Уникальный идентификатор позиции инструмента.
     #[prost(string, tag = "29")]
@@ -1200,9 +1552,11 @@ pub struct Currency {
     pub blocked_tca_flag: bool,
     /// Дата первой минутной свечи.
     #[prost(message, optional, tag = "56")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub first_1min_candle_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Дата первой дневной свечи.
     #[prost(message, optional, tag = "57")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub first_1day_candle_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Информация о бренде.
     #[prost(message, optional, tag = "60")]
@@ -1214,8 +1568,30 @@ pub struct Currency {
     #[prost(message, optional, tag = "91")]
     pub dshort_client: ::core::option::Option<Quotation>,
 }
+impl Currency {
+    /// Returns typed `trading_status`, keeping values unknown to the contracts.
+    pub fn trading_status_value(&self) -> crate::api::v1::EnumValue<SecurityTradingStatus> {
+        crate::api::v1::EnumValue::from(self.trading_status)
+    }
+    /// Sets `trading_status` and returns message.
+    pub fn with_trading_status(mut self, value: SecurityTradingStatus) -> Self {
+        self.trading_status = value as i32;
+        self
+    }
+    /// Returns typed `real_exchange`, keeping values unknown to the contracts.
+    pub fn real_exchange_value(&self) -> crate::api::v1::EnumValue<RealExchange> {
+        crate::api::v1::EnumValue::from(self.real_exchange)
+    }
+    /// Sets `real_exchange` and returns message.
+    pub fn with_real_exchange(mut self, value: RealExchange) -> Self {
+        self.real_exchange = value as i32;
+        self
+    }
+}
 /// Объект передачи информации об инвестиционном фонде.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct Etf {
     /// FIGI-идентификатор инструмента.
     #[prost(string, tag = "1")]
@@ -1274,6 +1650,7 @@ pub struct Etf {
     pub focus_type: ::prost::alloc::string::String,
     /// Дата выпуска по UTC.
     #[prost(message, optional, tag = "19")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub released_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Количество паев фонда в обращении.
     #[prost(message, optional, tag = "20")]
@@ -1292,6 +1669,7 @@ pub struct Etf {
     pub rebalancing_freq: ::prost::alloc::string::String,
     /// Текущий режим торгов инструмента.
     #[prost(enumeration = "SecurityTradingStatus", tag = "25")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<SecurityTradingStatus, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<SecurityTradingStatus, _>"))]
     pub trading_status: i32,
     /// Флаг, используемый ранее для определения внебиржевых инструментов. На данный момент не используется для торгуемых через API инструментов. Может использоваться как фильтр для операций, совершавшихся некоторое время назад на ОТС площадке.
     #[prost(bool, tag = "26")]
@@ -1313,6 +1691,7 @@ pub struct Etf {
     pub uid: ::prost::alloc::string::String,
     /// Реальная площадка исполнения расчётов (биржа).
     #[prost(enumeration = "RealExchange", tag = "32")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<RealExchange, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<RealExchange, _>"))]
     pub real_exchange: i32,
     /// Уникальный идентификатор позиции инструмента.
     #[prost(string, tag = "33")]
@@ -1322,6 +1701,7 @@ pub struct Etf {
     pub asset_uid: ::prost::alloc::string::String,
     /// Тип площадки торговли.
     #[prost(enumeration = "InstrumentExchangeType", tag = "35")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<InstrumentExchangeType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<InstrumentExchangeType, _>"))]
     pub instrument_exchange: i32,
     /// Признак доступности для ИИС.
     #[prost(bool, tag = "41")]
@@ -1340,9 +1720,11 @@ pub struct Etf {
     pub liquidity_flag: bool,
     /// Дата первой минутной свечи.
     #[prost(message, optional, tag = "56")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub first_1min_candle_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Дата первой дневной свечи.
     #[prost(message, optional, tag = "57")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub first_1day_candle_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Информация о бренде.
     #[prost(message, optional, tag = "60")]
@@ -1354,8 +1736,39 @@ pub struct Etf {
     #[prost(message, optional, tag = "91")]
     pub dshort_client: ::core::option::Option<Quotation>,
 }
+impl Etf {
+    /// Returns typed `trading_status`, keeping values unknown to the contracts.
+    pub fn trading_status_value(&self) -> crate::api::v1::EnumValue<SecurityTradingStatus> {
+        crate::api::v1::EnumValue::from(self.trading_status)
+    }
+    /// Sets `trading_status` and returns message.
+    pub fn with_trading_status(mut self, value: SecurityTradingStatus) -> Self {
+        self.trading_status = value as i32;
+        self
+    }
+    /// Returns typed `real_exchange`, keeping values unknown to the contracts.
+    pub fn real_exchange_value(&self) -> crate::api::v1::EnumValue<RealExchange> {
+        crate::api::v1::EnumValue::from(self.real_exchange)
+    }
+    /// Sets `real_exchange` and returns message.
+    pub fn with_real_exchange(mut self, value: RealExchange) -> Self {
+        self.real_exchange = value as i32;
+        self
+    }
+    /// Returns typed `instrument_exchange`, keeping values unknown to the contracts.
+    pub fn instrument_exchange_value(&self) -> crate::api::v1::EnumValue<InstrumentExchangeType> {
+        crate::api::v1::EnumValue::from(self.instrument_exchange)
+    }
+    /// Sets `instrument_exchange` and returns message.
+    pub fn with_instrument_exchange(mut self, value: InstrumentExchangeType) -> Self {
+        self.instrument_exchange = value as i32;
+        self
+    }
+}
 /// Объект передачи информации о фьючерсе.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct Future {
     /// FIGI-идентификатор инструмента.
     #[prost(string, tag = "1")]
@@ -1405,9 +1818,11 @@ pub struct Future {
     pub exchange: ::prost::alloc::string::String,
     /// Дата начала обращения контракта по UTC.
     #[prost(message, optional, tag = "15")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub first_trade_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Дата по UTC, до которой возможно проведение операций с фьючерсом.
     #[prost(message, optional, tag = "16")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub last_trade_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Тип фьючерса. Возможные значения: </br>**physical_delivery** — физические поставки; </br>**cash_settlement** — денежный эквивалент.
     #[prost(string, tag = "17")]
@@ -1432,9 +1847,11 @@ pub struct Future {
     pub sector: ::prost::alloc::string::String,
     /// Дата истечения срока в часов поясе UTC.
     #[prost(message, optional, tag = "24")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub expiration_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Текущий режим торгов инструмента.
     #[prost(enumeration = "SecurityTradingStatus", tag = "25")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<SecurityTradingStatus, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<SecurityTradingStatus, _>"))]
     pub trading_status: i32,
     /// Флаг, используемый ранее для определения внебиржевых инструментов. На данный момент не используется для торгуемых через API инструментов. Может использоваться как фильтр для операций, совершавшихся некоторое время назад на ОТС площадке.
     #[prost(bool, tag = "26")]
@@ -1456,6 +1873,7 @@ pub struct Future {
     pub uid: ::prost::alloc::string::String,
     /// Реальная площадка исполнения расчётов (биржа).
     #[prost(enumeration = "RealExchange", tag = "32")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<RealExchange, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<RealExchange, _>"))]
     pub real_exchange: i32,
     /// Уникальный идентификатор позиции инструмента.
     #[prost(string, tag = "33")]
@@ -1477,9 +1895,11 @@ pub struct Future {
     pub blocked_tca_flag: bool,
     /// Дата первой минутной свечи.
     #[prost(message, optional, tag = "56")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub first_1min_candle_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Дата первой дневной свечи.
     #[prost(message, optional, tag = "57")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub first_1day_candle_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Гарантийное обеспечение при покупке.
     #[prost(message, optional, tag = "61")]
@@ -1500,8 +1920,30 @@ pub struct Future {
     #[prost(message, optional, tag = "91")]
     pub dshort_client: ::core::option::Option<Quotation>,
 }
+impl Future {
+    /// Returns typed `trading_status`, keeping values unknown to the contracts.
+    pub fn trading_status_value(&self) -> crate::api::v1::EnumValue<SecurityTradingStatus> {
+        crate::api::v1::EnumValue::from(self.trading_status)
+    }
+    /// Sets `trading_status` and returns message.
+    pub fn with_trading_status(mut self, value: SecurityTradingStatus) -> Self {
+        self.trading_status = value as i32;
+        self
+    }
+    /// Returns typed `real_exchange`, keeping values unknown to the contracts.
+    pub fn real_exchange_value(&self) -> crate::api::v1::EnumValue<RealExchange> {
+        crate::api::v1::EnumValue::from(self.real_exchange)
+    }
+    /// Sets `real_exchange` and returns message.
+    pub fn with_real_exchange(mut self, value: RealExchange) -> Self {
+        self.real_exchange = value as i32;
+        self
+    }
+}
 /// Объект передачи информации об акции.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct Share {
     /// FIGI-идентификатор инструмента.
     #[prost(string, tag = "1")]
@@ -1554,9 +1996,11 @@ pub struct Share {
     pub exchange: ::prost::alloc::string::String,
     /// Дата IPO акции по UTC.
     #[prost(message, optional, tag = "17")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub ipo_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Размер выпуска.
     #[prost(int64, tag = "18")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
     pub issue_size: i64,
     /// Код страны риска — то есть страны, в которой компания ведёт основной бизнес.
     #[prost(string, tag = "19")]
@@ -1569,12 +2013,14 @@ pub struct Share {
     pub sector: ::prost::alloc::string::String,
     /// Плановый размер выпуска.
     #[prost(int64, tag = "22")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
     pub issue_size_plan: i64,
     /// Номинал.
     #[prost(message, optional, tag = "23")]
     pub nominal: ::core::option::Option<MoneyValue>,
     /// Текущий режим торгов инструмента.
     #[prost(enumeration = "SecurityTradingStatus", tag = "25")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<SecurityTradingStatus, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<SecurityTradingStatus, _>"))]
     pub trading_status: i32,
     /// Флаг, используемый ранее для определения внебиржевых инструментов. На данный момент не используется для торгуемых через API инструментов. Может использоваться как фильтр для операций, совершавшихся некоторое время назад на ОТС площадке.
     #[prost(bool, tag = "26")]
@@ -1590,6 +2036,7 @@ pub struct Share {
     pub div_yield_flag: bool,
     /// Тип акции. Возможные значения — `[ShareType](<https://russianinvestments.github.io/investAPI/instruments#sharetype>)`.
     #[prost(enumeration = "ShareType", tag = "30")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<ShareType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<ShareType, _>"))]
     pub share_type: i32,
     /// Шаг цены.
     #[prost(message, optional, tag = "31")]
@@ -1602,6 +2049,7 @@ pub struct Share {
     pub uid: ::prost::alloc::string::String,
     /// Реальная площадка исполнения расчётов (биржа).
     #[prost(enumeration = "RealExchange", tag = "34")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<RealExchange, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<RealExchange, _>"))]
     pub real_exchange: i32,
     /// Уникальный идентификатор позиции инструмента.
     #[prost(string, tag = "35")]
@@ -1611,6 +2059,7 @@ pub struct Share {
     pub asset_uid: ::prost::alloc::string::String,
     /// Тип площадки торговли.
     #[prost(enumeration = "InstrumentExchangeType", tag = "37")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<InstrumentExchangeType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<InstrumentExchangeType, _>"))]
     pub instrument_exchange: i32,
     /// Признак доступности для ИИС.
     #[prost(bool, tag = "46")]
@@ -1629,9 +2078,11 @@ pub struct Share {
     pub liquidity_flag: bool,
     /// Дата первой минутной свечи.
     #[prost(message, optional, tag = "56")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub first_1min_candle_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Дата первой дневной свечи.
     #[prost(message, optional, tag = "57")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub first_1day_candle_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Информация о бренде.
     #[prost(message, optional, tag = "60")]
@@ -1643,8 +2094,48 @@ pub struct Share {
     #[prost(message, optional, tag = "91")]
     pub dshort_client: ::core::option::Option<Quotation>,
 }
+impl Share {
+    /// Returns typed `trading_status`, keeping values unknown to the contracts.
+    pub fn trading_status_value(&self) -> crate::api::v1::EnumValue<SecurityTradingStatus> {
+        crate::api::v1::EnumValue::from(self.trading_status)
+    }
+    /// Sets `trading_status` and returns message.
+    pub fn with_trading_status(mut self, value: SecurityTradingStatus) -> Self {
+        self.trading_status = value as i32;
+        self
+    }
+    /// Returns typed `share_type`, keeping values unknown to the contracts.
+    pub fn share_type_value(&self) -> crate::api::v1::EnumValue<ShareType> {
+        crate::api::v1::EnumValue::from(self.share_type)
+    }
+    /// Sets `share_type` and returns message.
+    pub fn with_share_type(mut self, value: ShareType) -> Self {
+        self.share_type = value as i32;
+        self
+    }
+    /// Returns typed `real_exchange`, keeping values unknown to the contracts.
+    pub fn real_exchange_value(&self) -> crate::api::v1::EnumValue<RealExchange> {
+        crate::api::v1::EnumValue::from(self.real_exchange)
+    }
+    /// Sets `real_exchange` and returns message.
+    pub fn with_real_exchange(mut self, value: RealExchange) -> Self {
+        self.real_exchange = value as i32;
+        self
+    }
+    /// Returns typed `instrument_exchange`, keeping values unknown to the contracts.
+    pub fn instrument_exchange_value(&self) -> crate::api::v1::EnumValue<InstrumentExchangeType> {
+        crate::api::v1::EnumValue::from(self.instrument_exchange)
+    }
+    /// Sets `instrument_exchange` and returns message.
+    pub fn with_instrument_exchange(mut self, value: InstrumentExchangeType) -> Self {
+        self.instrument_exchange = value as i32;
+        self
+    }
+}
 /// Запрос НКД по облигации.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetAccruedInterestsRequest {
     /// FIGI-идентификатор инструмента.
     #[deprecated]
@@ -1652,9 +2143,11 @@ pub struct GetAccruedInterestsRequest {
     pub figi: ::prost::alloc::string::String,
     /// Начало запрашиваемого периода по UTC.
     #[prost(message, optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub from: ::core::option::Option<::prost_types::Timestamp>,
     /// Окончание запрашиваемого периода по UTC.
     #[prost(message, optional, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub to: ::core::option::Option<::prost_types::Timestamp>,
     /// Идентификатор инструмента — `figi` или `instrument_uid`.
     #[prost(string, tag = "4")]
@@ -1662,6 +2155,8 @@ pub struct GetAccruedInterestsRequest {
 }
 /// НКД облигации.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetAccruedInterestsResponse {
     /// Массив операций начисления купонов.
     #[prost(message, repeated, tag = "1")]
@@ -1669,9 +2164,12 @@ pub struct GetAccruedInterestsResponse {
 }
 /// Операция начисления купонов.
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct AccruedInterest {
     /// Дата и время выплаты по UTC.
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub date: ::core::option::Option<::prost_types::Timestamp>,
     /// Величина выплаты.
     #[prost(message, optional, tag = "2")]
@@ -1685,6 +2183,8 @@ pub struct AccruedInterest {
 }
 /// Запрос информации о фьючерсе
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetFuturesMarginRequest {
     /// Идентификатор инструмента.
     #[deprecated]
@@ -1696,6 +2196,8 @@ pub struct GetFuturesMarginRequest {
 }
 /// Данные по фьючерсу
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetFuturesMarginResponse {
     /// Гарантийное обеспечение при покупке.
     #[prost(message, optional, tag = "1")]
@@ -1712,6 +2214,8 @@ pub struct GetFuturesMarginResponse {
 }
 /// Данные по инструменту.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct InstrumentResponse {
     /// Основная информация об инструменте.
     #[prost(message, optional, tag = "1")]
@@ -1719,6 +2223,8 @@ pub struct InstrumentResponse {
 }
 /// Объект передачи основной информации об инструменте.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct Instrument {
     /// FIGI-идентификатор инструмента.
     #[prost(string, tag = "1")]
@@ -1780,6 +2286,7 @@ pub struct Instrument {
     pub instrument_type: ::prost::alloc::string::String,
     /// Текущий режим торгов инструмента.
     #[prost(enumeration = "SecurityTradingStatus", tag = "19")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<SecurityTradingStatus, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<SecurityTradingStatus, _>"))]
     pub trading_status: i32,
     /// Флаг, используемый ранее для определения внебиржевых инструментов. На данный момент не используется для торгуемых через API инструментов. Может использоваться как фильтр для операций, совершавшихся некоторое время назад на ОТС площадке.
     #[prost(bool, tag = "20")]
@@ -1801,6 +2308,7 @@ pub struct Instrument {
     pub uid: ::prost::alloc::string::String,
     /// Реальная площадка исполнения расчётов (биржа).
     #[prost(enumeration = "RealExchange", tag = "26")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<RealExchange, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<RealExchange, _>"))]
     pub real_exchange: i32,
     /// Уникальный идентификатор позиции инструмента.
     #[prost(string, tag = "27")]
@@ -1822,12 +2330,15 @@ pub struct Instrument {
     pub blocked_tca_flag: bool,
     /// Тип инструмента.
     #[prost(enumeration = "InstrumentType", tag = "40")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<InstrumentType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<InstrumentType, _>"))]
     pub instrument_kind: i32,
     /// Дата первой минутной свечи.
     #[prost(message, optional, tag = "56")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub first_1min_candle_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Дата первой дневной свечи.
     #[prost(message, optional, tag = "57")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub first_1day_candle_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Информация о бренде.
     #[prost(message, optional, tag = "60")]
@@ -1839,8 +2350,39 @@ pub struct Instrument {
     #[prost(message, optional, tag = "491")]
     pub dshort_client: ::core::option::Option<Quotation>,
 }
+impl Instrument {
+    /// Returns typed `trading_status`, keeping values unknown to the contracts.
+    pub fn trading_status_value(&self) -> crate::api::v1::EnumValue<SecurityTradingStatus> {
+        crate::api::v1::EnumValue::from(self.trading_status)
+    }
+    /// Sets `trading_status` and returns message.
+    pub fn with_trading_status(mut self, value: SecurityTradingStatus) -> Self {
+        self.trading_status = value as i32;
+        self
+    }
+    /// Returns typed `real_exchange`, keeping values unknown to the contracts.
+    pub fn real_exchange_value(&self) -> crate::api::v1::EnumValue<RealExchange> {
+        crate::api::v1::EnumValue::from(self.real_exchange)
+    }
+    /// Sets `real_exchange` and returns message.
+    pub fn with_real_exchange(mut self, value: RealExchange) -> Self {
+        self.real_exchange = value as i32;
+        self
+    }
+    /// Returns typed `instrument_kind`, keeping values unknown to the contracts.
+    pub fn instrument_kind_value(&self) -> crate::api::v1::EnumValue<InstrumentType> {
+        crate::api::v1::EnumValue::from(self.instrument_kind)
+    }
+    /// Sets `instrument_kind` and returns message.
+    pub fn with_instrument_kind(mut self, value: InstrumentType) -> Self {
+        self.instrument_kind = value as i32;
+        self
+    }
+}
 /// Запрос дивидендов.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetDividendsRequest {
     /// FIGI-идентификатор инструмента.
     #[deprecated]
@@ -1848,9 +2390,11 @@ pub struct GetDividendsRequest {
     pub figi: ::prost::alloc::string::String,
     /// Начало запрашиваемого периода по UTC. Фильтрация происходит по параметру `record_date` — дата фиксации реестра.
     #[prost(message, optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub from: ::core::option::Option<::prost_types::Timestamp>,
     /// Окончание запрашиваемого периода по UTC. Фильтрация происходит по параметру `record_date` — дата фиксации реестра.
     #[prost(message, optional, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub to: ::core::option::Option<::prost_types::Timestamp>,
     /// Идентификатор инструмента — `figi` или `instrument_uid`.
     #[prost(string, tag = "4")]
@@ -1858,30 +2402,38 @@ pub struct GetDividendsRequest {
 }
 /// Дивиденды.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetDividendsResponse {
     #[prost(message, repeated, tag = "1")]
     pub dividends: ::prost::alloc::vec::Vec<Dividend>,
 }
 /// Информация о выплате.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct Dividend {
     /// Величина дивиденда на 1 ценную бумагу (включая валюту).
     #[prost(message, optional, tag = "1")]
     pub dividend_net: ::core::option::Option<MoneyValue>,
     /// Дата фактических выплат по UTC.
     #[prost(message, optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub payment_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Дата объявления дивидендов по UTC.
     #[prost(message, optional, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub declared_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Последний день (включительно) покупки для получения выплаты по UTC.
     #[prost(message, optional, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub last_buy_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Тип выплаты. Возможные значения: `Regular Cash` – регулярные выплаты, `Cancelled` – выплата отменена, `Daily Accrual` – ежедневное начисление, `Return of Capital` – возврат капитала, прочие типы выплат.
     #[prost(string, tag = "5")]
     pub dividend_type: ::prost::alloc::string::String,
     /// Дата фиксации реестра по UTC.
     #[prost(message, optional, tag = "6")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub record_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Регулярность выплаты. Возможные значения: `Annual` – ежегодная, `Semi-Anl` – каждые полгода, прочие типы выплат.
     #[prost(string, tag = "7")]
@@ -1894,10 +2446,13 @@ pub struct Dividend {
     pub yield_value: ::core::option::Option<Quotation>,
     /// Дата и время создания записи по UTC.
     #[prost(message, optional, tag = "10")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub created_at: ::core::option::Option<::prost_types::Timestamp>,
 }
 /// Запрос актива по идентификатору.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct AssetRequest {
     /// UID-идентификатор актива.
     #[prost(string, tag = "1")]
@@ -1905,6 +2460,8 @@ pub struct AssetRequest {
 }
 /// Данные по активу.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct AssetResponse {
     /// Актив.
     #[prost(message, optional, tag = "1")]
@@ -1912,27 +2469,56 @@ pub struct AssetResponse {
 }
 /// Запрос списка активов.
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct AssetsRequest {
     #[prost(enumeration = "InstrumentType", optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration_option::serialize::<InstrumentType, _>", deserialize_with = "crate::api::v1::serialization::enumeration_option::deserialize::<InstrumentType, _>"))]
     pub instrument_type: ::core::option::Option<i32>,
     /// Статус запрашиваемых инструментов. [Возможные значения](#instrumentstatus).
     #[prost(enumeration = "InstrumentStatus", optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration_option::serialize::<InstrumentStatus, _>", deserialize_with = "crate::api::v1::serialization::enumeration_option::deserialize::<InstrumentStatus, _>"))]
     pub instrument_status: ::core::option::Option<i32>,
 }
+impl AssetsRequest {
+    /// Returns typed `instrument_type`, keeping values unknown to the contracts.
+    pub fn instrument_type_value(&self) -> ::core::option::Option<crate::api::v1::EnumValue<InstrumentType>> {
+        self.instrument_type.map(crate::api::v1::EnumValue::from)
+    }
+    /// Sets `instrument_type` and returns message.
+    pub fn with_instrument_type(mut self, value: InstrumentType) -> Self {
+        self.instrument_type = ::core::option::Option::Some(value as i32);
+        self
+    }
+    /// Returns typed `instrument_status`, keeping values unknown to the contracts.
+    pub fn instrument_status_value(&self) -> ::core::option::Option<crate::api::v1::EnumValue<InstrumentStatus>> {
+        self.instrument_status.map(crate::api::v1::EnumValue::from)
+    }
+    /// Sets `instrument_status` and returns message.
+    pub fn with_instrument_status(mut self, value: InstrumentStatus) -> Self {
+        self.instrument_status = ::core::option::Option::Some(value as i32);
+        self
+    }
+}
 /// Список активов.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct AssetsResponse {
     /// Активы.
     #[prost(message, repeated, tag = "1")]
     pub assets: ::prost::alloc::vec::Vec<Asset>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct AssetFull {
     /// Уникальный идентификатор актива.
     #[prost(string, tag = "1")]
     pub uid: ::prost::alloc::string::String,
     /// Тип актива.
     #[prost(enumeration = "AssetType", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<AssetType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<AssetType, _>"))]
     pub r#type: i32,
     /// Наименование актива.
     #[prost(string, tag = "3")]
@@ -1945,6 +2531,7 @@ pub struct AssetFull {
     pub description: ::prost::alloc::string::String,
     /// Дата и время удаления актива.
     #[prost(message, optional, tag = "6")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub deleted_at: ::core::option::Option<::prost_types::Timestamp>,
     /// Тестирование клиентов.
     #[prost(string, repeated, tag = "7")]
@@ -1966,6 +2553,7 @@ pub struct AssetFull {
     pub brand: ::core::option::Option<Brand>,
     /// Дата и время последнего обновления записи.
     #[prost(message, optional, tag = "15")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub updated_at: ::core::option::Option<::prost_types::Timestamp>,
     /// Код типа ц.б. по классификации Банка России.
     #[prost(string, tag = "16")]
@@ -1977,11 +2565,25 @@ pub struct AssetFull {
     #[prost(message, repeated, tag = "18")]
     pub instruments: ::prost::alloc::vec::Vec<AssetInstrument>,
     #[prost(oneof = "asset_full::Ext", tags = "8, 9")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub ext: ::core::option::Option<asset_full::Ext>,
 }
+impl AssetFull {
+    /// Returns typed `type`, keeping values unknown to the contracts.
+    pub fn type_value(&self) -> crate::api::v1::EnumValue<AssetType> {
+        crate::api::v1::EnumValue::from(self.r#type)
+    }
+    /// Sets `type` and returns message.
+    pub fn with_type(mut self, value: AssetType) -> Self {
+        self.r#type = value as i32;
+        self
+    }
+}
 /// Nested message and enum types in `AssetFull`.
 pub mod asset_full {
     #[derive(Clone, PartialEq, ::prost::Oneof)]
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
     pub enum Ext {
         /// Валюта. Обязательно и заполняется только для `type = ASSET_TYPE_CURRENCY`.
         #[prost(message, tag = "8")]
@@ -1993,12 +2595,15 @@ pub mod asset_full {
 }
 /// Информация об активе.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct Asset {
     /// Уникальный идентификатор актива.
     #[prost(string, tag = "1")]
     pub uid: ::prost::alloc::string::String,
     /// Тип актива.
     #[prost(enumeration = "AssetType", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<AssetType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<AssetType, _>"))]
     pub r#type: i32,
     /// Наименование актива.
     #[prost(string, tag = "3")]
@@ -2007,8 +2612,21 @@ pub struct Asset {
     #[prost(message, repeated, tag = "4")]
     pub instruments: ::prost::alloc::vec::Vec<AssetInstrument>,
 }
+impl Asset {
+    /// Returns typed `type`, keeping values unknown to the contracts.
+    pub fn type_value(&self) -> crate::api::v1::EnumValue<AssetType> {
+        crate::api::v1::EnumValue::from(self.r#type)
+    }
+    /// Sets `type` and returns message.
+    pub fn with_type(mut self, value: AssetType) -> Self {
+        self.r#type = value as i32;
+        self
+    }
+}
 /// Валюта.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct AssetCurrency {
     /// ISO-код валюты.
     #[prost(string, tag = "1")]
@@ -2016,6 +2634,8 @@ pub struct AssetCurrency {
 }
 /// Ценная бумага.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct AssetSecurity {
     /// ISIN-идентификатор ценной бумаги.
     #[prost(string, tag = "1")]
@@ -2025,13 +2645,28 @@ pub struct AssetSecurity {
     pub r#type: ::prost::alloc::string::String,
     /// Тип инструмента.
     #[prost(enumeration = "InstrumentType", tag = "10")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<InstrumentType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<InstrumentType, _>"))]
     pub instrument_kind: i32,
     #[prost(oneof = "asset_security::Ext", tags = "3, 4, 5, 6, 7")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub ext: ::core::option::Option<asset_security::Ext>,
 }
+impl AssetSecurity {
+    /// Returns typed `instrument_kind`, keeping values unknown to the contracts.
+    pub fn instrument_kind_value(&self) -> crate::api::v1::EnumValue<InstrumentType> {
+        crate::api::v1::EnumValue::from(self.instrument_kind)
+    }
+    /// Sets `instrument_kind` and returns message.
+    pub fn with_instrument_kind(mut self, value: InstrumentType) -> Self {
+        self.instrument_kind = value as i32;
+        self
+    }
+}
 /// Nested message and enum types in `AssetSecurity`.
 pub mod asset_security {
     #[derive(Clone, PartialEq, ::prost::Oneof)]
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
     pub enum Ext {
         /// Акция. Заполняется только для акций — тип актива `asset.type = ASSET_TYPE_SECURITY` и `security.type = share`.
         #[prost(message, tag = "3")]
@@ -2052,9 +2687,12 @@ pub mod asset_security {
 }
 /// Акция.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct AssetShare {
     /// Тип акции.
     #[prost(enumeration = "ShareType", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<ShareType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<ShareType, _>"))]
     pub r#type: i32,
     /// Объем выпуска (шт.).
     #[prost(message, optional, tag = "2")]
@@ -2076,9 +2714,11 @@ pub struct AssetShare {
     pub preferred_share_type: ::prost::alloc::string::String,
     /// Дата IPO.
     #[prost(message, optional, tag = "8")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub ipo_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Дата регистрации.
     #[prost(message, optional, tag = "9")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub registry_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Признак наличия дивидендной доходности.
     #[prost(bool, tag = "10")]
@@ -2088,6 +2728,7 @@ pub struct AssetShare {
     pub issue_kind: ::prost::alloc::string::String,
     /// Дата размещения акции.
     #[prost(message, optional, tag = "12")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub placement_date: ::core::option::Option<::prost_types::Timestamp>,
     /// ISIN базового актива.
     #[prost(string, tag = "13")]
@@ -2099,8 +2740,21 @@ pub struct AssetShare {
     #[prost(message, optional, tag = "15")]
     pub total_float: ::core::option::Option<Quotation>,
 }
+impl AssetShare {
+    /// Returns typed `type`, keeping values unknown to the contracts.
+    pub fn type_value(&self) -> crate::api::v1::EnumValue<ShareType> {
+        crate::api::v1::EnumValue::from(self.r#type)
+    }
+    /// Sets `type` and returns message.
+    pub fn with_type(mut self, value: ShareType) -> Self {
+        self.r#type = value as i32;
+        self
+    }
+}
 /// Облигация.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct AssetBond {
     /// Текущий номинал.
     #[prost(message, optional, tag = "1")]
@@ -2149,15 +2803,18 @@ pub struct AssetBond {
     pub perpetual_flag: bool,
     /// Дата погашения облигации.
     #[prost(message, optional, tag = "16")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub maturity_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Описание и условия получения дополнительного дохода.
     #[prost(string, tag = "17")]
     pub return_condition: ::prost::alloc::string::String,
     /// Дата выпуска облигации.
     #[prost(message, optional, tag = "18")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub state_reg_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Дата размещения облигации.
     #[prost(message, optional, tag = "19")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub placement_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Цена размещения облигации.
     #[prost(message, optional, tag = "20")]
@@ -2168,6 +2825,8 @@ pub struct AssetBond {
 }
 /// Структурная нота.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct AssetStructuredProduct {
     /// Наименование заёмщика.
     #[prost(string, tag = "1")]
@@ -2180,12 +2839,14 @@ pub struct AssetStructuredProduct {
     pub nominal_currency: ::prost::alloc::string::String,
     /// Тип структурной ноты.
     #[prost(enumeration = "StructuredProductType", tag = "4")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<StructuredProductType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<StructuredProductType, _>"))]
     pub r#type: i32,
     /// Стратегия портфеля.
     #[prost(string, tag = "5")]
     pub logic_portfolio: ::prost::alloc::string::String,
     /// Тип базового актива.
     #[prost(enumeration = "AssetType", tag = "6")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<AssetType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<AssetType, _>"))]
     pub asset_type: i32,
     /// Вид базового актива в зависимости от типа базового актива.
     #[prost(string, tag = "7")]
@@ -2195,6 +2856,7 @@ pub struct AssetStructuredProduct {
     pub safety_barrier: ::core::option::Option<Quotation>,
     /// Дата погашения.
     #[prost(message, optional, tag = "9")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub maturity_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Объявленное количество, шт.
     #[prost(message, optional, tag = "10")]
@@ -2204,13 +2866,36 @@ pub struct AssetStructuredProduct {
     pub issue_size: ::core::option::Option<Quotation>,
     /// Дата размещения ноты.
     #[prost(message, optional, tag = "12")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub placement_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Форма выпуска.
     #[prost(string, tag = "13")]
     pub issue_kind: ::prost::alloc::string::String,
 }
+impl AssetStructuredProduct {
+    /// Returns typed `type`, keeping values unknown to the contracts.
+    pub fn type_value(&self) -> crate::api::v1::EnumValue<StructuredProductType> {
+        crate::api::v1::EnumValue::from(self.r#type)
+    }
+    /// Sets `type` and returns message.
+    pub fn with_type(mut self, value: StructuredProductType) -> Self {
+        self.r#type = value as i32;
+        self
+    }
+    /// Returns typed `asset_type`, keeping values unknown to the contracts.
+    pub fn asset_type_value(&self) -> crate::api::v1::EnumValue<AssetType> {
+        crate::api::v1::EnumValue::from(self.asset_type)
+    }
+    /// Sets `asset_type` and returns message.
+    pub fn with_asset_type(mut self, value: AssetType) -> Self {
+        self.asset_type = value as i32;
+        self
+    }
+}
 /// Фонд.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct AssetEtf {
     /// Суммарные расходы фонда в процентах.
     #[prost(message, optional, tag = "1")]
@@ -2262,6 +2947,7 @@ pub struct AssetEtf {
     pub ucits_flag: bool,
     /// Дата выпуска.
     #[prost(message, optional, tag = "17")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub released_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Описание фонда.
     #[prost(string, tag = "18")]
@@ -2295,6 +2981,7 @@ pub struct AssetEtf {
     pub tax_rate: ::prost::alloc::string::String,
     /// Даты ребалансировок.
     #[prost(message, repeated, tag = "28")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp_vec"))]
     pub rebalancing_dates: ::prost::alloc::vec::Vec<::prost_types::Timestamp>,
     /// Форма выпуска.
     #[prost(string, tag = "29")]
@@ -2308,6 +2995,8 @@ pub struct AssetEtf {
 }
 /// Клиринговый сертификат участия.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct AssetClearingCertificate {
     /// Номинал.
     #[prost(message, optional, tag = "1")]
@@ -2318,6 +3007,8 @@ pub struct AssetClearingCertificate {
 }
 /// Бренд.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct Brand {
     /// UID-идентификатор бренда.
     #[prost(string, tag = "1")]
@@ -2346,6 +3037,8 @@ pub struct Brand {
 }
 /// Идентификаторы инструмента.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct AssetInstrument {
     /// UID-идентификатор инструмента.
     #[prost(string, tag = "1")]
@@ -2367,13 +3060,27 @@ pub struct AssetInstrument {
     pub links: ::prost::alloc::vec::Vec<InstrumentLink>,
     /// Тип инструмента.
     #[prost(enumeration = "InstrumentType", tag = "10")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<InstrumentType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<InstrumentType, _>"))]
     pub instrument_kind: i32,
     /// ID позиции.
     #[prost(string, tag = "11")]
     pub position_uid: ::prost::alloc::string::String,
 }
+impl AssetInstrument {
+    /// Returns typed `instrument_kind`, keeping values unknown to the contracts.
+    pub fn instrument_kind_value(&self) -> crate::api::v1::EnumValue<InstrumentType> {
+        crate::api::v1::EnumValue::from(self.instrument_kind)
+    }
+    /// Sets `instrument_kind` and returns message.
+    pub fn with_instrument_kind(mut self, value: InstrumentType) -> Self {
+        self.instrument_kind = value as i32;
+        self
+    }
+}
 /// Связь с другим инструментом.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct InstrumentLink {
     /// Тип связи.
     #[prost(string, tag = "1")]
@@ -2384,9 +3091,13 @@ pub struct InstrumentLink {
 }
 /// Запрос списка избранных инструментов, входные параметры не требуются.
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetFavoritesRequest {}
 /// В ответ передаётся список избранных инструментов в качестве массива.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetFavoritesResponse {
     /// Массив инструментов.
     #[prost(message, repeated, tag = "1")]
@@ -2394,6 +3105,8 @@ pub struct GetFavoritesResponse {
 }
 /// Массив избранных инструментов.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct FavoriteInstrument {
     /// FIGI-идентификатор инструмента.
     #[prost(string, tag = "1")]
@@ -2424,20 +3137,48 @@ pub struct FavoriteInstrument {
     pub api_trade_available_flag: bool,
     /// Тип инструмента.
     #[prost(enumeration = "InstrumentType", tag = "18")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<InstrumentType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<InstrumentType, _>"))]
     pub instrument_kind: i32,
 }
+impl FavoriteInstrument {
+    /// Returns typed `instrument_kind`, keeping values unknown to the contracts.
+    pub fn instrument_kind_value(&self) -> crate::api::v1::EnumValue<InstrumentType> {
+        crate::api::v1::EnumValue::from(self.instrument_kind)
+    }
+    /// Sets `instrument_kind` and returns message.
+    pub fn with_instrument_kind(mut self, value: InstrumentType) -> Self {
+        self.instrument_kind = value as i32;
+        self
+    }
+}
 /// Запрос редактирования списка избранных инструментов.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct EditFavoritesRequest {
     /// Массив инструментов.
     #[prost(message, repeated, tag = "1")]
     pub instruments: ::prost::alloc::vec::Vec<EditFavoritesRequestInstrument>,
     /// Тип действия со списком.
     #[prost(enumeration = "EditFavoritesActionType", tag = "6")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<EditFavoritesActionType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<EditFavoritesActionType, _>"))]
     pub action_type: i32,
 }
+impl EditFavoritesRequest {
+    /// Returns typed `action_type`, keeping values unknown to the contracts.
+    pub fn action_type_value(&self) -> crate::api::v1::EnumValue<EditFavoritesActionType> {
+        crate::api::v1::EnumValue::from(self.action_type)
+    }
+    /// Sets `action_type` and returns message.
+    pub fn with_action_type(mut self, value: EditFavoritesActionType) -> Self {
+        self.action_type = value as i32;
+        self
+    }
+}
 /// Массив инструментов для редактирования списка избранных инструментов.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct EditFavoritesRequestInstrument {
     /// FIGI-идентификатор инструмента.
     #[deprecated]
@@ -2449,6 +3190,8 @@ pub struct EditFavoritesRequestInstrument {
 }
 /// Результат редактирования списка избранных инструментов.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct EditFavoritesResponse {
     /// Массив инструментов.
     #[prost(message, repeated, tag = "1")]
@@ -2456,9 +3199,13 @@ pub struct EditFavoritesResponse {
 }
 /// Запрос справочника стран.
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetCountriesRequest {}
 /// Справочник стран.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetCountriesResponse {
     /// Массив стран.
     #[prost(message, repeated, tag = "1")]
@@ -2466,9 +3213,13 @@ pub struct GetCountriesResponse {
 }
 /// Запрос справочника индексов и товаров
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct IndicativesRequest {}
 /// Справочник индексов и товаров
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct IndicativesResponse {
     /// Массив инструментов.
     #[prost(message, repeated, tag = "1")]
@@ -2476,6 +3227,8 @@ pub struct IndicativesResponse {
 }
 /// Индикатив
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct IndicativeResponse {
     /// FIGI-идентификатор инструмента.
     #[prost(string, tag = "1")]
@@ -2491,6 +3244,7 @@ pub struct IndicativeResponse {
     pub currency: ::prost::alloc::string::String,
     /// Тип инструмента.
     #[prost(enumeration = "InstrumentType", tag = "10")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<InstrumentType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<InstrumentType, _>"))]
     pub instrument_kind: i32,
     /// Название инструмента.
     #[prost(string, tag = "12")]
@@ -2508,8 +3262,21 @@ pub struct IndicativeResponse {
     #[prost(bool, tag = "405")]
     pub sell_available_flag: bool,
 }
+impl IndicativeResponse {
+    /// Returns typed `instrument_kind`, keeping values unknown to the contracts.
+    pub fn instrument_kind_value(&self) -> crate::api::v1::EnumValue<InstrumentType> {
+        crate::api::v1::EnumValue::from(self.instrument_kind)
+    }
+    /// Sets `instrument_kind` and returns message.
+    pub fn with_instrument_kind(mut self, value: InstrumentType) -> Self {
+        self.instrument_kind = value as i32;
+        self
+    }
+}
 /// Данные о стране.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct CountryResponse {
     /// Двухбуквенный код страны.
     #[prost(string, tag = "1")]
@@ -2526,19 +3293,35 @@ pub struct CountryResponse {
 }
 /// Запрос на поиск инструментов.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct FindInstrumentRequest {
     /// Строка поиска.
     #[prost(string, tag = "1")]
     pub query: ::prost::alloc::string::String,
     /// Фильтр по типу инструмента.
     #[prost(enumeration = "InstrumentType", optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration_option::serialize::<InstrumentType, _>", deserialize_with = "crate::api::v1::serialization::enumeration_option::deserialize::<InstrumentType, _>"))]
     pub instrument_kind: ::core::option::Option<i32>,
     /// Фильтр для отображения только торговых инструментов.
     #[prost(bool, optional, tag = "3")]
     pub api_trade_available_flag: ::core::option::Option<bool>,
 }
+impl FindInstrumentRequest {
+    /// Returns typed `instrument_kind`, keeping values unknown to the contracts.
+    pub fn instrument_kind_value(&self) -> ::core::option::Option<crate::api::v1::EnumValue<InstrumentType>> {
+        self.instrument_kind.map(crate::api::v1::EnumValue::from)
+    }
+    /// Sets `instrument_kind` and returns message.
+    pub fn with_instrument_kind(mut self, value: InstrumentType) -> Self {
+        self.instrument_kind = ::core::option::Option::Some(value as i32);
+        self
+    }
+}
 /// Результат поиска инструментов.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct FindInstrumentResponse {
     /// Массив инструментов, удовлетворяющих условиям поиска.
     #[prost(message, repeated, tag = "1")]
@@ -2546,6 +3329,8 @@ pub struct FindInstrumentResponse {
 }
 /// Краткая информация об инструменте.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct InstrumentShort {
     /// ISIN инструмента.
     #[prost(string, tag = "1")]
@@ -2573,6 +3358,7 @@ pub struct InstrumentShort {
     pub position_uid: ::prost::alloc::string::String,
     /// Тип инструмента.
     #[prost(enumeration = "InstrumentType", tag = "10")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<InstrumentType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<InstrumentType, _>"))]
     pub instrument_kind: i32,
     /// Возможность торговать инструментом через API.
     #[prost(bool, tag = "11")]
@@ -2582,9 +3368,11 @@ pub struct InstrumentShort {
     pub for_iis_flag: bool,
     /// Дата первой минутной свечи.
     #[prost(message, optional, tag = "26")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub first_1min_candle_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Дата первой дневной свечи.
     #[prost(message, optional, tag = "27")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub first_1day_candle_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Флаг, отображающий доступность торговли инструментом только для квалифицированных инвесторов.
     #[prost(bool, tag = "28")]
@@ -2599,8 +3387,21 @@ pub struct InstrumentShort {
     #[prost(int32, tag = "31")]
     pub lot: i32,
 }
+impl InstrumentShort {
+    /// Returns typed `instrument_kind`, keeping values unknown to the contracts.
+    pub fn instrument_kind_value(&self) -> crate::api::v1::EnumValue<InstrumentType> {
+        crate::api::v1::EnumValue::from(self.instrument_kind)
+    }
+    /// Sets `instrument_kind` and returns message.
+    pub fn with_instrument_kind(mut self, value: InstrumentType) -> Self {
+        self.instrument_kind = value as i32;
+        self
+    }
+}
 /// Запрос списка брендов.
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetBrandsRequest {
     /// Настройки пагинации.
     #[prost(message, optional, tag = "1")]
@@ -2608,6 +3409,8 @@ pub struct GetBrandsRequest {
 }
 /// Запрос бренда.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetBrandRequest {
     /// UID-идентификатор бренда.
     #[prost(string, tag = "1")]
@@ -2615,6 +3418,8 @@ pub struct GetBrandRequest {
 }
 /// Список брендов.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetBrandsResponse {
     /// Массив брендов.
     #[prost(message, repeated, tag = "1")]
@@ -2625,6 +3430,8 @@ pub struct GetBrandsResponse {
 }
 /// Запрос фундаментальных показателей
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetAssetFundamentalsRequest {
     /// Массив идентификаторов активов, не более 100 шт.
     #[prost(string, repeated, tag = "1")]
@@ -2632,6 +3439,8 @@ pub struct GetAssetFundamentalsRequest {
 }
 /// Фундаментальные показатели
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetAssetFundamentalsResponse {
     #[prost(message, repeated, tag = "1")]
     pub fundamentals: ::prost::alloc::vec::Vec<
@@ -2642,6 +3451,8 @@ pub struct GetAssetFundamentalsResponse {
 pub mod get_asset_fundamentals_response {
     /// Фундаментальные показатели по активу
     #[derive(Clone, PartialEq, ::prost::Message)]
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
     pub struct StatisticResponse {
         /// Идентификатор актива.
         #[prost(string, tag = "1")]
@@ -2787,12 +3598,15 @@ pub mod get_asset_fundamentals_response {
         #[prost(double, tag = "48")]
         pub number_of_employees: f64,
         #[prost(message, optional, tag = "49")]
+        #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
         pub ex_dividend_date: ::core::option::Option<::prost_types::Timestamp>,
         /// Начало фискального периода.
         #[prost(message, optional, tag = "50")]
+        #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
         pub fiscal_period_start_date: ::core::option::Option<::prost_types::Timestamp>,
         /// Окончание фискального периода.
         #[prost(message, optional, tag = "51")]
+        #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
         pub fiscal_period_end_date: ::core::option::Option<::prost_types::Timestamp>,
         /// Изменение общего дохода за 5 лет.
         #[prost(double, tag = "53")]
@@ -2813,19 +3627,25 @@ pub mod get_asset_fundamentals_response {
 }
 /// Запрос отчетов эмитентов
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetAssetReportsRequest {
     /// Идентификатор инструмента в формате UID.
     #[prost(string, tag = "1")]
     pub instrument_id: ::prost::alloc::string::String,
     /// Начало запрашиваемого периода по UTC.
     #[prost(message, optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub from: ::core::option::Option<::prost_types::Timestamp>,
     /// Окончание запрашиваемого периода по UTC.
     #[prost(message, optional, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub to: ::core::option::Option<::prost_types::Timestamp>,
 }
 /// Отчеты эмитентов
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetAssetReportsResponse {
     /// Массив событий по облигации.
     #[prost(message, repeated, tag = "1")]
@@ -2837,12 +3657,15 @@ pub struct GetAssetReportsResponse {
 pub mod get_asset_reports_response {
     /// Отчет
     #[derive(Clone, PartialEq, ::prost::Message)]
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
     pub struct GetAssetReportsEvent {
         /// Идентификатор инструмента.
         #[prost(string, tag = "1")]
         pub instrument_id: ::prost::alloc::string::String,
         /// Дата публикации отчёта.
         #[prost(message, optional, tag = "2")]
+        #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
         pub report_date: ::core::option::Option<::prost_types::Timestamp>,
         /// Год периода отчета.
         #[prost(int32, tag = "3")]
@@ -2852,11 +3675,24 @@ pub mod get_asset_reports_response {
         pub period_num: i32,
         /// Тип отчёта.
         #[prost(enumeration = "AssetReportPeriodType", tag = "5")]
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<AssetReportPeriodType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<AssetReportPeriodType, _>"))]
         pub period_type: i32,
         /// Дата создания записи.
         #[prost(message, optional, tag = "6")]
+        #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
         pub created_at: ::core::option::Option<::prost_types::Timestamp>,
     }
+    impl GetAssetReportsEvent {
+        /// Returns typed `period_type`, keeping values unknown to the contracts.
+        pub fn period_type_value(&self) -> crate::api::v1::EnumValue<AssetReportPeriodType> {
+            crate::api::v1::EnumValue::from(self.period_type)
+        }
+        /// Sets `period_type` and returns message.
+        pub fn with_period_type(mut self, value: AssetReportPeriodType) -> Self {
+            self.period_type = value as i32;
+            self
+        }
+    }
     #[derive(
         Clone,
         Copy,
@@ -2903,9 +3739,20 @@ pub mod get_asset_reports_response {
             }
         }
     }
+    #[cfg(feature = "serde")]
+    impl crate::api::v1::serialization::ProtoEnum for AssetReportPeriodType {
+        fn proto_name(&self) -> &'static str {
+            self.as_str_name()
+        }
+        fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+            Self::from_str_name(value)
+        }
+    }
 }
 /// Запрос консенсус-прогнозов
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetConsensusForecastsRequest {
     /// Настройки пагинации.
     #[prost(message, optional, tag = "1")]
@@ -2913,6 +3760,8 @@ pub struct GetConsensusForecastsRequest {
 }
 /// Консенсус-прогнозы
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetConsensusForecastsResponse {
     /// Массив прогнозов.
     #[prost(message, repeated, tag = "1")]
@@ -2927,6 +3776,8 @@ pub struct GetConsensusForecastsResponse {
 pub mod get_consensus_forecasts_response {
     /// Прогноз
     #[derive(Clone, PartialEq, ::prost::Message)]
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
     pub struct ConsensusForecastsItem {
         /// UID-идентификатор.
         #[prost(string, tag = "1")]
@@ -2936,6 +3787,7 @@ pub mod get_consensus_forecasts_response {
         pub asset_uid: ::prost::alloc::string::String,
         /// Дата и время создания записи.
         #[prost(message, optional, tag = "3")]
+        #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
         pub created_at: ::core::option::Option<::prost_types::Timestamp>,
         /// Целевая цена на 12 месяцев.
         #[prost(message, optional, tag = "4")]
@@ -2960,14 +3812,29 @@ pub mod get_consensus_forecasts_response {
         pub currency: ::prost::alloc::string::String,
         /// Консенсус-прогноз.
         #[prost(enumeration = "super::Recommendation", tag = "11")]
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<super::Recommendation, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<super::Recommendation, _>"))]
         pub consensus: i32,
         /// Дата прогноза.
         #[prost(message, optional, tag = "12")]
+        #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
         pub prognosis_date: ::core::option::Option<::prost_types::Timestamp>,
     }
+    impl ConsensusForecastsItem {
+        /// Returns typed `consensus`, keeping values unknown to the contracts.
+        pub fn consensus_value(&self) -> crate::api::v1::EnumValue<super::Recommendation> {
+            crate::api::v1::EnumValue::from(self.consensus)
+        }
+        /// Sets `consensus` and returns message.
+        pub fn with_consensus(mut self, value: super::Recommendation) -> Self {
+            self.consensus = value as i32;
+            self
+        }
+    }
 }
 /// Запрос прогнозов инвестдомов.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetForecastRequest {
     /// Идентификатор инструмента.
     #[prost(string, tag = "1")]
@@ -2975,6 +3842,8 @@ pub struct GetForecastRequest {
 }
 /// Прогнозы инвестдомов по инструменту.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetForecastResponse {
     /// Массив прогнозов.
     #[prost(message, repeated, tag = "1")]
@@ -2987,6 +3856,8 @@ pub struct GetForecastResponse {
 pub mod get_forecast_response {
     /// Прогноз
     #[derive(Clone, PartialEq, ::prost::Message)]
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
     pub struct TargetItem {
         /// Уникальный идентификатор инструмента.
         #[prost(string, tag = "1")]
@@ -2999,9 +3870,11 @@ pub mod get_forecast_response {
         pub company: ::prost::alloc::string::String,
         /// Прогноз.
         #[prost(enumeration = "super::Recommendation", tag = "4")]
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<super::Recommendation, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<super::Recommendation, _>"))]
         pub recommendation: i32,
         /// Дата прогноза.
         #[prost(message, optional, tag = "5")]
+        #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
         pub recommendation_date: ::core::option::Option<::prost_types::Timestamp>,
         /// Валюта.
         #[prost(string, tag = "6")]
@@ -3022,8 +3895,21 @@ pub mod get_forecast_response {
         #[prost(string, tag = "11")]
         pub show_name: ::prost::alloc::string::String,
     }
+    impl TargetItem {
+        /// Returns typed `recommendation`, keeping values unknown to the contracts.
+        pub fn recommendation_value(&self) -> crate::api::v1::EnumValue<super::Recommendation> {
+            crate::api::v1::EnumValue::from(self.recommendation)
+        }
+        /// Sets `recommendation` and returns message.
+        pub fn with_recommendation(mut self, value: super::Recommendation) -> Self {
+            self.recommendation = value as i32;
+            self
+        }
+    }
     /// Консенсус-прогноз.
     #[derive(Clone, PartialEq, ::prost::Message)]
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
     pub struct ConsensusItem {
         /// Уникальный идентификатор инструмента.
         #[prost(string, tag = "1")]
@@ -3033,6 +3919,7 @@ pub mod get_forecast_response {
         pub ticker: ::prost::alloc::string::String,
         /// Прогноз.
         #[prost(enumeration = "super::Recommendation", tag = "3")]
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<super::Recommendation, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<super::Recommendation, _>"))]
         pub recommendation: i32,
         /// Валюта.
         #[prost(string, tag = "4")]
@@ -3056,8 +3943,21 @@ pub mod get_forecast_response {
         #[prost(message, optional, tag = "10")]
         pub price_change_rel: ::core::option::Option<super::Quotation>,
     }
+    impl ConsensusItem {
+        /// Returns typed `recommendation`, keeping values unknown to the contracts.
+        pub fn recommendation_value(&self) -> crate::api::v1::EnumValue<super::Recommendation> {
+            crate::api::v1::EnumValue::from(self.recommendation)
+        }
+        /// Sets `recommendation` and returns message.
+        pub fn with_recommendation(mut self, value: super::Recommendation) -> Self {
+            self.recommendation = value as i32;
+            self
+        }
+    }
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct TradingInterval {
     /// Название интервала.
     #[prost(string, tag = "1")]
@@ -3069,12 +3969,16 @@ pub struct TradingInterval {
 /// Nested message and enum types in `TradingInterval`.
 pub mod trading_interval {
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
     pub struct TimeInterval {
         /// Время начала интервала.
         #[prost(message, optional, tag = "1")]
+        #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
         pub start_ts: ::core::option::Option<::prost_types::Timestamp>,
         /// Время окончания интервала.
         #[prost(message, optional, tag = "2")]
+        #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
         pub end_ts: ::core::option::Option<::prost_types::Timestamp>,
     }
 }
@@ -3131,6 +4035,15 @@ impl CouponType {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for CouponType {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Тип опциона по направлению сделки.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -3164,6 +4077,15 @@ impl OptionDirection {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for OptionDirection {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Тип расчётов по опциону.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -3197,6 +4119,15 @@ impl OptionPaymentType {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for OptionPaymentType {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Тип опциона по стилю.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -3230,6 +4161,15 @@ impl OptionStyle {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for OptionStyle {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Тип опциона по способу исполнения.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -3273,6 +4213,15 @@ impl OptionSettlementType {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for OptionSettlementType {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Тип идентификатора инструмента. [Подробнее об идентификации инструментов](<https://russianinvestments.github.io/investAPI/faq_identification/>).
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -3314,6 +4263,15 @@ impl InstrumentIdType {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for InstrumentIdType {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Тип акций.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -3371,6 +4329,15 @@ impl ShareType {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for ShareType {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Тип актива.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -3412,6 +4379,15 @@ impl AssetType {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for AssetType {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Тип структурной ноты.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -3445,6 +4421,15 @@ impl StructuredProductType {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for StructuredProductType {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Тип действия со списком избранных инструментов.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -3478,6 +4463,15 @@ impl EditFavoritesActionType {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for EditFavoritesActionType {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Реальная площадка исполнения расчётов.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -3519,6 +4513,15 @@ impl RealExchange {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for RealExchange {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum Recommendation {
@@ -3555,6 +4558,15 @@ impl Recommendation {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for Recommendation {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Уровень риска облигации.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -3592,6 +4604,15 @@ impl RiskLevel {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for RiskLevel {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum BondType {
@@ -3620,6 +4641,15 @@ impl BondType {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for BondType {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Площадка торговли.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -3651,6 +4681,15 @@ impl InstrumentExchangeType {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for InstrumentExchangeType {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Generated client implementations.
 pub mod instruments_service_client {
     #![allow(
@@ -4704,13 +5743,18 @@ pub mod instruments_service_client {
 }
 /// Запрос подписки или отписки на определённые биржевые данные.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct MarketDataRequest {
     #[prost(oneof = "market_data_request::Payload", tags = "1, 2, 3, 4, 5, 6, 7, 15")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub payload: ::core::option::Option<market_data_request::Payload>,
 }
 /// Nested message and enum types in `MarketDataRequest`.
 pub mod market_data_request {
     #[derive(Clone, PartialEq, ::prost::Oneof)]
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
     pub enum Payload {
         /// Запрос подписки на свечи.
         #[prost(message, tag = "1")]
@@ -4739,6 +5783,8 @@ pub mod market_data_request {
     }
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct MarketDataServerSideStreamRequest {
     /// Запрос подписки на свечи.
     #[prost(message, optional, tag = "1")]
@@ -4761,16 +5807,21 @@ pub struct MarketDataServerSideStreamRequest {
 }
 /// Пакет биржевой информации по подписке.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct MarketDataResponse {
     #[prost(
         oneof = "market_data_response::Payload",
         tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11"
     )]
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub payload: ::core::option::Option<market_data_response::Payload>,
 }
 /// Nested message and enum types in `MarketDataResponse`.
 pub mod market_data_response {
     #[derive(Clone, PartialEq, ::prost::Oneof)]
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
     pub enum Payload {
         /// Результат подписки на свечи.
         #[prost(message, tag = "1")]
@@ -4809,9 +5860,12 @@ pub mod market_data_response {
 }
 /// subscribeCandles | Изменения статуса подписки на свечи.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct SubscribeCandlesRequest {
     /// Изменение статуса подписки.
     #[prost(enumeration = "SubscriptionAction", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<SubscriptionAction, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<SubscriptionAction, _>"))]
     pub subscription_action: i32,
     /// Массив инструментов для подписки на свечи.
     #[prost(message, repeated, tag = "2")]
@@ -4821,10 +5875,33 @@ pub struct SubscribeCandlesRequest {
     pub waiting_close: bool,
     /// Источник свечей.
     #[prost(enumeration = "get_candles_request::CandleSource", optional, tag = "9")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration_option::serialize::<get_candles_request::CandleSource, _>", deserialize_with = "crate::api::v1::serialization::enumeration_option::deserialize::<get_candles_request::CandleSource, _>"))]
     pub candle_source_type: ::core::option::Option<i32>,
 }
+impl SubscribeCandlesRequest {
+    /// Returns typed `subscription_action`, keeping values unknown to the contracts.
+    pub fn subscription_action_value(&self) -> crate::api::v1::EnumValue<SubscriptionAction> {
+        crate::api::v1::EnumValue::from(self.subscription_action)
+    }
+    /// Sets `subscription_action` and returns message.
+    pub fn with_subscription_action(mut self, value: SubscriptionAction) -> Self {
+        self.subscription_action = value as i32;
+        self
+    }
+    /// Returns typed `candle_source_type`, keeping values unknown to the contracts.
+    pub fn candle_source_type_value(&self) -> ::core::option::Option<crate::api::v1::EnumValue<get_candles_request::CandleSource>> {
+        self.candle_source_type.map(crate::api::v1::EnumValue::from)
+    }
+    /// Sets `candle_source_type` and returns message.
+    pub fn with_candle_source_type(mut self, value: get_candles_request::CandleSource) -> Self {
+        self.candle_source_type = ::core::option::Option::Some(value as i32);
+        self
+    }
+}
 /// Запрос изменения статус подписки на свечи.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct CandleInstrument {
     /// Deprecated FIGI-идентификатор инструмента. Используйте `instrument_id`.
     #[deprecated]
@@ -4832,13 +5909,27 @@ pub struct CandleInstrument {
     pub figi: ::prost::alloc::string::String,
     /// Интервал свечей. Двухчасовые и четырёхчасовые свечи в стриме отсчитываются с 0:00 по UTC.
     #[prost(enumeration = "SubscriptionInterval", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<SubscriptionInterval, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<SubscriptionInterval, _>"))]
     pub interval: i32,
     /// Идентификатор инструмента. Принимает значение `figi` или `instrument_uid`.
     #[prost(string, tag = "3")]
     pub instrument_id: ::prost::alloc::string::String,
 }
+impl CandleInstrument {
+    /// Returns typed `interval`, keeping values unknown to the contracts.
+    pub fn interval_value(&self) -> crate::api::v1::EnumValue<SubscriptionInterval> {
+        crate::api::v1::EnumValue::from(self.interval)
+    }
+    /// Sets `interval` and returns message.
+    pub fn with_interval(mut self, value: SubscriptionInterval) -> Self {
+        self.interval = value as i32;
+        self
+    }
+}
 /// Результат изменения статус подписки на свечи.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct SubscribeCandlesResponse {
     /// Уникальный идентификатор запроса. [Подробнее](<https://russianinvestments.github.io/investAPI/grpc#tracking-id>).
     #[prost(string, tag = "1")]
@@ -4849,15 +5940,19 @@ pub struct SubscribeCandlesResponse {
 }
 /// Статус подписки на свечи.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct CandleSubscription {
     /// FIGI-идентификатор инструмента.
     #[prost(string, tag = "1")]
     pub figi: ::prost::alloc::string::String,
     /// Интервал свечей.
     #[prost(enumeration = "SubscriptionInterval", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<SubscriptionInterval, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<SubscriptionInterval, _>"))]
     pub interval: i32,
     /// Статус подписки.
     #[prost(enumeration = "SubscriptionStatus", tag = "3")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<SubscriptionStatus, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<SubscriptionStatus, _>"))]
     pub subscription_status: i32,
     /// UID инструмента.
     #[prost(string, tag = "4")]
@@ -4873,20 +5968,66 @@ pub struct CandleSubscription {
     pub subscription_id: ::prost::alloc::string::String,
     /// Источник свечей.
     #[prost(enumeration = "get_candles_request::CandleSource", optional, tag = "9")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration_option::serialize::<get_candles_request::CandleSource, _>", deserialize_with = "crate::api::v1::serialization::enumeration_option::deserialize::<get_candles_request::CandleSource, _>"))]
     pub candle_source_type: ::core::option::Option<i32>,
 }
+impl CandleSubscription {
+    /// Returns typed `interval`, keeping values unknown to the contracts.
+    pub fn interval_value(&self) -> crate::api::v1::EnumValue<SubscriptionInterval> {
+        crate::api::v1::EnumValue::from(self.interval)
+    }
+    /// Sets `interval` and returns message.
+    pub fn with_interval(mut self, value: SubscriptionInterval) -> Self {
+        self.interval = value as i32;
+        self
+    }
+    /// Returns typed `subscription_status`, keeping values unknown to the contracts.
+    pub fn subscription_status_value(&self) -> crate::api::v1::EnumValue<SubscriptionStatus> {
+        crate::api::v1::EnumValue::from(self.subscription_status)
+    }
+    /// Sets `subscription_status` and returns message.
+    pub fn with_subscription_status(mut self, value: SubscriptionStatus) -> Self {
+        self.subscription_status = value as i32;
+        self
+    }
+    /// Returns typed `candle_source_type`, keeping values unknown to the contracts.
+    pub fn candle_source_type_value(&self) -> ::core::option::Option<crate::api::v1::EnumValue<get_candles_request::CandleSource>> {
+        self.candle_source_type.map(crate::api::v1::EnumValue::from)
+    }
+    /// Sets `candle_source_type` and returns message.
+    pub fn with_candle_source_type(mut self, value: get_candles_request::CandleSource) -> Self {
+        self.candle_source_type = ::core::option::Option::Some(value as i32);
+        self
+    }
+}
 /// Запрос на изменение статуса подписки на стаканы.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct SubscribeOrderBookRequest {
     /// Изменение статуса подписки.
     #[prost(enumeration = "SubscriptionAction", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<SubscriptionAction, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<SubscriptionAction, _>"))]
     pub subscription_action: i32,
     /// Массив инструментов для подписки на стаканы.
     #[prost(message, repeated, tag = "2")]
     pub instruments: ::prost::alloc::vec::Vec<OrderBookInstrument>,
 }
+impl SubscribeOrderBookRequest {
+    /// Returns typed `subscription_action`, keeping values unknown to the contracts.
+    pub fn subscription_action_value(&self) -> crate::api::v1::EnumValue<SubscriptionAction> {
+        crate::api::v1::EnumValue::from(self.subscription_action)
+    }
+    /// Sets `subscription_action` and returns message.
+    pub fn with_subscription_action(mut self, value: SubscriptionAction) -> Self {
+        self.subscription_action = value as i32;
+        self
+    }
+}
 /// Запрос подписки на стаканы.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct OrderBookInstrument {
     /// Deprecated FIGI-идентификатор инструмента. Используйте `instrument_id`.
     #[deprecated]
@@ -4900,10 +6041,24 @@ pub struct OrderBookInstrument {
     pub instrument_id: ::prost::alloc::string::String,
     /// Тип стакана. По умолчанию ORDERBOOK_TYPE_ALL - стакан биржевой и дилера.
     #[prost(enumeration = "OrderBookType", tag = "4")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<OrderBookType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<OrderBookType, _>"))]
     pub order_book_type: i32,
 }
+impl OrderBookInstrument {
+    /// Returns typed `order_book_type`, keeping values unknown to the contracts.
+    pub fn order_book_type_value(&self) -> crate::api::v1::EnumValue<OrderBookType> {
+        crate::api::v1::EnumValue::from(self.order_book_type)
+    }
+    /// Sets `order_book_type` and returns message.
+    pub fn with_order_book_type(mut self, value: OrderBookType) -> Self {
+        self.order_book_type = value as i32;
+        self
+    }
+}
 /// Результат изменения статуса подписки на стаканы.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct SubscribeOrderBookResponse {
     /// Уникальный идентификатор запроса. [Подробнее](<https://russianinvestments.github.io/investAPI/grpc#tracking-id>).
     #[prost(string, tag = "1")]
@@ -4914,6 +6069,8 @@ pub struct SubscribeOrderBookResponse {
 }
 /// Статус подписки.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct OrderBookSubscription {
     /// FIGI-идентификатор инструмента.
     #[prost(string, tag = "1")]
@@ -4923,6 +6080,7 @@ pub struct OrderBookSubscription {
     pub depth: i32,
     /// Статус подписки.
     #[prost(enumeration = "SubscriptionStatus", tag = "3")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<SubscriptionStatus, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<SubscriptionStatus, _>"))]
     pub subscription_status: i32,
     /// UID инструмента.
     #[prost(string, tag = "4")]
@@ -4935,23 +6093,70 @@ pub struct OrderBookSubscription {
     pub subscription_id: ::prost::alloc::string::String,
     /// Тип стакана.
     #[prost(enumeration = "OrderBookType", tag = "7")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<OrderBookType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<OrderBookType, _>"))]
     pub order_book_type: i32,
 }
+impl OrderBookSubscription {
+    /// Returns typed `subscription_status`, keeping values unknown to the contracts.
+    pub fn subscription_status_value(&self) -> crate::api::v1::EnumValue<SubscriptionStatus> {
+        crate::api::v1::EnumValue::from(self.subscription_status)
+    }
+    /// Sets `subscription_status` and returns message.
+    pub fn with_subscription_status(mut self, value: SubscriptionStatus) -> Self {
+        self.subscription_status = value as i32;
+        self
+    }
+    /// Returns typed `order_book_type`, keeping values unknown to the contracts.
+    pub fn order_book_type_value(&self) -> crate::api::v1::EnumValue<OrderBookType> {
+        crate::api::v1::EnumValue::from(self.order_book_type)
+    }
+    /// Sets `order_book_type` and returns message.
+    pub fn with_order_book_type(mut self, value: OrderBookType) -> Self {
+        self.order_book_type = value as i32;
+        self
+    }
+}
 /// Изменение статуса подписки на поток обезличенных сделок.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct SubscribeTradesRequest {
     /// Изменение статуса подписки.
     #[prost(enumeration = "SubscriptionAction", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<SubscriptionAction, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<SubscriptionAction, _>"))]
     pub subscription_action: i32,
     /// Массив инструментов для подписки на поток обезличенных сделок.
     #[prost(message, repeated, tag = "2")]
     pub instruments: ::prost::alloc::vec::Vec<TradeInstrument>,
     /// Тип источника сделок. По умолчанию TRADE_SOURCE_ALL - все сделки.
     #[prost(enumeration = "TradeSourceType", tag = "3")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<TradeSourceType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<TradeSourceType, _>"))]
     pub trade_source: i32,
 }
+impl SubscribeTradesRequest {
+    /// Returns typed `subscription_action`, keeping values unknown to the contracts.
+    pub fn subscription_action_value(&self) -> crate::api::v1::EnumValue<SubscriptionAction> {
+        crate::api::v1::EnumValue::from(self.subscription_action)
+    }
+    /// Sets `subscription_action` and returns message.
+    pub fn with_subscription_action(mut self, value: SubscriptionAction) -> Self {
+        self.subscription_action = value as i32;
+        self
+    }
+    /// Returns typed `trade_source`, keeping values unknown to the contracts.
+    pub fn trade_source_value(&self) -> crate::api::v1::EnumValue<TradeSourceType> {
+        crate::api::v1::EnumValue::from(self.trade_source)
+    }
+    /// Sets `trade_source` and returns message.
+    pub fn with_trade_source(mut self, value: TradeSourceType) -> Self {
+        self.trade_source = value as i32;
+        self
+    }
+}
 /// Запрос подписки на поток обезличенных сделок.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct TradeInstrument {
     /// Deprecated FIGI-идентификатор инструмента. Используйте instrument_id`.
     #[deprecated]
@@ -4963,6 +6168,8 @@ pub struct TradeInstrument {
 }
 /// Результат изменения статуса подписки на поток обезличенных сделок.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct SubscribeTradesResponse {
     /// Уникальный идентификатор запроса. [Подробнее](<https://russianinvestments.github.io/investAPI/grpc#tracking-id>).
     #[prost(string, tag = "1")]
@@ -4972,16 +6179,31 @@ pub struct SubscribeTradesResponse {
     pub trade_subscriptions: ::prost::alloc::vec::Vec<TradeSubscription>,
     /// Тип источника сделок.
     #[prost(enumeration = "TradeSourceType", tag = "3")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<TradeSourceType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<TradeSourceType, _>"))]
     pub trade_source: i32,
 }
+impl SubscribeTradesResponse {
+    /// Returns typed `trade_source`, keeping values unknown to the contracts.
+    pub fn trade_source_value(&self) -> crate::api::v1::EnumValue<TradeSourceType> {
+        crate::api::v1::EnumValue::from(self.trade_source)
+    }
+    /// Sets `trade_source` and returns message.
+    pub fn with_trade_source(mut self, value: TradeSourceType) -> Self {
+        self.trade_source = value as i32;
+        self
+    }
+}
 /// Статус подписки.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct TradeSubscription {
     /// FIGI-идентификатор инструмента.
     #[prost(string, tag = "1")]
     pub figi: ::prost::alloc::string::String,
     /// Статус подписки.
     #[prost(enumeration = "SubscriptionStatus", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<SubscriptionStatus, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<SubscriptionStatus, _>"))]
     pub subscription_status: i32,
     /// UID инструмента.
     #[prost(string, tag = "3")]
@@ -4993,18 +6215,45 @@ pub struct TradeSubscription {
     #[prost(string, tag = "5")]
     pub subscription_id: ::prost::alloc::string::String,
 }
+impl TradeSubscription {
+    /// Returns typed `subscription_status`, keeping values unknown to the contracts.
+    pub fn subscription_status_value(&self) -> crate::api::v1::EnumValue<SubscriptionStatus> {
+        crate::api::v1::EnumValue::from(self.subscription_status)
+    }
+    /// Sets `subscription_status` and returns message.
+    pub fn with_subscription_status(mut self, value: SubscriptionStatus) -> Self {
+        self.subscription_status = value as i32;
+        self
+    }
+}
 /// Изменение статуса подписки на торговый статус инструмента.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct SubscribeInfoRequest {
     /// Изменение статуса подписки.
     #[prost(enumeration = "SubscriptionAction", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<SubscriptionAction, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<SubscriptionAction, _>"))]
     pub subscription_action: i32,
     /// Массив инструментов для подписки на торговый статус.
     #[prost(message, repeated, tag = "2")]
     pub instruments: ::prost::alloc::vec::Vec<InfoInstrument>,
 }
+impl SubscribeInfoRequest {
+    /// Returns typed `subscription_action`, keeping values unknown to the contracts.
+    pub fn subscription_action_value(&self) -> crate::api::v1::EnumValue<SubscriptionAction> {
+        crate::api::v1::EnumValue::from(self.subscription_action)
+    }
+    /// Sets `subscription_action` and returns message.
+    pub fn with_subscription_action(mut self, value: SubscriptionAction) -> Self {
+        self.subscription_action = value as i32;
+        self
+    }
+}
 /// Запрос подписки на торговый статус.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct InfoInstrument {
     /// Deprecated FIGI-идентификатор инструмента. Используйте instrument_id`.
     #[deprecated]
@@ -5016,6 +6265,8 @@ pub struct InfoInstrument {
 }
 /// Результат изменения статуса подписки на торговый статус.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct SubscribeInfoResponse {
     /// Уникальный идентификатор запроса. [Подробнее](<https://russianinvestments.github.io/investAPI/grpc#tracking-id>).
     #[prost(string, tag = "1")]
@@ -5026,12 +6277,15 @@ pub struct SubscribeInfoResponse {
 }
 /// Статус подписки.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct InfoSubscription {
     /// FIGI-идентификатор инструмента.
     #[prost(string, tag = "1")]
     pub figi: ::prost::alloc::string::String,
     /// Статус подписки.
     #[prost(enumeration = "SubscriptionStatus", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<SubscriptionStatus, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<SubscriptionStatus, _>"))]
     pub subscription_status: i32,
     /// UID инструмента.
     #[prost(string, tag = "3")]
@@ -5043,18 +6297,45 @@ pub struct InfoSubscription {
     #[prost(string, tag = "5")]
     pub subscription_id: ::prost::alloc::string::String,
 }
+impl InfoSubscription {
+    /// Returns typed `subscription_status`, keeping values unknown to the contracts.
+    pub fn subscription_status_value(&self) -> crate::api::v1::EnumValue<SubscriptionStatus> {
+        crate::api::v1::EnumValue::from(self.subscription_status)
+    }
+    /// Sets `subscription_status` and returns message.
+    pub fn with_subscription_status(mut self, value: SubscriptionStatus) -> Self {
+        self.subscription_status = value as i32;
+        self
+    }
+}
 /// Изменение статуса подписки на цену последней сделки по инструменту.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct SubscribeLastPriceRequest {
     /// Изменение статуса подписки.
     #[prost(enumeration = "SubscriptionAction", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<SubscriptionAction, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<SubscriptionAction, _>"))]
     pub subscription_action: i32,
     /// Массив инструментов для подписки на цену последней сделки.
     #[prost(message, repeated, tag = "2")]
     pub instruments: ::prost::alloc::vec::Vec<LastPriceInstrument>,
 }
+impl SubscribeLastPriceRequest {
+    /// Returns typed `subscription_action`, keeping values unknown to the contracts.
+    pub fn subscription_action_value(&self) -> crate::api::v1::EnumValue<SubscriptionAction> {
+        crate::api::v1::EnumValue::from(self.subscription_action)
+    }
+    /// Sets `subscription_action` and returns message.
+    pub fn with_subscription_action(mut self, value: SubscriptionAction) -> Self {
+        self.subscription_action = value as i32;
+        self
+    }
+}
 /// Запрос подписки на последнюю цену.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct LastPriceInstrument {
     /// Deprecated FIGI-идентификатор инструмента. Используйте instrument_id`.
     #[deprecated]
@@ -5066,6 +6347,8 @@ pub struct LastPriceInstrument {
 }
 /// Результат изменения статуса подписки на цену последней сделки.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct SubscribeLastPriceResponse {
     /// Уникальный идентификатор запроса. [Подробнее](<https://russianinvestments.github.io/investAPI/grpc#tracking-id>).
     #[prost(string, tag = "1")]
@@ -5076,12 +6359,15 @@ pub struct SubscribeLastPriceResponse {
 }
 /// Статус подписки на цену последней сделки.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct LastPriceSubscription {
     /// FIGI-идентификатор инструмента.
     #[prost(string, tag = "1")]
     pub figi: ::prost::alloc::string::String,
     /// Статус подписки.
     #[prost(enumeration = "SubscriptionStatus", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<SubscriptionStatus, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<SubscriptionStatus, _>"))]
     pub subscription_status: i32,
     /// UID инструмента.
     #[prost(string, tag = "3")]
@@ -5093,14 +6379,28 @@ pub struct LastPriceSubscription {
     #[prost(string, tag = "5")]
     pub subscription_id: ::prost::alloc::string::String,
 }
+impl LastPriceSubscription {
+    /// Returns typed `subscription_status`, keeping values unknown to the contracts.
+    pub fn subscription_status_value(&self) -> crate::api::v1::EnumValue<SubscriptionStatus> {
+        crate::api::v1::EnumValue::from(self.subscription_status)
+    }
+    /// Sets `subscription_status` and returns message.
+    pub fn with_subscription_status(mut self, value: SubscriptionStatus) -> Self {
+        self.subscription_status = value as i32;
+        self
+    }
+}
 /// Пакет свечей в рамках стрима.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct Candle {
     /// FIGI-идентификатор инструмента.
     #[prost(string, tag = "1")]
     pub figi: ::prost::alloc::string::String,
     /// Интервал свечи.
     #[prost(enumeration = "SubscriptionInterval", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<SubscriptionInterval, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<SubscriptionInterval, _>"))]
     pub interval: i32,
     /// Цена открытия за 1 инструмент. Чтобы получить стоимость лота, нужно умножить на лотность инструмента. [Подробнее про перевод цен в валюту](<https://russianinvestments.github.io/investAPI/faq_marketdata/#_15>).
     #[prost(message, optional, tag = "3")]
@@ -5116,22 +6416,48 @@ pub struct Candle {
     pub close: ::core::option::Option<Quotation>,
     /// Объём сделок в лотах.
     #[prost(int64, tag = "7")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
     pub volume: i64,
     /// Время начала интервала свечи по UTC.
     #[prost(message, optional, tag = "8")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub time: ::core::option::Option<::prost_types::Timestamp>,
     /// Время последней сделки, вошедшей в свечу по UTC.
     #[prost(message, optional, tag = "9")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub last_trade_ts: ::core::option::Option<::prost_types::Timestamp>,
     /// UID инструмента.
     #[prost(string, tag = "10")]
     pub instrument_uid: ::prost::alloc::string::String,
     /// Источник свечей
     #[prost(enumeration = "CandleSource", tag = "19")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<CandleSource, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<CandleSource, _>"))]
     pub candle_source_type: i32,
 }
+impl Candle {
+    /// Returns typed `interval`, keeping values unknown to the contracts.
+    pub fn interval_value(&self) -> crate::api::v1::EnumValue<SubscriptionInterval> {
+        crate::api::v1::EnumValue::from(self.interval)
+    }
+    /// Sets `interval` and returns message.
+    pub fn with_interval(mut self, value: SubscriptionInterval) -> Self {
+        self.interval = value as i32;
+        self
+    }
+    /// Returns typed `candle_source_type`, keeping values unknown to the contracts.
+    pub fn candle_source_type_value(&self) -> crate::api::v1::EnumValue<CandleSource> {
+        crate::api::v1::EnumValue::from(self.candle_source_type)
+    }
+    /// Sets `candle_source_type` and returns message.
+    pub fn with_candle_source_type(mut self, value: CandleSource) -> Self {
+        self.candle_source_type = value as i32;
+        self
+    }
+}
 /// Пакет стаканов в рамках стрима.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct OrderBook {
     /// FIGI-идентификатор инструмента.
     #[prost(string, tag = "1")]
@@ -5150,6 +6476,7 @@ pub struct OrderBook {
     pub asks: ::prost::alloc::vec::Vec<Order>,
     /// Время формирования стакана в часовом поясе UTC по времени биржи.
     #[prost(message, optional, tag = "6")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub time: ::core::option::Option<::prost_types::Timestamp>,
     /// Верхний лимит цены за 1 инструмент. Чтобы получить стоимость лота, нужно умножить на лотность инструмента. [Подробнее про перевод цен в валюту](<https://russianinvestments.github.io/investAPI/faq_marketdata/#_15>).
     #[prost(message, optional, tag = "7")]
@@ -5162,54 +6489,99 @@ pub struct OrderBook {
     pub instrument_uid: ::prost::alloc::string::String,
     /// Тип стакана.
     #[prost(enumeration = "OrderBookType", tag = "10")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<OrderBookType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<OrderBookType, _>"))]
     pub order_book_type: i32,
 }
+impl OrderBook {
+    /// Returns typed `order_book_type`, keeping values unknown to the contracts.
+    pub fn order_book_type_value(&self) -> crate::api::v1::EnumValue<OrderBookType> {
+        crate::api::v1::EnumValue::from(self.order_book_type)
+    }
+    /// Sets `order_book_type` and returns message.
+    pub fn with_order_book_type(mut self, value: OrderBookType) -> Self {
+        self.order_book_type = value as i32;
+        self
+    }
+}
 /// Массив предложений/спроса.
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct Order {
     /// Цена за 1 инструмент. Чтобы получить стоимость лота, нужно умножить на лотность инструмента. [Подробнее про перевод цен в валюту](<https://russianinvestments.github.io/investAPI/faq_marketdata/#_15>).
     #[prost(message, optional, tag = "1")]
     pub price: ::core::option::Option<Quotation>,
     /// Количество в лотах.
     #[prost(int64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
     pub quantity: i64,
 }
 /// Информация о сделке.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct Trade {
     /// FIGI-идентификатор инструмента.
     #[prost(string, tag = "1")]
     pub figi: ::prost::alloc::string::String,
     /// Направление сделки.
     #[prost(enumeration = "TradeDirection", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<TradeDirection, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<TradeDirection, _>"))]
     pub direction: i32,
     /// Цена за 1 инструмент. Чтобы получить стоимость лота, нужно умножить на лотность инструмента. [Подробнее про перевод цен в валюту](<https://russianinvestments.github.io/investAPI/faq_marketdata/#_15>).
     #[prost(message, optional, tag = "3")]
     pub price: ::core::option::Option<Quotation>,
     /// Количество лотов.
     #[prost(int64, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
     pub quantity: i64,
     /// Время сделки в часовом поясе UTC по времени биржи.
     #[prost(message, optional, tag = "5")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub time: ::core::option::Option<::prost_types::Timestamp>,
     /// UID инструмента.
     #[prost(string, tag = "6")]
     pub instrument_uid: ::prost::alloc::string::String,
     /// Тип источника сделки.
     #[prost(enumeration = "TradeSourceType", tag = "7")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<TradeSourceType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<TradeSourceType, _>"))]
     pub trade_source: i32,
 }
+impl Trade {
+    /// Returns typed `direction`, keeping values unknown to the contracts.
+    pub fn direction_value(&self) -> crate::api::v1::EnumValue<TradeDirection> {
+        crate::api::v1::EnumValue::from(self.direction)
+    }
+    /// Sets `direction` and returns message.
+    pub fn with_direction(mut self, value: TradeDirection) -> Self {
+        self.direction = value as i32;
+        self
+    }
+    /// Returns typed `trade_source`, keeping values unknown to the contracts.
+    pub fn trade_source_value(&self) -> crate::api::v1::EnumValue<TradeSourceType> {
+        crate::api::v1::EnumValue::from(self.trade_source)
+    }
+    /// Sets `trade_source` and returns message.
+    pub fn with_trade_source(mut self, value: TradeSourceType) -> Self {
+        self.trade_source = value as i32;
+        self
+    }
+}
 /// Пакет изменения торгового статуса.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct TradingStatus {
     /// FIGI-идентификатор инструмента.
     #[prost(string, tag = "1")]
     pub figi: ::prost::alloc::string::String,
     /// Статус торговли инструментом.
     #[prost(enumeration = "SecurityTradingStatus", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<SecurityTradingStatus, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<SecurityTradingStatus, _>"))]
     pub trading_status: i32,
     /// Время изменения торгового статуса по UTC.
     #[prost(message, optional, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub time: ::core::option::Option<::prost_types::Timestamp>,
     /// Признак доступности выставления лимитной заявки по инструменту.
     #[prost(bool, tag = "4")]
@@ -5221,8 +6593,21 @@ pub struct TradingStatus {
     #[prost(string, tag = "6")]
     pub instrument_uid: ::prost::alloc::string::String,
 }
+impl TradingStatus {
+    /// Returns typed `trading_status`, keeping values unknown to the contracts.
+    pub fn trading_status_value(&self) -> crate::api::v1::EnumValue<SecurityTradingStatus> {
+        crate::api::v1::EnumValue::from(self.trading_status)
+    }
+    /// Sets `trading_status` and returns message.
+    pub fn with_trading_status(mut self, value: SecurityTradingStatus) -> Self {
+        self.trading_status = value as i32;
+        self
+    }
+}
 /// Запрос исторических свечей.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetCandlesRequest {
     /// Deprecated FIGI-идентификатор инструмента. Используйте `instrument_id`.
     #[deprecated]
@@ -5230,23 +6615,47 @@ pub struct GetCandlesRequest {
     pub figi: ::core::option::Option<::prost::alloc::string::String>,
     /// Начало запрашиваемого периода по UTC.
     #[prost(message, optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub from: ::core::option::Option<::prost_types::Timestamp>,
     /// Окончание запрашиваемого периода по UTC.
     #[prost(message, optional, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub to: ::core::option::Option<::prost_types::Timestamp>,
     /// Интервал запрошенных свечей.
     #[prost(enumeration = "CandleInterval", tag = "4")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<CandleInterval, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<CandleInterval, _>"))]
     pub interval: i32,
     /// Идентификатор инструмента. Принимает значение `figi` или `instrument_uid`.
     #[prost(string, optional, tag = "5")]
     pub instrument_id: ::core::option::Option<::prost::alloc::string::String>,
     /// Тип источника свечи.
     #[prost(enumeration = "get_candles_request::CandleSource", optional, tag = "7")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration_option::serialize::<get_candles_request::CandleSource, _>", deserialize_with = "crate::api::v1::serialization::enumeration_option::deserialize::<get_candles_request::CandleSource, _>"))]
     pub candle_source_type: ::core::option::Option<i32>,
     /// Максимальное количество свечей в ответе.
     #[prost(int32, optional, tag = "10")]
     pub limit: ::core::option::Option<i32>,
 }
+impl GetCandlesRequest {
+    /// Returns typed `interval`, keeping values unknown to the contracts.
+    pub fn interval_value(&self) -> crate::api::v1::EnumValue<CandleInterval> {
+        crate::api::v1::EnumValue::from(self.interval)
+    }
+    /// Sets `interval` and returns message.
+    pub fn with_interval(mut self, value: CandleInterval) -> Self {
+        self.interval = value as i32;
+        self
+    }
+    /// Returns typed `candle_source_type`, keeping values unknown to the contracts.
+    pub fn candle_source_type_value(&self) -> ::core::option::Option<crate::api::v1::EnumValue<get_candles_request::CandleSource>> {
+        self.candle_source_type.map(crate::api::v1::EnumValue::from)
+    }
+    /// Sets `candle_source_type` and returns message.
+    pub fn with_candle_source_type(mut self, value: get_candles_request::CandleSource) -> Self {
+        self.candle_source_type = ::core::option::Option::Some(value as i32);
+        self
+    }
+}
 /// Nested message and enum types in `GetCandlesRequest`.
 pub mod get_candles_request {
     #[derive(
@@ -5291,9 +6700,20 @@ pub mod get_candles_request {
             }
         }
     }
+    #[cfg(feature = "serde")]
+    impl crate::api::v1::serialization::ProtoEnum for CandleSource {
+        fn proto_name(&self) -> &'static str {
+            self.as_str_name()
+        }
+        fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+            Self::from_str_name(value)
+        }
+    }
 }
 /// Список свечей.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetCandlesResponse {
     /// Массив свечей.
     #[prost(message, repeated, tag = "1")]
@@ -5301,6 +6721,8 @@ pub struct GetCandlesResponse {
 }
 /// Информация о свече.
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct HistoricCandle {
     /// Цена открытия за 1 инструмент. Чтобы получить стоимость лота, нужно умножить на лотность инструмента. [Подробнее про перевод цен в валюту](<https://russianinvestments.github.io/investAPI/faq_marketdata/#_15>).
     #[prost(message, optional, tag = "1")]
@@ -5316,19 +6738,35 @@ pub struct HistoricCandle {
     pub close: ::core::option::Option<Quotation>,
     /// Объём торгов в лотах.
     #[prost(int64, tag = "5")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
     pub volume: i64,
     /// Время свечи в часовом поясе UTC.
     #[prost(message, optional, tag = "6")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub time: ::core::option::Option<::prost_types::Timestamp>,
     /// Признак завершённости свечи. **false** — свеча за текущие интервал ещё сформирована не полностью.
     #[prost(bool, tag = "7")]
     pub is_complete: bool,
     /// Тип источника свечи
     #[prost(enumeration = "CandleSource", tag = "9")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<CandleSource, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<CandleSource, _>"))]
     pub candle_source_type: i32,
 }
+impl HistoricCandle {
+    /// Returns typed `candle_source_type`, keeping values unknown to the contracts.
+    pub fn candle_source_type_value(&self) -> crate::api::v1::EnumValue<CandleSource> {
+        crate::api::v1::EnumValue::from(self.candle_source_type)
+    }
+    /// Sets `candle_source_type` and returns message.
+    pub fn with_candle_source_type(mut self, value: CandleSource) -> Self {
+        self.candle_source_type = value as i32;
+        self
+    }
+}
 /// Запрос получения цен последних сделок.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetLastPricesRequest {
     /// Deprecated FIGI-идентификатор инструмента. Используйте `instrument_id`.
     #[deprecated]
@@ -5339,13 +6777,37 @@ pub struct GetLastPricesRequest {
     pub instrument_id: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
     /// Тип запрашиваемой последней цены.
     #[prost(enumeration = "LastPriceType", tag = "3")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<LastPriceType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<LastPriceType, _>"))]
     pub last_price_type: i32,
     /// Статус запрашиваемых инструментов. [Возможные значения](#instrumentstatus).
     #[prost(enumeration = "InstrumentStatus", optional, tag = "9")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration_option::serialize::<InstrumentStatus, _>", deserialize_with = "crate::api::v1::serialization::enumeration_option::deserialize::<InstrumentStatus, _>"))]
     pub instrument_status: ::core::option::Option<i32>,
 }
+impl GetLastPricesRequest {
+    /// Returns typed `last_price_type`, keeping values unknown to the contracts.
+    pub fn last_price_type_value(&self) -> crate::api::v1::EnumValue<LastPriceType> {
+        crate::api::v1::EnumValue::from(self.last_price_type)
+    }
+    /// Sets `last_price_type` and returns message.
+    pub fn with_last_price_type(mut self, value: LastPriceType) -> Self {
+        self.last_price_type = value as i32;
+        self
+    }
+    /// Returns typed `instrument_status`, keeping values unknown to the contracts.
+    pub fn instrument_status_value(&self) -> ::core::option::Option<crate::api::v1::EnumValue<InstrumentStatus>> {
+        self.instrument_status.map(crate::api::v1::EnumValue::from)
+    }
+    /// Sets `instrument_status` and returns message.
+    pub fn with_instrument_status(mut self, value: InstrumentStatus) -> Self {
+        self.instrument_status = ::core::option::Option::Some(value as i32);
+        self
+    }
+}
 /// Список цен последних сделок.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetLastPricesResponse {
     /// Массив цен последних сделок.
     #[prost(message, repeated, tag = "1")]
@@ -5353,6 +6815,8 @@ pub struct GetLastPricesResponse {
 }
 /// Информация о цене последней сделки.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct LastPrice {
     /// FIGI инструмента.
     #[prost(string, tag = "1")]
@@ -5362,16 +6826,31 @@ pub struct LastPrice {
     pub price: ::core::option::Option<Quotation>,
     /// Время получения последней цены в часовом поясе UTC по времени биржи.
     #[prost(message, optional, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub time: ::core::option::Option<::prost_types::Timestamp>,
     /// UID инструмента.
     #[prost(string, tag = "11")]
     pub instrument_uid: ::prost::alloc::string::String,
     /// Тип последней цены.
     #[prost(enumeration = "LastPriceType", tag = "12")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<LastPriceType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<LastPriceType, _>"))]
     pub last_price_type: i32,
 }
+impl LastPrice {
+    /// Returns typed `last_price_type`, keeping values unknown to the contracts.
+    pub fn last_price_type_value(&self) -> crate::api::v1::EnumValue<LastPriceType> {
+        crate::api::v1::EnumValue::from(self.last_price_type)
+    }
+    /// Sets `last_price_type` and returns message.
+    pub fn with_last_price_type(mut self, value: LastPriceType) -> Self {
+        self.last_price_type = value as i32;
+        self
+    }
+}
 /// Запрос стакана.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetOrderBookRequest {
     /// Deprecated FIGI-идентификатор инструмента. Используйте `instrument_id`.
     #[deprecated]
@@ -5386,6 +6865,8 @@ pub struct GetOrderBookRequest {
 }
 /// Информация о стакане.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetOrderBookResponse {
     /// FIGI-идентификатор инструмента.
     #[prost(string, tag = "1")]
@@ -5413,12 +6894,15 @@ pub struct GetOrderBookResponse {
     pub limit_down: ::core::option::Option<Quotation>,
     /// Время получения цены последней сделки.
     #[prost(message, optional, tag = "21")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub last_price_ts: ::core::option::Option<::prost_types::Timestamp>,
     /// Время получения цены закрытия.
     #[prost(message, optional, tag = "22")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub close_price_ts: ::core::option::Option<::prost_types::Timestamp>,
     /// Время формирования стакана на бирже.
     #[prost(message, optional, tag = "23")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub orderbook_ts: ::core::option::Option<::prost_types::Timestamp>,
     /// UID инструмента.
     #[prost(string, tag = "9")]
@@ -5426,6 +6910,8 @@ pub struct GetOrderBookResponse {
 }
 /// Запрос получения торгового статуса.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetTradingStatusRequest {
     /// Deprecated FIGI-идентификатор инструмента. Используйте `instrument_id`.
     #[deprecated]
@@ -5437,6 +6923,8 @@ pub struct GetTradingStatusRequest {
 }
 /// Запрос получения торгового статуса.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetTradingStatusesRequest {
     /// Идентификатор инструмента. Принимает значение `figi` или `instrument_uid`.
     #[prost(string, repeated, tag = "1")]
@@ -5444,6 +6932,8 @@ pub struct GetTradingStatusesRequest {
 }
 /// Информация о торговом статусе.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetTradingStatusesResponse {
     /// Массив информации о торговых статусах.
     #[prost(message, repeated, tag = "1")]
@@ -5451,12 +6941,15 @@ pub struct GetTradingStatusesResponse {
 }
 /// Информация о торговом статусе.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetTradingStatusResponse {
     /// FIGI-идентификатор инструмента.
     #[prost(string, tag = "1")]
     pub figi: ::prost::alloc::string::String,
     /// Статус торговли инструментом.
     #[prost(enumeration = "SecurityTradingStatus", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<SecurityTradingStatus, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<SecurityTradingStatus, _>"))]
     pub trading_status: i32,
     /// Признак доступности выставления лимитной заявки по инструменту.
     #[prost(bool, tag = "3")]
@@ -5477,8 +6970,21 @@ pub struct GetTradingStatusResponse {
     #[prost(bool, tag = "9")]
     pub only_best_price: bool,
 }
+impl GetTradingStatusResponse {
+    /// Returns typed `trading_status`, keeping values unknown to the contracts.
+    pub fn trading_status_value(&self) -> crate::api::v1::EnumValue<SecurityTradingStatus> {
+        crate::api::v1::EnumValue::from(self.trading_status)
+    }
+    /// Sets `trading_status` and returns message.
+    pub fn with_trading_status(mut self, value: SecurityTradingStatus) -> Self {
+        self.trading_status = value as i32;
+        self
+    }
+}
 /// Запрос обезличенных сделок за последний час.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetLastTradesRequest {
     /// Deprecated FIGI-идентификатор инструмента. Используйте `instrument_id`.
     #[deprecated]
@@ -5486,19 +6992,35 @@ pub struct GetLastTradesRequest {
     pub figi: ::core::option::Option<::prost::alloc::string::String>,
     /// Начало запрашиваемого периода по UTC.
     #[prost(message, optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub from: ::core::option::Option<::prost_types::Timestamp>,
     /// Окончание запрашиваемого периода по UTC.
     #[prost(message, optional, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub to: ::core::option::Option<::prost_types::Timestamp>,
     /// Идентификатор инструмента. Принимает значение `figi` или `instrument_uid`.
     #[prost(string, optional, tag = "4")]
     pub instrument_id: ::core::option::Option<::prost::alloc::string::String>,
     /// Тип источника сделок. По умолчанию TRADE_SOURCE_ALL - все сделки.
     #[prost(enumeration = "TradeSourceType", tag = "5")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<TradeSourceType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<TradeSourceType, _>"))]
     pub trade_source: i32,
 }
+impl GetLastTradesRequest {
+    /// Returns typed `trade_source`, keeping values unknown to the contracts.
+    pub fn trade_source_value(&self) -> crate::api::v1::EnumValue<TradeSourceType> {
+        crate::api::v1::EnumValue::from(self.trade_source)
+    }
+    /// Sets `trade_source` and returns message.
+    pub fn with_trade_source(mut self, value: TradeSourceType) -> Self {
+        self.trade_source = value as i32;
+        self
+    }
+}
 /// Обезличенных сделок за последний час.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetLastTradesResponse {
     /// Массив сделок.
     #[prost(message, repeated, tag = "1")]
@@ -5506,19 +7028,37 @@ pub struct GetLastTradesResponse {
 }
 /// Запрос активных подписок. Возвращает по одному сообщению на каждый тип активных подписок — `SubscribeLastPriceResponse`, `SubscribeInfoResponse`, `SubscribeTradesResponse`, `SubscribeOrderBookResponse`, `SubscribeCandlesResponse`.
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetMySubscriptions {}
 /// Запрос цен закрытия торговой сессии по инструментам.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetClosePricesRequest {
     /// Массив по инструментам.
     #[prost(message, repeated, tag = "1")]
     pub instruments: ::prost::alloc::vec::Vec<InstrumentClosePriceRequest>,
     /// Статус запрашиваемых инструментов. [Возможные значения](#instrumentstatus).
     #[prost(enumeration = "InstrumentStatus", optional, tag = "9")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration_option::serialize::<InstrumentStatus, _>", deserialize_with = "crate::api::v1::serialization::enumeration_option::deserialize::<InstrumentStatus, _>"))]
     pub instrument_status: ::core::option::Option<i32>,
 }
+impl GetClosePricesRequest {
+    /// Returns typed `instrument_status`, keeping values unknown to the contracts.
+    pub fn instrument_status_value(&self) -> ::core::option::Option<crate::api::v1::EnumValue<InstrumentStatus>> {
+        self.instrument_status.map(crate::api::v1::EnumValue::from)
+    }
+    /// Sets `instrument_status` and returns message.
+    pub fn with_instrument_status(mut self, value: InstrumentStatus) -> Self {
+        self.instrument_status = ::core::option::Option::Some(value as i32);
+        self
+    }
+}
 /// Запрос цен закрытия торговой сессии по инструменту.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct InstrumentClosePriceRequest {
     /// Идентификатор инструмента. Принимает значение `figi` или `instrument_uid`.
     #[prost(string, tag = "1")]
@@ -5526,6 +7066,8 @@ pub struct InstrumentClosePriceRequest {
 }
 /// Цены закрытия торговой сессии по инструментам.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetClosePricesResponse {
     /// Массив по инструментам.
     #[prost(message, repeated, tag = "1")]
@@ -5533,6 +7075,8 @@ pub struct GetClosePricesResponse {
 }
 /// Цена закрытия торговой сессии по инструменту.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct InstrumentClosePriceResponse {
     /// FIGI инструмента.
     #[prost(string, tag = "1")]
@@ -5548,27 +7092,35 @@ pub struct InstrumentClosePriceResponse {
     pub evening_session_price: ::core::option::Option<Quotation>,
     /// Дата совершения торгов.
     #[prost(message, optional, tag = "21")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub time: ::core::option::Option<::prost_types::Timestamp>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetTechAnalysisRequest {
     /// Тип технического индикатора.
     #[prost(enumeration = "get_tech_analysis_request::IndicatorType", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<get_tech_analysis_request::IndicatorType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<get_tech_analysis_request::IndicatorType, _>"))]
     pub indicator_type: i32,
     /// UID инструмента.
     #[prost(string, tag = "2")]
     pub instrument_uid: ::prost::alloc::string::String,
     /// Начало запрашиваемого периода по UTC.
     #[prost(message, optional, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub from: ::core::option::Option<::prost_types::Timestamp>,
     /// Окончание запрашиваемого периода по UTC.
     #[prost(message, optional, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub to: ::core::option::Option<::prost_types::Timestamp>,
     /// Интервал, за который рассчитывается индикатор.
     #[prost(enumeration = "get_tech_analysis_request::IndicatorInterval", tag = "5")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<get_tech_analysis_request::IndicatorInterval, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<get_tech_analysis_request::IndicatorInterval, _>"))]
     pub interval: i32,
     /// Тип цены, который используется при расчёте индикатора.
     #[prost(enumeration = "get_tech_analysis_request::TypeOfPrice", tag = "6")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<get_tech_analysis_request::TypeOfPrice, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<get_tech_analysis_request::TypeOfPrice, _>"))]
     pub type_of_price: i32,
     /// Торговый период, за который рассчитывается индикатор.
     #[prost(int32, tag = "7")]
@@ -5580,9 +7132,40 @@ pub struct GetTechAnalysisRequest {
     #[prost(message, optional, tag = "9")]
     pub smoothing: ::core::option::Option<get_tech_analysis_request::Smoothing>,
 }
+impl GetTechAnalysisRequest {
+    /// Returns typed `indicator_type`, keeping values unknown to the contracts.
+    pub fn indicator_type_value(&self) -> crate::api::v1::EnumValue<get_tech_analysis_request::IndicatorType> {
+        crate::api::v1::EnumValue::from(self.indicator_type)
+    }
+    /// Sets `indicator_type` and returns message.
+    pub fn with_indicator_type(mut self, value: get_tech_analysis_request::IndicatorType) -> Self {
+        self.indicator_type = value as i32;
+        self
+    }
+    /// Returns typed `interval`, keeping values unknown to the contracts.
+    pub fn interval_value(&self) -> crate::api::v1::EnumValue<get_tech_analysis_request::IndicatorInterval> {
+        crate::api::v1::EnumValue::from(self.interval)
+    }
+    /// Sets `interval` and returns message.
+    pub fn with_interval(mut self, value: get_tech_analysis_request::IndicatorInterval) -> Self {
+        self.interval = value as i32;
+        self
+    }
+    /// Returns typed `type_of_price`, keeping values unknown to the contracts.
+    pub fn type_of_price_value(&self) -> crate::api::v1::EnumValue<get_tech_analysis_request::TypeOfPrice> {
+        crate::api::v1::EnumValue::from(self.type_of_price)
+    }
+    /// Sets `type_of_price` and returns message.
+    pub fn with_type_of_price(mut self, value: get_tech_analysis_request::TypeOfPrice) -> Self {
+        self.type_of_price = value as i32;
+        self
+    }
+}
 /// Nested message and enum types in `GetTechAnalysisRequest`.
 pub mod get_tech_analysis_request {
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
     pub struct Smoothing {
         /// Короткий период сглаживания для первой экспоненциальной скользящей средней (EMA).
         #[prost(int32, tag = "1")]
@@ -5595,6 +7178,8 @@ pub mod get_tech_analysis_request {
         pub signal_smoothing: i32,
     }
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
     pub struct Deviation {
         /// Количество стандартных отклонений, на которые отступают верхняя и нижняя границы.
         #[prost(message, optional, tag = "1")]
@@ -5687,6 +7272,15 @@ pub mod get_tech_analysis_request {
             }
         }
     }
+    #[cfg(feature = "serde")]
+    impl crate::api::v1::serialization::ProtoEnum for IndicatorInterval {
+        fn proto_name(&self) -> &'static str {
+            self.as_str_name()
+        }
+        fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+            Self::from_str_name(value)
+        }
+    }
     #[derive(
         Clone,
         Copy,
@@ -5741,6 +7335,15 @@ pub mod get_tech_analysis_request {
             }
         }
     }
+    #[cfg(feature = "serde")]
+    impl crate::api::v1::serialization::ProtoEnum for TypeOfPrice {
+        fn proto_name(&self) -> &'static str {
+            self.as_str_name()
+        }
+        fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+            Self::from_str_name(value)
+        }
+    }
     #[derive(
         Clone,
         Copy,
@@ -5795,8 +7398,19 @@ pub mod get_tech_analysis_request {
             }
         }
     }
+    #[cfg(feature = "serde")]
+    impl crate::api::v1::serialization::ProtoEnum for IndicatorType {
+        fn proto_name(&self) -> &'static str {
+            self.as_str_name()
+        }
+        fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+            Self::from_str_name(value)
+        }
+    }
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetTechAnalysisResponse {
     /// Массив значений результатов технического анализа.
     #[prost(message, repeated, tag = "1")]
@@ -5807,9 +7421,12 @@ pub struct GetTechAnalysisResponse {
 /// Nested message and enum types in `GetTechAnalysisResponse`.
 pub mod get_tech_analysis_response {
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
     pub struct TechAnalysisItem {
         /// Временная метка по UTC, для которой были рассчитаны значения индикатора.
         #[prost(message, optional, tag = "1")]
+        #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
         pub timestamp: ::core::option::Option<::prost_types::Timestamp>,
         /// Значение простого скользящего среднего (средней линии).
         #[prost(message, optional, tag = "2")]
@@ -5861,6 +7478,15 @@ impl SubscriptionAction {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for SubscriptionAction {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Интервал свечи.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -5938,6 +7564,15 @@ impl SubscriptionInterval {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for SubscriptionInterval {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Результат подписки.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -6009,6 +7644,15 @@ impl SubscriptionStatus {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for SubscriptionStatus {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Типы источников сделок.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -6046,6 +7690,15 @@ impl TradeSourceType {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for TradeSourceType {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Направление сделки.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -6079,6 +7732,15 @@ impl TradeDirection {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for TradeDirection {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Интервал свечей. Максимальное значение интервала приведено ориентировочно, может отличаться в большую сторону в зависимости от параметров запроса.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -6156,6 +7818,15 @@ impl CandleInterval {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for CandleInterval {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum CandleSource {
@@ -6188,6 +7859,15 @@ impl CandleSource {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for CandleSource {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum OrderBookType {
@@ -6224,6 +7904,15 @@ impl OrderBookType {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for OrderBookType {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Тип последней цены
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -6257,6 +7946,15 @@ impl LastPriceType {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for LastPriceType {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Generated client implementations.
 pub mod market_data_service_client {
     #![allow(
@@ -6747,25 +8445,43 @@ pub mod market_data_stream_service_client {
 }
 /// Запрос получения списка операций по счёту.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct OperationsRequest {
     /// Идентификатор счёта клиента.
     #[prost(string, tag = "1")]
     pub account_id: ::prost::alloc::string::String,
     /// Начало периода по UTC.
     #[prost(message, optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub from: ::core::option::Option<::prost_types::Timestamp>,
     /// Окончание периода по UTC.
     #[prost(message, optional, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub to: ::core::option::Option<::prost_types::Timestamp>,
     /// Статус запрашиваемых операций.
     #[prost(enumeration = "OperationState", optional, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration_option::serialize::<OperationState, _>", deserialize_with = "crate::api::v1::serialization::enumeration_option::deserialize::<OperationState, _>"))]
     pub state: ::core::option::Option<i32>,
     /// FIGI-идентификатор инструмента для фильтрации.
     #[prost(string, optional, tag = "5")]
     pub figi: ::core::option::Option<::prost::alloc::string::String>,
 }
+impl OperationsRequest {
+    /// Returns typed `state`, keeping values unknown to the contracts.
+    pub fn state_value(&self) -> ::core::option::Option<crate::api::v1::EnumValue<OperationState>> {
+        self.state.map(crate::api::v1::EnumValue::from)
+    }
+    /// Sets `state` and returns message.
+    pub fn with_state(mut self, value: OperationState) -> Self {
+        self.state = ::core::option::Option::Some(value as i32);
+        self
+    }
+}
 /// Список операций.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct OperationsResponse {
     /// Массив операций.
     #[prost(message, repeated, tag = "1")]
@@ -6773,6 +8489,8 @@ pub struct OperationsResponse {
 }
 /// Данные по операции.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct Operation {
     /// Идентификатор операции.
     #[prost(string, tag = "1")]
@@ -6791,12 +8509,15 @@ pub struct Operation {
     pub price: ::core::option::Option<MoneyValue>,
     /// Статус операции.
     #[prost(enumeration = "OperationState", tag = "6")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<OperationState, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<OperationState, _>"))]
     pub state: i32,
     /// Количество единиц инструмента.
     #[prost(int64, tag = "7")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
     pub quantity: i64,
     /// Неисполненный остаток по сделке.
     #[prost(int64, tag = "8")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
     pub quantity_rest: i64,
     /// FIGI-идентификатор инструмента, связанного с операцией.
     #[prost(string, tag = "9")]
@@ -6806,12 +8527,14 @@ pub struct Operation {
     pub instrument_type: ::prost::alloc::string::String,
     /// Дата и время операции в формате часовом поясе UTC.
     #[prost(message, optional, tag = "11")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub date: ::core::option::Option<::prost_types::Timestamp>,
     /// Текстовое описание типа операции.
     #[prost(string, tag = "12")]
     pub r#type: ::prost::alloc::string::String,
     /// Тип операции.
     #[prost(enumeration = "OperationType", tag = "13")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<OperationType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<OperationType, _>"))]
     pub operation_type: i32,
     /// Массив сделок.
     #[prost(message, repeated, tag = "14")]
@@ -6829,17 +8552,41 @@ pub struct Operation {
     #[prost(message, repeated, tag = "19")]
     pub child_operations: ::prost::alloc::vec::Vec<ChildOperationItem>,
 }
+impl Operation {
+    /// Returns typed `state`, keeping values unknown to the contracts.
+    pub fn state_value(&self) -> crate::api::v1::EnumValue<OperationState> {
+        crate::api::v1::EnumValue::from(self.state)
+    }
+    /// Sets `state` and returns message.
+    pub fn with_state(mut self, value: OperationState) -> Self {
+        self.state = value as i32;
+        self
+    }
+    /// Returns typed `operation_type`, keeping values unknown to the contracts.
+    pub fn operation_type_value(&self) -> crate::api::v1::EnumValue<OperationType> {
+        crate::api::v1::EnumValue::from(self.operation_type)
+    }
+    /// Sets `operation_type` and returns message.
+    pub fn with_operation_type(mut self, value: OperationType) -> Self {
+        self.operation_type = value as i32;
+        self
+    }
+}
 /// Сделка по операции.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct OperationTrade {
     /// Идентификатор сделки.
     #[prost(string, tag = "1")]
     pub trade_id: ::prost::alloc::string::String,
     /// Дата и время сделки по UTC.
     #[prost(message, optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub date_time: ::core::option::Option<::prost_types::Timestamp>,
     /// Количество инструментов.
     #[prost(int64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
     pub quantity: i64,
     /// Цена за 1 инструмент. Чтобы получить стоимость лота, нужно умножить на лотность инструмента.
     #[prost(message, optional, tag = "4")]
@@ -6847,14 +8594,28 @@ pub struct OperationTrade {
 }
 /// Запрос получения текущего портфеля по счёту.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct PortfolioRequest {
     /// Идентификатор счёта пользователя.
     #[prost(string, tag = "1")]
     pub account_id: ::prost::alloc::string::String,
     /// Валюта, в которой нужно рассчитать портфель.
     #[prost(enumeration = "portfolio_request::CurrencyRequest", optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration_option::serialize::<portfolio_request::CurrencyRequest, _>", deserialize_with = "crate::api::v1::serialization::enumeration_option::deserialize::<portfolio_request::CurrencyRequest, _>"))]
     pub currency: ::core::option::Option<i32>,
 }
+impl PortfolioRequest {
+    /// Returns typed `currency`, keeping values unknown to the contracts.
+    pub fn currency_value(&self) -> ::core::option::Option<crate::api::v1::EnumValue<portfolio_request::CurrencyRequest>> {
+        self.currency.map(crate::api::v1::EnumValue::from)
+    }
+    /// Sets `currency` and returns message.
+    pub fn with_currency(mut self, value: portfolio_request::CurrencyRequest) -> Self {
+        self.currency = ::core::option::Option::Some(value as i32);
+        self
+    }
+}
 /// Nested message and enum types in `PortfolioRequest`.
 pub mod portfolio_request {
     #[derive(
@@ -6899,9 +8660,20 @@ pub mod portfolio_request {
             }
         }
     }
+    #[cfg(feature = "serde")]
+    impl crate::api::v1::serialization::ProtoEnum for CurrencyRequest {
+        fn proto_name(&self) -> &'static str {
+            self.as_str_name()
+        }
+        fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+            Self::from_str_name(value)
+        }
+    }
 }
 /// Текущий портфель по счёту.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct PortfolioResponse {
     /// Общая стоимость акций в портфеле.
     #[prost(message, optional, tag = "1")]
@@ -6948,6 +8720,8 @@ pub struct PortfolioResponse {
 }
 /// Запрос позиций портфеля по счёту.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct PositionsRequest {
     /// Идентификатор счёта пользователя.
     #[prost(string, tag = "1")]
@@ -6955,6 +8729,8 @@ pub struct PositionsRequest {
 }
 /// Список позиций по счёту.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct PositionsResponse {
     /// Массив валютных позиций портфеля.
     #[prost(message, repeated, tag = "1")]
@@ -6980,6 +8756,8 @@ pub struct PositionsResponse {
 }
 /// Запрос доступного остатка для вывода.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct WithdrawLimitsRequest {
     /// Идентификатор счёта пользователя.
     #[prost(string, tag = "1")]
@@ -6987,6 +8765,8 @@ pub struct WithdrawLimitsRequest {
 }
 /// Доступный остаток для вывода.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct WithdrawLimitsResponse {
     /// Массив валютных позиций портфеля.
     #[prost(message, repeated, tag = "1")]
@@ -7000,6 +8780,8 @@ pub struct WithdrawLimitsResponse {
 }
 /// Позиции портфеля.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct PortfolioPosition {
     /// FIGI-идентификатор инструмента.
     #[prost(string, tag = "1")]
@@ -7056,6 +8838,8 @@ pub struct PortfolioPosition {
     pub daily_yield: ::core::option::Option<MoneyValue>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct VirtualPortfolioPosition {
     /// Уникальный идентификатор позиции.
     #[prost(string, tag = "1")]
@@ -7083,6 +8867,7 @@ pub struct VirtualPortfolioPosition {
     pub expected_yield_fifo: ::core::option::Option<Quotation>,
     /// Дата, до которой нужно продать виртуальные бумаги. После этой даты виртуальная позиция «сгораетт».
     #[prost(message, optional, tag = "9")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub expire_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Текущая цена за 1 инструмент. Чтобы получить стоимость лота, нужно умножить на лотность инструмента.
     #[prost(message, optional, tag = "10")]
@@ -7096,15 +8881,19 @@ pub struct VirtualPortfolioPosition {
 }
 /// Баланс позиции ценной бумаги.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct PositionsSecurities {
     /// FIGI-идентификатор бумаги.
     #[prost(string, tag = "1")]
     pub figi: ::prost::alloc::string::String,
     /// Количество бумаг, заблокированных выставленными заявками.
     #[prost(int64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
     pub blocked: i64,
     /// Текущий незаблокированный баланс.
     #[prost(int64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
     pub balance: i64,
     /// Уникальный идентификатор позиции.
     #[prost(string, tag = "4")]
@@ -7121,15 +8910,19 @@ pub struct PositionsSecurities {
 }
 /// Баланс фьючерса.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct PositionsFutures {
     /// FIGI-идентификатор фьючерса.
     #[prost(string, tag = "1")]
     pub figi: ::prost::alloc::string::String,
     /// Количество бумаг, заблокированных выставленными заявками.
     #[prost(int64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
     pub blocked: i64,
     /// Текущий незаблокированный баланс.
     #[prost(int64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
     pub balance: i64,
     /// Уникальный идентификатор позиции.
     #[prost(string, tag = "4")]
@@ -7140,6 +8933,8 @@ pub struct PositionsFutures {
 }
 /// Баланс опциона.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct PositionsOptions {
     /// Уникальный идентификатор позиции опциона.
     #[prost(string, tag = "1")]
@@ -7149,19 +8944,26 @@ pub struct PositionsOptions {
     pub instrument_uid: ::prost::alloc::string::String,
     /// Количество бумаг, заблокированных выставленными заявками.
     #[prost(int64, tag = "11")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
     pub blocked: i64,
     /// Текущий незаблокированный баланс.
     #[prost(int64, tag = "21")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
     pub balance: i64,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct BrokerReportRequest {
     #[prost(oneof = "broker_report_request::Payload", tags = "1, 2")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub payload: ::core::option::Option<broker_report_request::Payload>,
 }
 /// Nested message and enum types in `BrokerReportRequest`.
 pub mod broker_report_request {
     #[derive(Clone, PartialEq, ::prost::Oneof)]
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
     pub enum Payload {
         #[prost(message, tag = "1")]
         GenerateBrokerReportRequest(super::GenerateBrokerReportRequest),
@@ -7170,13 +8972,18 @@ pub mod broker_report_request {
     }
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct BrokerReportResponse {
     #[prost(oneof = "broker_report_response::Payload", tags = "1, 2")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub payload: ::core::option::Option<broker_report_response::Payload>,
 }
 /// Nested message and enum types in `BrokerReportResponse`.
 pub mod broker_report_response {
     #[derive(Clone, PartialEq, ::prost::Oneof)]
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
     pub enum Payload {
         #[prost(message, tag = "1")]
         GenerateBrokerReportResponse(super::GenerateBrokerReportResponse),
@@ -7185,24 +8992,32 @@ pub mod broker_report_response {
     }
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GenerateBrokerReportRequest {
     /// Идентификатор счёта клиента.
     #[prost(string, tag = "1")]
     pub account_id: ::prost::alloc::string::String,
     /// Начало периода по UTC.
     #[prost(message, optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub from: ::core::option::Option<::prost_types::Timestamp>,
     /// Окончание периода по UTC.
     #[prost(message, optional, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub to: ::core::option::Option<::prost_types::Timestamp>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GenerateBrokerReportResponse {
     /// Идентификатор задачи формирования брокерского отчёта.
     #[prost(string, tag = "1")]
     pub task_id: ::prost::alloc::string::String,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetBrokerReportRequest {
     /// Идентификатор задачи формирования брокерского отчёта.
     #[prost(string, tag = "1")]
@@ -7212,6 +9027,8 @@ pub struct GetBrokerReportRequest {
     pub page: ::core::option::Option<i32>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetBrokerReportResponse {
     #[prost(message, repeated, tag = "1")]
     pub broker_report: ::prost::alloc::vec::Vec<BrokerReport>,
@@ -7226,6 +9043,8 @@ pub struct GetBrokerReportResponse {
     pub page: i32,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct BrokerReport {
     /// Номер сделки.
     #[prost(string, tag = "1")]
@@ -7241,6 +9060,7 @@ pub struct BrokerReport {
     pub execute_sign: ::prost::alloc::string::String,
     /// Дата и время заключения по UTC.
     #[prost(message, optional, tag = "5")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub trade_datetime: ::core::option::Option<::prost_types::Timestamp>,
     /// Торговая площадка.
     #[prost(string, tag = "6")]
@@ -7262,6 +9082,7 @@ pub struct BrokerReport {
     pub price: ::core::option::Option<MoneyValue>,
     /// Количество.
     #[prost(int64, tag = "12")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
     pub quantity: i64,
     /// Сумма без НКД.
     #[prost(message, optional, tag = "13")]
@@ -7289,9 +9110,11 @@ pub struct BrokerReport {
     pub party: ::prost::alloc::string::String,
     /// Дата расчётов по UTC.
     #[prost(message, optional, tag = "21")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub clear_value_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Дата поставки по UTC.
     #[prost(message, optional, tag = "22")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub sec_value_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Статус брокера.
     #[prost(string, tag = "23")]
@@ -7310,13 +9133,18 @@ pub struct BrokerReport {
     pub delivery_type: ::prost::alloc::string::String,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetDividendsForeignIssuerRequest {
     #[prost(oneof = "get_dividends_foreign_issuer_request::Payload", tags = "1, 2")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub payload: ::core::option::Option<get_dividends_foreign_issuer_request::Payload>,
 }
 /// Nested message and enum types in `GetDividendsForeignIssuerRequest`.
 pub mod get_dividends_foreign_issuer_request {
     #[derive(Clone, PartialEq, ::prost::Oneof)]
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
     pub enum Payload {
         /// Объект запроса формирования отчёта.
         #[prost(message, tag = "1")]
@@ -7329,13 +9157,18 @@ pub mod get_dividends_foreign_issuer_request {
     }
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetDividendsForeignIssuerResponse {
     #[prost(oneof = "get_dividends_foreign_issuer_response::Payload", tags = "1, 2")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub payload: ::core::option::Option<get_dividends_foreign_issuer_response::Payload>,
 }
 /// Nested message and enum types in `GetDividendsForeignIssuerResponse`.
 pub mod get_dividends_foreign_issuer_response {
     #[derive(Clone, PartialEq, ::prost::Oneof)]
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
     pub enum Payload {
         /// Объект результата задачи запуска формирования отчёта.
         #[prost(message, tag = "1")]
@@ -7349,19 +9182,25 @@ pub mod get_dividends_foreign_issuer_response {
 }
 /// Объект запроса формирования отчёта «Справка о доходах за пределами РФ».
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GenerateDividendsForeignIssuerReportRequest {
     /// Идентификатор счёта клиента.
     #[prost(string, tag = "1")]
     pub account_id: ::prost::alloc::string::String,
     /// Начало периода по UTC.
     #[prost(message, optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub from: ::core::option::Option<::prost_types::Timestamp>,
     /// Окончание периода по UTC. Как правило, можно сформировать отчёт по дату на несколько дней меньше текущей. Начало и окончание периода должны быть в рамках одного календарного года.
     #[prost(message, optional, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub to: ::core::option::Option<::prost_types::Timestamp>,
 }
 /// Объект запроса сформированного отчёта «Справка о доходах за пределами РФ».
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetDividendsForeignIssuerReportRequest {
     /// Идентификатор задачи формирования отчёта.
     #[prost(string, tag = "1")]
@@ -7372,12 +9211,16 @@ pub struct GetDividendsForeignIssuerReportRequest {
 }
 /// Объект результата задачи запуска формирования отчёта «Справка о доходах за пределами РФ».
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GenerateDividendsForeignIssuerReportResponse {
     /// Идентификатор задачи формирования отчёта.
     #[prost(string, tag = "1")]
     pub task_id: ::prost::alloc::string::String,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetDividendsForeignIssuerReportResponse {
     #[prost(message, repeated, tag = "1")]
     pub dividends_foreign_issuer_report: ::prost::alloc::vec::Vec<
@@ -7395,12 +9238,16 @@ pub struct GetDividendsForeignIssuerReportResponse {
 }
 /// Отчёт «Справка о доходах за пределами РФ».
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct DividendsForeignIssuerReport {
     /// Дата фиксации реестра.
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub record_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Дата выплаты.
     #[prost(message, optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub payment_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Наименование ценной бумаги.
     #[prost(string, tag = "3")]
@@ -7413,6 +9260,7 @@ pub struct DividendsForeignIssuerReport {
     pub issuer_country: ::prost::alloc::string::String,
     /// Количество ценных бумаг.
     #[prost(int64, tag = "6")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
     pub quantity: i64,
     /// Выплаты на одну бумагу
     #[prost(message, optional, tag = "7")]
@@ -7435,6 +9283,8 @@ pub struct DividendsForeignIssuerReport {
 }
 /// Запрос установки stream-соединения.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct PortfolioStreamRequest {
     /// Массив идентификаторов счётов пользователя.
     #[prost(string, repeated, tag = "1")]
@@ -7445,13 +9295,18 @@ pub struct PortfolioStreamRequest {
 }
 /// Информация по позициям и доходностям портфелей.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct PortfolioStreamResponse {
     #[prost(oneof = "portfolio_stream_response::Payload", tags = "1, 2, 3")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub payload: ::core::option::Option<portfolio_stream_response::Payload>,
 }
 /// Nested message and enum types in `PortfolioStreamResponse`.
 pub mod portfolio_stream_response {
     #[derive(Clone, PartialEq, ::prost::Oneof)]
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
     pub enum Payload {
         /// Объект результата подписки.
         #[prost(message, tag = "1")]
@@ -7466,6 +9321,8 @@ pub mod portfolio_stream_response {
 }
 /// Объект результата подписки.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct PortfolioSubscriptionResult {
     /// Массив счетов клиента.
     #[prost(message, repeated, tag = "1")]
@@ -7479,16 +9336,32 @@ pub struct PortfolioSubscriptionResult {
 }
 /// Счёт клиента.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct AccountSubscriptionStatus {
     /// Идентификатор счёта.
     #[prost(string, tag = "1")]
     pub account_id: ::prost::alloc::string::String,
     /// Результат подписки.
     #[prost(enumeration = "PortfolioSubscriptionStatus", tag = "6")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<PortfolioSubscriptionStatus, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<PortfolioSubscriptionStatus, _>"))]
     pub subscription_status: i32,
 }
+impl AccountSubscriptionStatus {
+    /// Returns typed `subscription_status`, keeping values unknown to the contracts.
+    pub fn subscription_status_value(&self) -> crate::api::v1::EnumValue<PortfolioSubscriptionStatus> {
+        crate::api::v1::EnumValue::from(self.subscription_status)
+    }
+    /// Sets `subscription_status` and returns message.
+    pub fn with_subscription_status(mut self, value: PortfolioSubscriptionStatus) -> Self {
+        self.subscription_status = value as i32;
+        self
+    }
+}
 /// Запрос списка операций по счёту с пагинацией.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetOperationsByCursorRequest {
     /// Идентификатор счёта клиента, обязательный параметр. Остальные параметры опциональны.
     #[prost(string, tag = "1")]
@@ -7498,9 +9371,11 @@ pub struct GetOperationsByCursorRequest {
     pub instrument_id: ::core::option::Option<::prost::alloc::string::String>,
     /// Начало периода по UTC.
     #[prost(message, optional, tag = "6")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub from: ::core::option::Option<::prost_types::Timestamp>,
     /// Окончание периода по UTC.
     #[prost(message, optional, tag = "7")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub to: ::core::option::Option<::prost_types::Timestamp>,
     /// Идентификатор элемента, с которого начать формировать ответ.
     #[prost(string, optional, tag = "11")]
@@ -7510,9 +9385,11 @@ pub struct GetOperationsByCursorRequest {
     pub limit: ::core::option::Option<i32>,
     /// Тип операции. Принимает значение из списка `OperationType`.
     #[prost(enumeration = "OperationType", repeated, tag = "13")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration_vec::serialize::<OperationType, _>", deserialize_with = "crate::api::v1::serialization::enumeration_vec::deserialize::<OperationType, _>"))]
     pub operation_types: ::prost::alloc::vec::Vec<i32>,
     /// Статус запрашиваемых операций. Возможные значения указаны в `OperationState`.
     #[prost(enumeration = "OperationState", optional, tag = "14")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration_option::serialize::<OperationState, _>", deserialize_with = "crate::api::v1::serialization::enumeration_option::deserialize::<OperationState, _>"))]
     pub state: ::core::option::Option<i32>,
     /// Флаг возврата комиссии. По умолчанию — `false`.
     #[prost(bool, optional, tag = "15")]
@@ -7524,8 +9401,30 @@ pub struct GetOperationsByCursorRequest {
     #[prost(bool, optional, tag = "17")]
     pub without_overnights: ::core::option::Option<bool>,
 }
+impl GetOperationsByCursorRequest {
+    /// Returns typed `operation_types`, keeping values unknown to the contracts.
+    pub fn operation_types_value(&self) -> ::prost::alloc::vec::Vec<crate::api::v1::EnumValue<OperationType>> {
+        self.operation_types.iter().copied().map(crate::api::v1::EnumValue::from).collect()
+    }
+    /// Sets `operation_types` and returns message.
+    pub fn with_operation_types(mut self, values: impl ::core::iter::IntoIterator<Item = OperationType>) -> Self {
+        self.operation_types = values.into_iter().map(|value| value as i32).collect();
+        self
+    }
+    /// Returns typed `state`, keeping values unknown to the contracts.
+    pub fn state_value(&self) -> ::core::option::Option<crate::api::v1::EnumValue<OperationState>> {
+        self.state.map(crate::api::v1::EnumValue::from)
+    }
+    /// Sets `state` and returns message.
+    pub fn with_state(mut self, value: OperationState) -> Self {
+        self.state = ::core::option::Option::Some(value as i32);
+        self
+    }
+}
 /// Список операций по счёту с пагинацией.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetOperationsByCursorResponse {
     /// Признак, есть ли следующий элемент.
     #[prost(bool, tag = "1")]
@@ -7539,6 +9438,8 @@ pub struct GetOperationsByCursorResponse {
 }
 /// Данные об операции.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct OperationItem {
     /// Курсор.
     #[prost(string, tag = "1")]
@@ -7557,15 +9458,18 @@ pub struct OperationItem {
     pub name: ::prost::alloc::string::String,
     /// Дата поручения.
     #[prost(message, optional, tag = "21")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub date: ::core::option::Option<::prost_types::Timestamp>,
     /// Тип операции.
     #[prost(enumeration = "OperationType", tag = "22")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<OperationType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<OperationType, _>"))]
     pub r#type: i32,
     /// Описание операции.
     #[prost(string, tag = "23")]
     pub description: ::prost::alloc::string::String,
     /// Статус поручения.
     #[prost(enumeration = "OperationState", tag = "24")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<OperationState, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<OperationState, _>"))]
     pub state: i32,
     /// Уникальный идентификатор инструмента.
     #[prost(string, tag = "31")]
@@ -7578,6 +9482,7 @@ pub struct OperationItem {
     pub instrument_type: ::prost::alloc::string::String,
     /// Тип инструмента.
     #[prost(enumeration = "InstrumentType", tag = "34")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<InstrumentType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<InstrumentType, _>"))]
     pub instrument_kind: i32,
     /// Уникальный идентификатор позиции.
     #[prost(string, tag = "35")]
@@ -7602,15 +9507,19 @@ pub struct OperationItem {
     pub accrued_int: ::core::option::Option<MoneyValue>,
     /// Количество единиц инструмента.
     #[prost(int64, tag = "51")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
     pub quantity: i64,
     /// Неисполненный остаток по сделке.
     #[prost(int64, tag = "52")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
     pub quantity_rest: i64,
     /// Исполненный остаток.
     #[prost(int64, tag = "53")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
     pub quantity_done: i64,
     /// Дата и время снятия заявки.
     #[prost(message, optional, tag = "56")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub cancel_date_time: ::core::option::Option<::prost_types::Timestamp>,
     /// Причина отмены операции.
     #[prost(string, tag = "57")]
@@ -7625,23 +9534,58 @@ pub struct OperationItem {
     #[prost(message, repeated, tag = "65")]
     pub child_operations: ::prost::alloc::vec::Vec<ChildOperationItem>,
 }
+impl OperationItem {
+    /// Returns typed `type`, keeping values unknown to the contracts.
+    pub fn type_value(&self) -> crate::api::v1::EnumValue<OperationType> {
+        crate::api::v1::EnumValue::from(self.r#type)
+    }
+    /// Sets `type` and returns message.
+    pub fn with_type(mut self, value: OperationType) -> Self {
+        self.r#type = value as i32;
+        self
+    }
+    /// Returns typed `state`, keeping values unknown to the contracts.
+    pub fn state_value(&self) -> crate::api::v1::EnumValue<OperationState> {
+        crate::api::v1::EnumValue::from(self.state)
+    }
+    /// Sets `state` and returns message.
+    pub fn with_state(mut self, value: OperationState) -> Self {
+        self.state = value as i32;
+        self
+    }
+    /// Returns typed `instrument_kind`, keeping values unknown to the contracts.
+    pub fn instrument_kind_value(&self) -> crate::api::v1::EnumValue<InstrumentType> {
+        crate::api::v1::EnumValue::from(self.instrument_kind)
+    }
+    /// Sets `instrument_kind` and returns message.
+    pub fn with_instrument_kind(mut self, value: InstrumentType) -> Self {
+        self.instrument_kind = value as i32;
+        self
+    }
+}
 /// Массив с информацией о сделках.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct OperationItemTrades {
     #[prost(message, repeated, tag = "6")]
     pub trades: ::prost::alloc::vec::Vec<OperationItemTrade>,
 }
 /// Сделка по операции.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct OperationItemTrade {
     /// Номер сделки.
     #[prost(string, tag = "1")]
     pub num: ::prost::alloc::string::String,
     /// Дата сделки.
     #[prost(message, optional, tag = "6")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub date: ::core::option::Option<::prost_types::Timestamp>,
     /// Количество в единицах.
     #[prost(int64, tag = "11")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
     pub quantity: i64,
     /// Цена.
     #[prost(message, optional, tag = "16")]
@@ -7655,6 +9599,8 @@ pub struct OperationItemTrade {
 }
 /// Запрос установки stream-соединения позиций.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct PositionsStreamRequest {
     /// Массив идентификаторов счётов пользователя.
     #[prost(string, repeated, tag = "1")]
@@ -7668,13 +9614,18 @@ pub struct PositionsStreamRequest {
 }
 /// Информация по изменению позиций портфеля.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct PositionsStreamResponse {
     #[prost(oneof = "positions_stream_response::Payload", tags = "1, 2, 3, 5")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub payload: ::core::option::Option<positions_stream_response::Payload>,
 }
 /// Nested message and enum types in `PositionsStreamResponse`.
 pub mod positions_stream_response {
     #[derive(Clone, PartialEq, ::prost::Oneof)]
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
     pub enum Payload {
         /// Объект результата подписки.
         #[prost(message, tag = "1")]
@@ -7692,6 +9643,8 @@ pub mod positions_stream_response {
 }
 /// Объект результата подписки.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct PositionsSubscriptionResult {
     /// Массив счетов клиента.
     #[prost(message, repeated, tag = "1")]
@@ -7705,16 +9658,32 @@ pub struct PositionsSubscriptionResult {
 }
 /// Счёт клиента.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct PositionsSubscriptionStatus {
     /// Идентификатор счёта.
     #[prost(string, tag = "1")]
     pub account_id: ::prost::alloc::string::String,
     /// Результат подписки.
     #[prost(enumeration = "PositionsAccountSubscriptionStatus", tag = "6")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<PositionsAccountSubscriptionStatus, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<PositionsAccountSubscriptionStatus, _>"))]
     pub subscription_status: i32,
 }
+impl PositionsSubscriptionStatus {
+    /// Returns typed `subscription_status`, keeping values unknown to the contracts.
+    pub fn subscription_status_value(&self) -> crate::api::v1::EnumValue<PositionsAccountSubscriptionStatus> {
+        crate::api::v1::EnumValue::from(self.subscription_status)
+    }
+    /// Sets `subscription_status` and returns message.
+    pub fn with_subscription_status(mut self, value: PositionsAccountSubscriptionStatus) -> Self {
+        self.subscription_status = value as i32;
+        self
+    }
+}
 /// Данные о позиции портфеля.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct PositionData {
     /// Идентификатор счёта.
     #[prost(string, tag = "1")]
@@ -7733,10 +9702,13 @@ pub struct PositionData {
     pub options: ::prost::alloc::vec::Vec<PositionsOptions>,
     /// Дата и время операции в формате UTC.
     #[prost(message, optional, tag = "6")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub date: ::core::option::Option<::prost_types::Timestamp>,
 }
 /// Валютная позиция портфеля.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct PositionsMoney {
     /// Доступное количество валютный позиций.
     #[prost(message, optional, tag = "1")]
@@ -7746,6 +9718,8 @@ pub struct PositionsMoney {
     pub blocked_value: ::core::option::Option<MoneyValue>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct ChildOperationItem {
     /// Уникальный идентификатор инструмента.
     #[prost(string, tag = "1")]
@@ -7791,6 +9765,15 @@ impl OperationState {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for OperationState {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Тип операции.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -8074,6 +10057,15 @@ impl OperationType {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for OperationType {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Результат подписки.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -8113,6 +10105,15 @@ impl PortfolioSubscriptionStatus {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for PortfolioSubscriptionStatus {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Результат подписки.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -8166,6 +10167,15 @@ impl PositionsAccountSubscriptionStatus {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for PositionsAccountSubscriptionStatus {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Generated client implementations.
 pub mod operations_service_client {
     #![allow(
@@ -8628,6 +10638,8 @@ pub mod operations_stream_service_client {
 }
 /// Запрос установки соединения.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct TradesStreamRequest {
     /// Идентификаторы счетов.
     #[prost(string, repeated, tag = "1")]
@@ -8638,13 +10650,18 @@ pub struct TradesStreamRequest {
 }
 /// Информация о торговых поручениях.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct TradesStreamResponse {
     #[prost(oneof = "trades_stream_response::Payload", tags = "1, 2, 3")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub payload: ::core::option::Option<trades_stream_response::Payload>,
 }
 /// Nested message and enum types in `TradesStreamResponse`.
 pub mod trades_stream_response {
     #[derive(Clone, PartialEq, ::prost::Oneof)]
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
     pub enum Payload {
         /// Информация об исполнении торгового поручения.
         #[prost(message, tag = "1")]
@@ -8659,15 +10676,19 @@ pub mod trades_stream_response {
 }
 /// Информация об исполнении торгового поручения.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct OrderTrades {
     /// Идентификатор торгового поручения.
     #[prost(string, tag = "1")]
     pub order_id: ::prost::alloc::string::String,
     /// Дата и время создания сообщения в часовом поясе UTC.
     #[prost(message, optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub created_at: ::core::option::Option<::prost_types::Timestamp>,
     /// Направление сделки.
     #[prost(enumeration = "OrderDirection", tag = "3")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<OrderDirection, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<OrderDirection, _>"))]
     pub direction: i32,
     /// Figi-идентификатор инструмента.
     #[prost(string, tag = "4")]
@@ -8682,17 +10703,32 @@ pub struct OrderTrades {
     #[prost(string, tag = "7")]
     pub instrument_uid: ::prost::alloc::string::String,
 }
+impl OrderTrades {
+    /// Returns typed `direction`, keeping values unknown to the contracts.
+    pub fn direction_value(&self) -> crate::api::v1::EnumValue<OrderDirection> {
+        crate::api::v1::EnumValue::from(self.direction)
+    }
+    /// Sets `direction` and returns message.
+    pub fn with_direction(mut self, value: OrderDirection) -> Self {
+        self.direction = value as i32;
+        self
+    }
+}
 /// Информация о сделке.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct OrderTrade {
     /// Дата и время совершения сделки в часовом поясе UTC.
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub date_time: ::core::option::Option<::prost_types::Timestamp>,
     /// Цена за 1 инструмент, по которой совершена сделка.
     #[prost(message, optional, tag = "2")]
     pub price: ::core::option::Option<Quotation>,
     /// Количество штук в сделке.
     #[prost(int64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
     pub quantity: i64,
     /// Идентификатор сделки.
     #[prost(string, tag = "4")]
@@ -8700,6 +10736,8 @@ pub struct OrderTrade {
 }
 /// Запрос выставления торгового поручения.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct PostOrderRequest {
     /// Deprecated Figi-идентификатор инструмента. Необходимо использовать instrument_id.
     #[deprecated]
@@ -8707,18 +10745,21 @@ pub struct PostOrderRequest {
     pub figi: ::core::option::Option<::prost::alloc::string::String>,
     /// Количество лотов.
     #[prost(int64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
     pub quantity: i64,
     /// Цена за 1 инструмент. Для получения стоимости лота требуется умножить на лотность инструмента. Игнорируется для рыночных поручений.
     #[prost(message, optional, tag = "3")]
     pub price: ::core::option::Option<Quotation>,
     /// Направление операции.
     #[prost(enumeration = "OrderDirection", tag = "4")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<OrderDirection, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<OrderDirection, _>"))]
     pub direction: i32,
     /// Номер счёта.
     #[prost(string, tag = "5")]
     pub account_id: ::prost::alloc::string::String,
     /// Тип заявки.
     #[prost(enumeration = "OrderType", tag = "6")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<OrderType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<OrderType, _>"))]
     pub order_type: i32,
     /// Идентификатор запроса выставления поручения для целей идемпотентности в формате UID. Максимальная длина 36 символов.
     #[prost(string, tag = "7")]
@@ -8728,25 +10769,70 @@ pub struct PostOrderRequest {
     pub instrument_id: ::prost::alloc::string::String,
     /// Алгоритм исполнения поручения, применяется только к лимитной заявке.
     #[prost(enumeration = "TimeInForceType", tag = "9")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<TimeInForceType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<TimeInForceType, _>"))]
     pub time_in_force: i32,
     /// Тип цены.
     #[prost(enumeration = "PriceType", tag = "10")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<PriceType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<PriceType, _>"))]
     pub price_type: i32,
 }
+impl PostOrderRequest {
+    /// Returns typed `direction`, keeping values unknown to the contracts.
+    pub fn direction_value(&self) -> crate::api::v1::EnumValue<OrderDirection> {
+        crate::api::v1::EnumValue::from(self.direction)
+    }
+    /// Sets `direction` and returns message.
+    pub fn with_direction(mut self, value: OrderDirection) -> Self {
+        self.direction = value as i32;
+        self
+    }
+    /// Returns typed `order_type`, keeping values unknown to the contracts.
+    pub fn order_type_value(&self) -> crate::api::v1::EnumValue<OrderType> {
+        crate::api::v1::EnumValue::from(self.order_type)
+    }
+    /// Sets `order_type` and returns message.
+    pub fn with_order_type(mut self, value: OrderType) -> Self {
+        self.order_type = value as i32;
+        self
+    }
+    /// Returns typed `time_in_force`, keeping values unknown to the contracts.
+    pub fn time_in_force_value(&self) -> crate::api::v1::EnumValue<TimeInForceType> {
+        crate::api::v1::EnumValue::from(self.time_in_force)
+    }
+    /// Sets `time_in_force` and returns message.
+    pub fn with_time_in_force(mut self, value: TimeInForceType) -> Self {
+        self.time_in_force = value as i32;
+        self
+    }
+    /// Returns typed `price_type`, keeping values unknown to the contracts.
+    pub fn price_type_value(&self) -> crate::api::v1::EnumValue<PriceType> {
+        crate::api::v1::EnumValue::from(self.price_type)
+    }
+    /// Sets `price_type` and returns message.
+    pub fn with_price_type(mut self, value: PriceType) -> Self {
+        self.price_type = value as i32;
+        self
+    }
+}
 /// Информация о выставлении поручения.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct PostOrderResponse {
     /// Биржевой идентификатор заявки.
     #[prost(string, tag = "1")]
     pub order_id: ::prost::alloc::string::String,
     /// Текущий статус заявки.
     #[prost(enumeration = "OrderExecutionReportStatus", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<OrderExecutionReportStatus, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<OrderExecutionReportStatus, _>"))]
     pub execution_report_status: i32,
     /// Запрошено лотов.
     #[prost(int64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
     pub lots_requested: i64,
     /// Исполнено лотов.
     #[prost(int64, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
     pub lots_executed: i64,
     /// Начальная цена заявки. Произведение количества запрошенных лотов на цену.
     #[prost(message, optional, tag = "5")]
@@ -8771,12 +10857,14 @@ pub struct PostOrderResponse {
     pub figi: ::prost::alloc::string::String,
     /// Направление сделки.
     #[prost(enumeration = "OrderDirection", tag = "12")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<OrderDirection, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<OrderDirection, _>"))]
     pub direction: i32,
     /// Начальная цена за 1 инструмент. Для получения стоимости лота требуется умножить на лотность инструмента.
     #[prost(message, optional, tag = "13")]
     pub initial_security_price: ::core::option::Option<MoneyValue>,
     /// Тип заявки.
     #[prost(enumeration = "OrderType", tag = "14")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<OrderType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<OrderType, _>"))]
     pub order_type: i32,
     /// Дополнительные данные об исполнении заявки.
     #[prost(string, tag = "15")]
@@ -8794,52 +10882,142 @@ pub struct PostOrderResponse {
     #[prost(message, optional, tag = "254")]
     pub response_metadata: ::core::option::Option<ResponseMetadata>,
 }
+impl PostOrderResponse {
+    /// Returns typed `execution_report_status`, keeping values unknown to the contracts.
+    pub fn execution_report_status_value(&self) -> crate::api::v1::EnumValue<OrderExecutionReportStatus> {
+        crate::api::v1::EnumValue::from(self.execution_report_status)
+    }
+    /// Sets `execution_report_status` and returns message.
+    pub fn with_execution_report_status(mut self, value: OrderExecutionReportStatus) -> Self {
+        self.execution_report_status = value as i32;
+        self
+    }
+    /// Returns typed `direction`, keeping values unknown to the contracts.
+    pub fn direction_value(&self) -> crate::api::v1::EnumValue<OrderDirection> {
+        crate::api::v1::EnumValue::from(self.direction)
+    }
+    /// Sets `direction` and returns message.
+    pub fn with_direction(mut self, value: OrderDirection) -> Self {
+        self.direction = value as i32;
+        self
+    }
+    /// Returns typed `order_type`, keeping values unknown to the contracts.
+    pub fn order_type_value(&self) -> crate::api::v1::EnumValue<OrderType> {
+        crate::api::v1::EnumValue::from(self.order_type)
+    }
+    /// Sets `order_type` and returns message.
+    pub fn with_order_type(mut self, value: OrderType) -> Self {
+        self.order_type = value as i32;
+        self
+    }
+}
 /// Запрос выставления асинхронного торгового поручения.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct PostOrderAsyncRequest {
     /// Идентификатор инструмента, принимает значения Figi или Instrument_uid.
     #[prost(string, tag = "1")]
     pub instrument_id: ::prost::alloc::string::String,
     /// Количество лотов.
     #[prost(int64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
     pub quantity: i64,
     /// Цена за 1 инструмент. Для получения стоимости лота требуется умножить на лотность инструмента. Игнорируется для рыночных поручений.
     #[prost(message, optional, tag = "3")]
     pub price: ::core::option::Option<Quotation>,
     /// Направление операции.
     #[prost(enumeration = "OrderDirection", tag = "4")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<OrderDirection, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<OrderDirection, _>"))]
     pub direction: i32,
     /// Номер счёта.
     #[prost(string, tag = "5")]
     pub account_id: ::prost::alloc::string::String,
     /// Тип заявки.
     #[prost(enumeration = "OrderType", tag = "6")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<OrderType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<OrderType, _>"))]
     pub order_type: i32,
     /// Идентификатор запроса выставления поручения для целей идемпотентности в формате UID. Максимальная длина 36 символов.
     #[prost(string, tag = "7")]
     pub order_id: ::prost::alloc::string::String,
     /// Алгоритм исполнения поручения, применяется только к лимитной заявке.
     #[prost(enumeration = "TimeInForceType", optional, tag = "8")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration_option::serialize::<TimeInForceType, _>", deserialize_with = "crate::api::v1::serialization::enumeration_option::deserialize::<TimeInForceType, _>"))]
     pub time_in_force: ::core::option::Option<i32>,
     /// Тип цены.
     #[prost(enumeration = "PriceType", optional, tag = "9")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration_option::serialize::<PriceType, _>", deserialize_with = "crate::api::v1::serialization::enumeration_option::deserialize::<PriceType, _>"))]
     pub price_type: ::core::option::Option<i32>,
 }
+impl PostOrderAsyncRequest {
+    /// Returns typed `direction`, keeping values unknown to the contracts.
+    pub fn direction_value(&self) -> crate::api::v1::EnumValue<OrderDirection> {
+        crate::api::v1::EnumValue::from(self.direction)
+    }
+    /// Sets `direction` and returns message.
+    pub fn with_direction(mut self, value: OrderDirection) -> Self {
+        self.direction = value as i32;
+        self
+    }
+    /// Returns typed `order_type`, keeping values unknown to the contracts.
+    pub fn order_type_value(&self) -> crate::api::v1::EnumValue<OrderType> {
+        crate::api::v1::EnumValue::from(self.order_type)
+    }
+    /// Sets `order_type` and returns message.
+    pub fn with_order_type(mut self, value: OrderType) -> Self {
+        self.order_type = value as i32;
+        self
+    }
+    /// Returns typed `time_in_force`, keeping values unknown to the contracts.
+    pub fn time_in_force_value(&self) -> ::core::option::Option<crate::api::v1::EnumValue<TimeInForceType>> {
+        self.time_in_force.map(crate::api::v1::EnumValue::from)
+    }
+    /// Sets `time_in_force` and returns message.
+    pub fn with_time_in_force(mut self, value: TimeInForceType) -> Self {
+        self.time_in_force = ::core::option::Option::Some(value as i32);
+        self
+    }
+    /// Returns typed `price_type`, keeping values unknown to the contracts.
+    pub fn price_type_value(&self) -> ::core::option::Option<crate::api::v1::EnumValue<PriceType>> {
+        self.price_type.map(crate::api::v1::EnumValue::from)
+    }
+    /// Sets `price_type` and returns message.
+    pub fn with_price_type(mut self, value: PriceType) -> Self {
+        self.price_type = ::core::option::Option::Some(value as i32);
+        self
+    }
+}
 /// Результат выставления асинхронного торгового поручения.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct PostOrderAsyncResponse {
     /// Идентификатор ключа идемпотентности, переданный клиентом, в формате UID. Максимальная длина 36 символов.
     #[prost(string, tag = "1")]
     pub order_request_id: ::prost::alloc::string::String,
     /// Текущий статус заявки.
     #[prost(enumeration = "OrderExecutionReportStatus", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<OrderExecutionReportStatus, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<OrderExecutionReportStatus, _>"))]
     pub execution_report_status: i32,
     /// Идентификатор торгового поручения.
     #[prost(string, optional, tag = "3")]
     pub trade_intent_id: ::core::option::Option<::prost::alloc::string::String>,
 }
+impl PostOrderAsyncResponse {
+    /// Returns typed `execution_report_status`, keeping values unknown to the contracts.
+    pub fn execution_report_status_value(&self) -> crate::api::v1::EnumValue<OrderExecutionReportStatus> {
+        crate::api::v1::EnumValue::from(self.execution_report_status)
+    }
+    /// Sets `execution_report_status` and returns message.
+    pub fn with_execution_report_status(mut self, value: OrderExecutionReportStatus) -> Self {
+        self.execution_report_status = value as i32;
+        self
+    }
+}
 /// Запрос отмены торгового поручения.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct CancelOrderRequest {
     /// Номер счёта.
     #[prost(string, tag = "1")]
@@ -8849,13 +11027,28 @@ pub struct CancelOrderRequest {
     pub order_id: ::prost::alloc::string::String,
     /// Тип идентификатора заявки.
     #[prost(enumeration = "OrderIdType", optional, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration_option::serialize::<OrderIdType, _>", deserialize_with = "crate::api::v1::serialization::enumeration_option::deserialize::<OrderIdType, _>"))]
     pub order_id_type: ::core::option::Option<i32>,
 }
+impl CancelOrderRequest {
+    /// Returns typed `order_id_type`, keeping values unknown to the contracts.
+    pub fn order_id_type_value(&self) -> ::core::option::Option<crate::api::v1::EnumValue<OrderIdType>> {
+        self.order_id_type.map(crate::api::v1::EnumValue::from)
+    }
+    /// Sets `order_id_type` and returns message.
+    pub fn with_order_id_type(mut self, value: OrderIdType) -> Self {
+        self.order_id_type = ::core::option::Option::Some(value as i32);
+        self
+    }
+}
 /// Результат отмены торгового поручения.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct CancelOrderResponse {
     /// Дата и время отмены заявки в часовом поясе UTC.
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub time: ::core::option::Option<::prost_types::Timestamp>,
     /// Метадата
     #[prost(message, optional, tag = "254")]
@@ -8863,6 +11056,8 @@ pub struct CancelOrderResponse {
 }
 /// Запрос получения статуса торгового поручения.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetOrderStateRequest {
     /// Номер счёта.
     #[prost(string, tag = "1")]
@@ -8872,13 +11067,37 @@ pub struct GetOrderStateRequest {
     pub order_id: ::prost::alloc::string::String,
     /// Тип цены.
     #[prost(enumeration = "PriceType", tag = "3")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<PriceType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<PriceType, _>"))]
     pub price_type: i32,
     /// Тип идентификатора заявки.
     #[prost(enumeration = "OrderIdType", optional, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration_option::serialize::<OrderIdType, _>", deserialize_with = "crate::api::v1::serialization::enumeration_option::deserialize::<OrderIdType, _>"))]
     pub order_id_type: ::core::option::Option<i32>,
 }
+impl GetOrderStateRequest {
+    /// Returns typed `price_type`, keeping values unknown to the contracts.
+    pub fn price_type_value(&self) -> crate::api::v1::EnumValue<PriceType> {
+        crate::api::v1::EnumValue::from(self.price_type)
+    }
+    /// Sets `price_type` and returns message.
+    pub fn with_price_type(mut self, value: PriceType) -> Self {
+        self.price_type = value as i32;
+        self
+    }
+    /// Returns typed `order_id_type`, keeping values unknown to the contracts.
+    pub fn order_id_type_value(&self) -> ::core::option::Option<crate::api::v1::EnumValue<OrderIdType>> {
+        self.order_id_type.map(crate::api::v1::EnumValue::from)
+    }
+    /// Sets `order_id_type` and returns message.
+    pub fn with_order_id_type(mut self, value: OrderIdType) -> Self {
+        self.order_id_type = ::core::option::Option::Some(value as i32);
+        self
+    }
+}
 /// Запрос получения списка активных торговых поручений.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetOrdersRequest {
     /// Номер счёта.
     #[prost(string, tag = "1")]
@@ -8886,6 +11105,8 @@ pub struct GetOrdersRequest {
 }
 /// Список активных торговых поручений.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetOrdersResponse {
     /// Массив активных заявок.
     #[prost(message, repeated, tag = "1")]
@@ -8893,18 +11114,23 @@ pub struct GetOrdersResponse {
 }
 /// Информация о торговом поручении.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct OrderState {
     /// Биржевой идентификатор заявки.
     #[prost(string, tag = "1")]
     pub order_id: ::prost::alloc::string::String,
     /// Текущий статус заявки.
     #[prost(enumeration = "OrderExecutionReportStatus", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<OrderExecutionReportStatus, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<OrderExecutionReportStatus, _>"))]
     pub execution_report_status: i32,
     /// Запрошено лотов.
     #[prost(int64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
     pub lots_requested: i64,
     /// Исполнено лотов.
     #[prost(int64, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
     pub lots_executed: i64,
     /// Начальная цена заявки. Произведение количества запрошенных лотов на цену.
     #[prost(message, optional, tag = "5")]
@@ -8929,6 +11155,7 @@ pub struct OrderState {
     pub figi: ::prost::alloc::string::String,
     /// Направление заявки.
     #[prost(enumeration = "OrderDirection", tag = "12")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<OrderDirection, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<OrderDirection, _>"))]
     pub direction: i32,
     /// Начальная цена за 1 инструмент. Для получения стоимости лота требуется умножить на лотность инструмента.
     #[prost(message, optional, tag = "13")]
@@ -8944,9 +11171,11 @@ pub struct OrderState {
     pub currency: ::prost::alloc::string::String,
     /// Тип заявки.
     #[prost(enumeration = "OrderType", tag = "17")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<OrderType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<OrderType, _>"))]
     pub order_type: i32,
     /// Дата и время выставления заявки в часовом поясе UTC.
     #[prost(message, optional, tag = "18")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub order_date: ::core::option::Option<::prost_types::Timestamp>,
     /// UID идентификатор инструмента.
     #[prost(string, tag = "19")]
@@ -8955,24 +11184,59 @@ pub struct OrderState {
     #[prost(string, tag = "20")]
     pub order_request_id: ::prost::alloc::string::String,
 }
+impl OrderState {
+    /// Returns typed `execution_report_status`, keeping values unknown to the contracts.
+    pub fn execution_report_status_value(&self) -> crate::api::v1::EnumValue<OrderExecutionReportStatus> {
+        crate::api::v1::EnumValue::from(self.execution_report_status)
+    }
+    /// Sets `execution_report_status` and returns message.
+    pub fn with_execution_report_status(mut self, value: OrderExecutionReportStatus) -> Self {
+        self.execution_report_status = value as i32;
+        self
+    }
+    /// Returns typed `direction`, keeping values unknown to the contracts.
+    pub fn direction_value(&self) -> crate::api::v1::EnumValue<OrderDirection> {
+        crate::api::v1::EnumValue::from(self.direction)
+    }
+    /// Sets `direction` and returns message.
+    pub fn with_direction(mut self, value: OrderDirection) -> Self {
+        self.direction = value as i32;
+        self
+    }
+    /// Returns typed `order_type`, keeping values unknown to the contracts.
+    pub fn order_type_value(&self) -> crate::api::v1::EnumValue<OrderType> {
+        crate::api::v1::EnumValue::from(self.order_type)
+    }
+    /// Sets `order_type` and returns message.
+    pub fn with_order_type(mut self, value: OrderType) -> Self {
+        self.order_type = value as i32;
+        self
+    }
+}
 /// Сделки в рамках торгового поручения.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct OrderStage {
     /// Цена за 1 инструмент. Для получения стоимости лота требуется умножить на лотность инструмента.
     #[prost(message, optional, tag = "1")]
     pub price: ::core::option::Option<MoneyValue>,
     /// Количество лотов.
     #[prost(int64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
     pub quantity: i64,
     /// Идентификатор сделки.
     #[prost(string, tag = "3")]
     pub trade_id: ::prost::alloc::string::String,
     /// Время исполнения сделки
     #[prost(message, optional, tag = "5")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub execution_time: ::core::option::Option<::prost_types::Timestamp>,
 }
 /// Запрос изменения выставленной заявки.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct ReplaceOrderRequest {
     /// Номер счета.
     #[prost(string, tag = "1")]
@@ -8985,16 +11249,31 @@ pub struct ReplaceOrderRequest {
     pub idempotency_key: ::prost::alloc::string::String,
     /// Количество лотов.
     #[prost(int64, tag = "11")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
     pub quantity: i64,
     /// Цена за 1 инструмент.
     #[prost(message, optional, tag = "12")]
     pub price: ::core::option::Option<Quotation>,
     /// Тип цены.
     #[prost(enumeration = "PriceType", optional, tag = "13")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration_option::serialize::<PriceType, _>", deserialize_with = "crate::api::v1::serialization::enumeration_option::deserialize::<PriceType, _>"))]
     pub price_type: ::core::option::Option<i32>,
 }
+impl ReplaceOrderRequest {
+    /// Returns typed `price_type`, keeping values unknown to the contracts.
+    pub fn price_type_value(&self) -> ::core::option::Option<crate::api::v1::EnumValue<PriceType>> {
+        self.price_type.map(crate::api::v1::EnumValue::from)
+    }
+    /// Sets `price_type` and returns message.
+    pub fn with_price_type(mut self, value: PriceType) -> Self {
+        self.price_type = ::core::option::Option::Some(value as i32);
+        self
+    }
+}
 /// Запрос на расчет количества доступных для покупки/продажи лотов. Если не указывать цену инструмента, то расчет произведется по текущум ценам в стакане: по лучшему предложению для покупки и по лучшему спросу для продажи.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetMaxLotsRequest {
     /// Номер счета
     #[prost(string, tag = "1")]
@@ -9008,6 +11287,8 @@ pub struct GetMaxLotsRequest {
 }
 /// Результат количество доступных для покупки/продажи лотов
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetMaxLotsResponse {
     /// Валюта инструмента
     #[prost(string, tag = "1")]
@@ -9030,26 +11311,35 @@ pub struct GetMaxLotsResponse {
 /// Nested message and enum types in `GetMaxLotsResponse`.
 pub mod get_max_lots_response {
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
     pub struct BuyLimitsView {
         /// Количество доступной валюты для покупки
         #[prost(message, optional, tag = "1")]
         pub buy_money_amount: ::core::option::Option<super::Quotation>,
         /// Максимальное доступное количество лотов для покупки
         #[prost(int64, tag = "2")]
+        #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
         pub buy_max_lots: i64,
         /// Максимальное доступное количество лотов для покупки для заявки по рыночной цене на текущий момент
         #[prost(int64, tag = "3")]
+        #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
         pub buy_max_market_lots: i64,
     }
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
     pub struct SellLimitsView {
         /// Максимальное доступное количество лотов для продажи
         #[prost(int64, tag = "1")]
+        #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
         pub sell_max_lots: i64,
     }
 }
 /// Запрос получения предварительной стоимости заявки
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetOrderPriceRequest {
     /// Номер счета
     #[prost(string, tag = "1")]
@@ -9062,13 +11352,28 @@ pub struct GetOrderPriceRequest {
     pub price: ::core::option::Option<Quotation>,
     /// Направление заявки
     #[prost(enumeration = "OrderDirection", tag = "12")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<OrderDirection, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<OrderDirection, _>"))]
     pub direction: i32,
     /// Количество лотов
     #[prost(int64, tag = "13")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
     pub quantity: i64,
 }
+impl GetOrderPriceRequest {
+    /// Returns typed `direction`, keeping values unknown to the contracts.
+    pub fn direction_value(&self) -> crate::api::v1::EnumValue<OrderDirection> {
+        crate::api::v1::EnumValue::from(self.direction)
+    }
+    /// Sets `direction` and returns message.
+    pub fn with_direction(mut self, value: OrderDirection) -> Self {
+        self.direction = value as i32;
+        self
+    }
+}
 /// Предварительная стоимость заявки
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetOrderPriceResponse {
     /// Итоговая стоимость заявки
     #[prost(message, optional, tag = "1")]
@@ -9078,6 +11383,7 @@ pub struct GetOrderPriceResponse {
     pub initial_order_amount: ::core::option::Option<MoneyValue>,
     /// Запрошено лотов
     #[prost(int64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
     pub lots_requested: i64,
     /// Общая комиссия
     #[prost(message, optional, tag = "7")]
@@ -9092,6 +11398,7 @@ pub struct GetOrderPriceResponse {
     #[prost(message, optional, tag = "10")]
     pub deal_commission: ::core::option::Option<MoneyValue>,
     #[prost(oneof = "get_order_price_response::InstrumentExtra", tags = "12, 13")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub instrument_extra: ::core::option::Option<
         get_order_price_response::InstrumentExtra,
     >,
@@ -9099,6 +11406,8 @@ pub struct GetOrderPriceResponse {
 /// Nested message and enum types in `GetOrderPriceResponse`.
 pub mod get_order_price_response {
     #[derive(Clone, PartialEq, ::prost::Message)]
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
     pub struct ExtraBond {
         /// Значение НКД (накопленного купонного дохода) на дату
         #[prost(message, optional, tag = "2")]
@@ -9108,12 +11417,16 @@ pub mod get_order_price_response {
         pub nominal_conversion_rate: ::core::option::Option<super::Quotation>,
     }
     #[derive(Clone, PartialEq, ::prost::Message)]
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
     pub struct ExtraFuture {
         /// Гарантийное обеспечение для фьючерса
         #[prost(message, optional, tag = "2")]
         pub initial_margin: ::core::option::Option<super::MoneyValue>,
     }
     #[derive(Clone, PartialEq, ::prost::Oneof)]
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
     pub enum InstrumentExtra {
         /// Дополнительная информация по облигациям
         #[prost(message, tag = "12")]
@@ -9125,6 +11438,8 @@ pub mod get_order_price_response {
 }
 /// Запрос установки стрим-соединения торговых поручений
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct OrderStateStreamRequest {
     /// Идентификаторы счетов.
     #[prost(string, repeated, tag = "1")]
@@ -9135,12 +11450,15 @@ pub struct OrderStateStreamRequest {
 }
 /// Информация по подпискам
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct SubscriptionResponse {
     /// Уникальный идентификатор запроса, подробнее: [tracking_id](<https://russianinvestments.github.io/investAPI/grpc#tracking-id>).
     #[prost(string, tag = "1")]
     pub tracking_id: ::prost::alloc::string::String,
     /// Статус подписки.
     #[prost(enumeration = "ResultSubscriptionStatus", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<ResultSubscriptionStatus, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<ResultSubscriptionStatus, _>"))]
     pub status: i32,
     /// Идентификатор открытого соединения
     #[prost(string, tag = "4")]
@@ -9151,16 +11469,32 @@ pub struct SubscriptionResponse {
     #[prost(message, optional, tag = "7")]
     pub error: ::core::option::Option<ErrorDetail>,
 }
+impl SubscriptionResponse {
+    /// Returns typed `status`, keeping values unknown to the contracts.
+    pub fn status_value(&self) -> crate::api::v1::EnumValue<ResultSubscriptionStatus> {
+        crate::api::v1::EnumValue::from(self.status)
+    }
+    /// Sets `status` and returns message.
+    pub fn with_status(mut self, value: ResultSubscriptionStatus) -> Self {
+        self.status = value as i32;
+        self
+    }
+}
 /// Информация по заявкам
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct OrderStateStreamResponse {
     #[prost(oneof = "order_state_stream_response::Payload", tags = "1, 2, 3")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub payload: ::core::option::Option<order_state_stream_response::Payload>,
 }
 /// Nested message and enum types in `OrderStateStreamResponse`.
 pub mod order_state_stream_response {
     /// Заявка
     #[derive(Clone, PartialEq, ::prost::Message)]
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
     pub struct OrderState {
         /// Биржевой идентификатор заявки
         #[prost(string, tag = "1")]
@@ -9173,12 +11507,15 @@ pub mod order_state_stream_response {
         pub client_code: ::prost::alloc::string::String,
         /// Дата создания заявки
         #[prost(message, optional, tag = "4")]
+        #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
         pub created_at: ::core::option::Option<::prost_types::Timestamp>,
         /// Статус заявки
         #[prost(enumeration = "super::OrderExecutionReportStatus", tag = "5")]
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<super::OrderExecutionReportStatus, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<super::OrderExecutionReportStatus, _>"))]
         pub execution_report_status: i32,
         /// Дополнительная информация по статусу
         #[prost(enumeration = "StatusCauseInfo", optional, tag = "6")]
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration_option::serialize::<StatusCauseInfo, _>", deserialize_with = "crate::api::v1::serialization::enumeration_option::deserialize::<StatusCauseInfo, _>"))]
         pub status_info: ::core::option::Option<i32>,
         /// Тикер инструмента
         #[prost(string, tag = "7")]
@@ -9191,12 +11528,15 @@ pub mod order_state_stream_response {
         pub lot_size: i32,
         /// Направление заявки
         #[prost(enumeration = "super::OrderDirection", tag = "10")]
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<super::OrderDirection, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<super::OrderDirection, _>"))]
         pub direction: i32,
         /// Алгоритм исполнения поручения
         #[prost(enumeration = "super::TimeInForceType", tag = "11")]
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<super::TimeInForceType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<super::TimeInForceType, _>"))]
         pub time_in_force: i32,
         /// Тип заявки
         #[prost(enumeration = "super::OrderType", tag = "12")]
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<super::OrderType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<super::OrderType, _>"))]
         pub order_type: i32,
         /// Номер счета
         #[prost(string, tag = "13")]
@@ -9218,24 +11558,30 @@ pub mod order_state_stream_response {
         pub currency: ::prost::alloc::string::String,
         /// Запрошено лотов
         #[prost(int64, tag = "27")]
+        #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
         pub lots_requested: i64,
         /// Исполнено лотов
         #[prost(int64, tag = "28")]
+        #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
         pub lots_executed: i64,
         /// Число неисполненных лотов по заявке
         #[prost(int64, tag = "29")]
+        #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
         pub lots_left: i64,
         /// Отмененные лоты
         #[prost(int64, tag = "30")]
+        #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
         pub lots_cancelled: i64,
         /// Спецсимвол
         #[prost(enumeration = "MarkerType", optional, tag = "31")]
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration_option::serialize::<MarkerType, _>", deserialize_with = "crate::api::v1::serialization::enumeration_option::deserialize::<MarkerType, _>"))]
         pub marker: ::core::option::Option<i32>,
         /// 	Список сделок
         #[prost(message, repeated, tag = "33")]
         pub trades: ::prost::alloc::vec::Vec<super::OrderTrade>,
         /// Время исполнения заявки
         #[prost(message, optional, tag = "35")]
+        #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
         pub completion_time: ::core::option::Option<::prost_types::Timestamp>,
         /// Код биржи
         #[prost(string, tag = "36")]
@@ -9244,6 +11590,62 @@ pub mod order_state_stream_response {
         #[prost(string, tag = "41")]
         pub instrument_uid: ::prost::alloc::string::String,
     }
+    impl OrderState {
+        /// Returns typed `execution_report_status`, keeping values unknown to the contracts.
+        pub fn execution_report_status_value(&self) -> crate::api::v1::EnumValue<super::OrderExecutionReportStatus> {
+            crate::api::v1::EnumValue::from(self.execution_report_status)
+        }
+        /// Sets `execution_report_status` and returns message.
+        pub fn with_execution_report_status(mut self, value: super::OrderExecutionReportStatus) -> Self {
+            self.execution_report_status = value as i32;
+            self
+        }
+        /// Returns typed `status_info`, keeping values unknown to the contracts.
+        pub fn status_info_value(&self) -> ::core::option::Option<crate::api::v1::EnumValue<StatusCauseInfo>> {
+            self.status_info.map(crate::api::v1::EnumValue::from)
+        }
+        /// Sets `status_info` and returns message.
+        pub fn with_status_info(mut self, value: StatusCauseInfo) -> Self {
+            self.status_info = ::core::option::Option::Some(value as i32);
+            self
+        }
+        /// Returns typed `direction`, keeping values unknown to the contracts.
+        pub fn direction_value(&self) -> crate::api::v1::EnumValue<super::OrderDirection> {
+            crate::api::v1::EnumValue::from(self.direction)
+        }
+        /// Sets `direction` and returns message.
+        pub fn with_direction(mut self, value: super::OrderDirection) -> Self {
+            self.direction = value as i32;
+            self
+        }
+        /// Returns typed `time_in_force`, keeping values unknown to the contracts.
+        pub fn time_in_force_value(&self) -> crate::api::v1::EnumValue<super::TimeInForceType> {
+            crate::api::v1::EnumValue::from(self.time_in_force)
+        }
+        /// Sets `time_in_force` and returns message.
+        pub fn with_time_in_force(mut self, value: super::TimeInForceType) -> Self {
+            self.time_in_force = value as i32;
+            self
+        }
+        /// Returns typed `order_type`, keeping values unknown to the contracts.
+        pub fn order_type_value(&self) -> crate::api::v1::EnumValue<super::OrderType> {
+            crate::api::v1::EnumValue::from(self.order_type)
+        }
+        /// Sets `order_type` and returns message.
+        pub fn with_order_type(mut self, value: super::OrderType) -> Self {
+            self.order_type = value as i32;
+            self
+        }
+        /// Returns typed `marker`, keeping values unknown to the contracts.
+        pub fn marker_value(&self) -> ::core::option::Option<crate::api::v1::EnumValue<MarkerType>> {
+            self.marker.map(crate::api::v1::EnumValue::from)
+        }
+        /// Sets `marker` and returns message.
+        pub fn with_marker(mut self, value: MarkerType) -> Self {
+            self.marker = ::core::option::Option::Some(value as i32);
+            self
+        }
+    }
     /// Маркер
     #[derive(
         Clone,
@@ -9310,6 +11712,15 @@ pub mod order_state_stream_response {
             }
         }
     }
+    #[cfg(feature = "serde")]
+    impl crate::api::v1::serialization::ProtoEnum for MarkerType {
+        fn proto_name(&self) -> &'static str {
+            self.as_str_name()
+        }
+        fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+            Self::from_str_name(value)
+        }
+    }
     /// Дополнительная информация по статусу заявки
     #[derive(
         Clone,
@@ -9379,7 +11790,18 @@ pub mod order_state_stream_response {
             }
         }
     }
+    #[cfg(feature = "serde")]
+    impl crate::api::v1::serialization::ProtoEnum for StatusCauseInfo {
+        fn proto_name(&self) -> &'static str {
+            self.as_str_name()
+        }
+        fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+            Self::from_str_name(value)
+        }
+    }
     #[derive(Clone, PartialEq, ::prost::Oneof)]
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
     pub enum Payload {
         /// Информация об исполнении торгового поручения.
         #[prost(message, tag = "1")]
@@ -9425,6 +11847,15 @@ impl OrderDirection {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for OrderDirection {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Тип заявки.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -9462,6 +11893,15 @@ impl OrderType {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for OrderType {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Текущий статус заявки (поручения)
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -9518,6 +11958,15 @@ impl OrderExecutionReportStatus {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for OrderExecutionReportStatus {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Алгоритм исполнения заявки
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -9555,6 +12004,15 @@ impl TimeInForceType {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for TimeInForceType {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Тип идентификатора заявки
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -9588,6 +12046,15 @@ impl OrderIdType {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for OrderIdType {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Generated client implementations.
 pub mod orders_stream_service_client {
     #![allow(
@@ -10073,13 +12540,29 @@ pub mod orders_service_client {
 }
 /// Запрос получения счетов пользователя.
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetAccountsRequest {
     /// Статус счета.
     #[prost(enumeration = "AccountStatus", optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration_option::serialize::<AccountStatus, _>", deserialize_with = "crate::api::v1::serialization::enumeration_option::deserialize::<AccountStatus, _>"))]
     pub status: ::core::option::Option<i32>,
 }
+impl GetAccountsRequest {
+    /// Returns typed `status`, keeping values unknown to the contracts.
+    pub fn status_value(&self) -> ::core::option::Option<crate::api::v1::EnumValue<AccountStatus>> {
+        self.status.map(crate::api::v1::EnumValue::from)
+    }
+    /// Sets `status` and returns message.
+    pub fn with_status(mut self, value: AccountStatus) -> Self {
+        self.status = ::core::option::Option::Some(value as i32);
+        self
+    }
+}
 /// Список счетов пользователя.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetAccountsResponse {
     /// Массив счетов клиента.
     #[prost(message, repeated, tag = "1")]
@@ -10087,31 +12570,69 @@ pub struct GetAccountsResponse {
 }
 /// Информация о счёте.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct Account {
     /// Идентификатор счёта.
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
     /// Тип счёта.
     #[prost(enumeration = "AccountType", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<AccountType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<AccountType, _>"))]
     pub r#type: i32,
     /// Название счёта.
     #[prost(string, tag = "3")]
     pub name: ::prost::alloc::string::String,
     /// Статус счёта.
     #[prost(enumeration = "AccountStatus", tag = "4")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<AccountStatus, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<AccountStatus, _>"))]
     pub status: i32,
     /// Дата открытия счёта в часовом поясе UTC.
     #[prost(message, optional, tag = "5")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub opened_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Дата закрытия счёта в часовом поясе UTC.
     #[prost(message, optional, tag = "6")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub closed_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Уровень доступа к текущему счёту (определяется токеном).
     #[prost(enumeration = "AccessLevel", tag = "7")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<AccessLevel, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<AccessLevel, _>"))]
     pub access_level: i32,
 }
+impl Account {
+    /// Returns typed `type`, keeping values unknown to the contracts.
+    pub fn type_value(&self) -> crate::api::v1::EnumValue<AccountType> {
+        crate::api::v1::EnumValue::from(self.r#type)
+    }
+    /// Sets `type` and returns message.
+    pub fn with_type(mut self, value: AccountType) -> Self {
+        self.r#type = value as i32;
+        self
+    }
+    /// Returns typed `status`, keeping values unknown to the contracts.
+    pub fn status_value(&self) -> crate::api::v1::EnumValue<AccountStatus> {
+        crate::api::v1::EnumValue::from(self.status)
+    }
+    /// Sets `status` and returns message.
+    pub fn with_status(mut self, value: AccountStatus) -> Self {
+        self.status = value as i32;
+        self
+    }
+    /// Returns typed `access_level`, keeping values unknown to the contracts.
+    pub fn access_level_value(&self) -> crate::api::v1::EnumValue<AccessLevel> {
+        crate::api::v1::EnumValue::from(self.access_level)
+    }
+    /// Sets `access_level` and returns message.
+    pub fn with_access_level(mut self, value: AccessLevel) -> Self {
+        self.access_level = value as i32;
+        self
+    }
+}
 /// Запрос маржинальных показателей по счёту.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetMarginAttributesRequest {
     /// Идентификатор счёта пользователя.
     #[prost(string, tag = "1")]
@@ -10119,6 +12640,8 @@ pub struct GetMarginAttributesRequest {
 }
 /// Маржинальные показатели по счёту.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetMarginAttributesResponse {
     /// Ликвидная стоимость портфеля. [Подробнее про ликвидный портфель](<https://help.tbank.ru/margin-trade/short/liquid-portfolio/>).
     #[prost(message, optional, tag = "1")]
@@ -10141,9 +12664,13 @@ pub struct GetMarginAttributesResponse {
 }
 /// Запрос текущих лимитов пользователя.
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetUserTariffRequest {}
 /// Текущие лимиты пользователя.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetUserTariffResponse {
     /// Массив лимитов пользователя по unary-запросам.
     #[prost(message, repeated, tag = "1")]
@@ -10154,6 +12681,8 @@ pub struct GetUserTariffResponse {
 }
 /// Лимит unary-методов.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct UnaryLimit {
     /// Количество unary-запросов в минуту.
     #[prost(int32, tag = "1")]
@@ -10164,6 +12693,8 @@ pub struct UnaryLimit {
 }
 /// Лимит stream-соединений.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct StreamLimit {
     /// Максимальное количество stream-соединений.
     #[prost(int32, tag = "1")]
@@ -10177,9 +12708,13 @@ pub struct StreamLimit {
 }
 /// Запрос информации о пользователе.
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetInfoRequest {}
 /// Информация о пользователе.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetInfoResponse {
     /// Признак премиум клиента.
     #[prost(bool, tag = "1")]
@@ -10237,6 +12772,15 @@ impl AccountType {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for AccountType {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Статус счёта.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -10278,6 +12822,15 @@ impl AccountStatus {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for AccountStatus {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Уровень доступа к счёту.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -10319,6 +12872,15 @@ impl AccessLevel {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for AccessLevel {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Generated client implementations.
 pub mod users_service_client {
     #![allow(
@@ -10534,6 +13096,8 @@ pub mod users_service_client {
 }
 /// Запрос открытия счёта в песочнице.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct OpenSandboxAccountRequest {
     /// Название счёта
     #[prost(string, optional, tag = "1")]
@@ -10541,6 +13105,8 @@ pub struct OpenSandboxAccountRequest {
 }
 /// Номер открытого счёта в песочнице.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct OpenSandboxAccountResponse {
     /// Номер счёта
     #[prost(string, tag = "1")]
@@ -10548,6 +13114,8 @@ pub struct OpenSandboxAccountResponse {
 }
 /// Запрос закрытия счёта в песочнице.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct CloseSandboxAccountRequest {
     /// Номер счёта
     #[prost(string, tag = "1")]
@@ -10557,9 +13125,13 @@ pub struct CloseSandboxAccountRequest {
 ///
 /// пустой ответ
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct CloseSandboxAccountResponse {}
 /// Запрос пополнения счёта в песочнице.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct SandboxPayInRequest {
     /// Номер счёта
     #[prost(string, tag = "1")]
@@ -10570,6 +13142,8 @@ pub struct SandboxPayInRequest {
 }
 /// Результат пополнения счёта, текущий баланс.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct SandboxPayInResponse {
     /// Текущий баланс счёта
     #[prost(message, optional, tag = "1")]
@@ -11117,6 +13691,8 @@ pub mod sandbox_service_client {
 }
 /// Запрос стратегий.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetStrategiesRequest {
     /// Идентификатор стратегии
     #[prost(string, optional, tag = "1")]
@@ -11124,12 +13700,16 @@ pub struct GetStrategiesRequest {
 }
 /// Стратегии
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetStrategiesResponse {
     #[prost(message, repeated, tag = "1")]
     pub strategies: ::prost::alloc::vec::Vec<Strategy>,
 }
 /// Стратегия
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct Strategy {
     /// Идентификатор стратегии.
     #[prost(string, tag = "1")]
@@ -11145,6 +13725,7 @@ pub struct Strategy {
     pub strategy_url: ::core::option::Option<::prost::alloc::string::String>,
     /// Тип стратегии.
     #[prost(enumeration = "StrategyType", tag = "5")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<StrategyType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<StrategyType, _>"))]
     pub strategy_type: i32,
     /// Количество активных сигналов.
     #[prost(int32, tag = "6")]
@@ -11154,6 +13735,7 @@ pub struct Strategy {
     pub total_signals: i32,
     /// Среднее время нахождения сигнала в позиции.
     #[prost(int64, tag = "8")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
     pub time_in_position: i64,
     /// Средняя доходность сигнала в стратегии.
     #[prost(message, optional, tag = "9")]
@@ -11168,8 +13750,21 @@ pub struct Strategy {
     #[prost(message, optional, tag = "12")]
     pub yield_year: ::core::option::Option<Quotation>,
 }
+impl Strategy {
+    /// Returns typed `strategy_type`, keeping values unknown to the contracts.
+    pub fn strategy_type_value(&self) -> crate::api::v1::EnumValue<StrategyType> {
+        crate::api::v1::EnumValue::from(self.strategy_type)
+    }
+    /// Sets `strategy_type` and returns message.
+    pub fn with_strategy_type(mut self, value: StrategyType) -> Self {
+        self.strategy_type = value as i32;
+        self
+    }
+}
 /// Запрос сигналов.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetSignalsRequest {
     /// Идентификатор сигнала.
     #[prost(string, optional, tag = "1")]
@@ -11179,28 +13774,64 @@ pub struct GetSignalsRequest {
     pub strategy_id: ::core::option::Option<::prost::alloc::string::String>,
     /// Тип стратегии.
     #[prost(enumeration = "StrategyType", optional, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration_option::serialize::<StrategyType, _>", deserialize_with = "crate::api::v1::serialization::enumeration_option::deserialize::<StrategyType, _>"))]
     pub strategy_type: ::core::option::Option<i32>,
     /// 	Идентификатор бумаги
     #[prost(string, optional, tag = "4")]
     pub instrument_uid: ::core::option::Option<::prost::alloc::string::String>,
     /// 	Дата начала запрашиваемого интервала в часовом поясе UTC.
     #[prost(message, optional, tag = "5")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub from: ::core::option::Option<::prost_types::Timestamp>,
     /// 	Дата конца запрашиваемого интервала  в часовом поясе UTC.
     #[prost(message, optional, tag = "6")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub to: ::core::option::Option<::prost_types::Timestamp>,
     /// 	Направление сигнала.
     #[prost(enumeration = "SignalDirection", optional, tag = "7")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration_option::serialize::<SignalDirection, _>", deserialize_with = "crate::api::v1::serialization::enumeration_option::deserialize::<SignalDirection, _>"))]
     pub direction: ::core::option::Option<i32>,
     /// Состояние сигнала.
     #[prost(enumeration = "SignalState", optional, tag = "8")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration_option::serialize::<SignalState, _>", deserialize_with = "crate::api::v1::serialization::enumeration_option::deserialize::<SignalState, _>"))]
     pub active: ::core::option::Option<i32>,
     /// Настройки пагинации.
     #[prost(message, optional, tag = "9")]
     pub paging: ::core::option::Option<Page>,
 }
+impl GetSignalsRequest {
+    /// Returns typed `strategy_type`, keeping values unknown to the contracts.
+    pub fn strategy_type_value(&self) -> ::core::option::Option<crate::api::v1::EnumValue<StrategyType>> {
+        self.strategy_type.map(crate::api::v1::EnumValue::from)
+    }
+    /// Sets `strategy_type` and returns message.
+    pub fn with_strategy_type(mut self, value: StrategyType) -> Self {
+        self.strategy_type = ::core::option::Option::Some(value as i32);
+        self
+    }
+    /// Returns typed `direction`, keeping values unknown to the contracts.
+    pub fn direction_value(&self) -> ::core::option::Option<crate::api::v1::EnumValue<SignalDirection>> {
+        self.direction.map(crate::api::v1::EnumValue::from)
+    }
+    /// Sets `direction` and returns message.
+    pub fn with_direction(mut self, value: SignalDirection) -> Self {
+        self.direction = ::core::option::Option::Some(value as i32);
+        self
+    }
+    /// Returns typed `active`, keeping values unknown to the contracts.
+    pub fn active_value(&self) -> ::core::option::Option<crate::api::v1::EnumValue<SignalState>> {
+        self.active.map(crate::api::v1::EnumValue::from)
+    }
+    /// Sets `active` and returns message.
+    pub fn with_active(mut self, value: SignalState) -> Self {
+        self.active = ::core::option::Option::Some(value as i32);
+        self
+    }
+}
 /// Сигналы.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetSignalsResponse {
     /// Массив сигналов.
     #[prost(message, repeated, tag = "1")]
@@ -11211,6 +13842,8 @@ pub struct GetSignalsResponse {
 }
 /// Сигнал.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct Signal {
     /// Идентификатор сигнала.
     #[prost(string, tag = "1")]
@@ -11226,9 +13859,11 @@ pub struct Signal {
     pub instrument_uid: ::prost::alloc::string::String,
     /// Дата и время создания сигнала  в часовом поясе UTC.
     #[prost(message, optional, tag = "5")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub create_dt: ::core::option::Option<::prost_types::Timestamp>,
     /// Направление сигнала
     #[prost(enumeration = "SignalDirection", tag = "6")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<SignalDirection, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<SignalDirection, _>"))]
     pub direction: i32,
     /// Цена бумаги на момент формирования сигнала
     #[prost(message, optional, tag = "7")]
@@ -11244,6 +13879,7 @@ pub struct Signal {
     pub target_price: ::core::option::Option<Quotation>,
     /// Дата и время дедлайна сигнала в часовом поясе UTC.
     #[prost(message, optional, tag = "11")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub end_dt: ::core::option::Option<::prost_types::Timestamp>,
     /// Вероятность сигнала.
     #[prost(int32, optional, tag = "12")]
@@ -11256,8 +13892,20 @@ pub struct Signal {
     pub close_price: ::core::option::Option<Quotation>,
     /// Дата и время закрытия сигнала в часовом поясе UTC.
     #[prost(message, optional, tag = "15")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub close_dt: ::core::option::Option<::prost_types::Timestamp>,
 }
+impl Signal {
+    /// Returns typed `direction`, keeping values unknown to the contracts.
+    pub fn direction_value(&self) -> crate::api::v1::EnumValue<SignalDirection> {
+        crate::api::v1::EnumValue::from(self.direction)
+    }
+    /// Sets `direction` and returns message.
+    pub fn with_direction(mut self, value: SignalDirection) -> Self {
+        self.direction = value as i32;
+        self
+    }
+}
 /// Тип стратегии.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -11291,6 +13939,15 @@ impl StrategyType {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for StrategyType {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Направление сигнала.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -11324,6 +13981,15 @@ impl SignalDirection {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for SignalDirection {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Статус сигнала.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -11361,6 +14027,15 @@ impl SignalState {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for SignalState {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Generated client implementations.
 pub mod signal_service_client {
     #![allow(
@@ -11516,6 +14191,8 @@ pub mod signal_service_client {
 }
 /// Запрос выставления стоп-заявки.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct PostStopOrderRequest {
     /// Deprecated FIGI-идентификатор инструмента. Используйте `instrument_id`.
     #[deprecated]
@@ -11523,6 +14200,7 @@ pub struct PostStopOrderRequest {
     pub figi: ::core::option::Option<::prost::alloc::string::String>,
     /// Количество лотов.
     #[prost(int64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
     pub quantity: i64,
     /// Цена за 1 инструмент биржевой заявки, которая будет выставлена при срабатывании по достижению `stop_price`. Чтобы получить стоимость лота, нужно умножить на лотность инструмента.
     #[prost(message, optional, tag = "3")]
@@ -11532,58 +14210,147 @@ pub struct PostStopOrderRequest {
     pub stop_price: ::core::option::Option<Quotation>,
     /// Направление операции.
     #[prost(enumeration = "StopOrderDirection", tag = "5")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<StopOrderDirection, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<StopOrderDirection, _>"))]
     pub direction: i32,
     /// Номер счёта.
     #[prost(string, tag = "6")]
     pub account_id: ::prost::alloc::string::String,
     /// Тип экспирации заявки.
     #[prost(enumeration = "StopOrderExpirationType", tag = "7")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<StopOrderExpirationType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<StopOrderExpirationType, _>"))]
     pub expiration_type: i32,
     /// Тип заявки.
     #[prost(enumeration = "StopOrderType", tag = "8")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<StopOrderType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<StopOrderType, _>"))]
     pub stop_order_type: i32,
     /// Дата и время окончания действия стоп-заявки по UTC. Для `ExpirationType = GoodTillDate` заполнение обязательно, для `GoodTillCancel` игнорируется.
     #[prost(message, optional, tag = "9")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub expire_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Идентификатор инструмента. Принимает значение `figi` или `instrument_uid`.
     #[prost(string, tag = "10")]
     pub instrument_id: ::prost::alloc::string::String,
     /// Тип дочерней биржевой заявки для тейкпрофита.
     #[prost(enumeration = "ExchangeOrderType", tag = "11")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<ExchangeOrderType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<ExchangeOrderType, _>"))]
     pub exchange_order_type: i32,
     /// Подтип стоп-заявки — `TakeProfit`.
     #[prost(enumeration = "TakeProfitType", tag = "12")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<TakeProfitType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<TakeProfitType, _>"))]
     pub take_profit_type: i32,
     /// Массив с параметрами трейлинг-стопа.
     #[prost(message, optional, tag = "13")]
     pub trailing_data: ::core::option::Option<post_stop_order_request::TrailingData>,
     /// Тип цены.
     #[prost(enumeration = "PriceType", tag = "14")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<PriceType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<PriceType, _>"))]
     pub price_type: i32,
     /// Идентификатор запроса выставления поручения для целей идемпотентности в формате `UID`. Максимальная длина — 36 символов.
     #[prost(string, tag = "15")]
     pub order_id: ::prost::alloc::string::String,
 }
+impl PostStopOrderRequest {
+    /// Returns typed `direction`, keeping values unknown to the contracts.
+    pub fn direction_value(&self) -> crate::api::v1::EnumValue<StopOrderDirection> {
+        crate::api::v1::EnumValue::from(self.direction)
+    }
+    /// Sets `direction` and returns message.
+    pub fn with_direction(mut self, value: StopOrderDirection) -> Self {
+        self.direction = value as i32;
+        self
+    }
+    /// Returns typed `expiration_type`, keeping values unknown to the contracts.
+    pub fn expiration_type_value(&self) -> crate::api::v1::EnumValue<StopOrderExpirationType> {
+        crate::api::v1::EnumValue::from(self.expiration_type)
+    }
+    /// Sets `expiration_type` and returns message.
+    pub fn with_expiration_type(mut self, value: StopOrderExpirationType) -> Self {
+        self.expiration_type = value as i32;
+        self
+    }
+    /// Returns typed `stop_order_type`, keeping values unknown to the contracts.
+    pub fn stop_order_type_value(&self) -> crate::api::v1::EnumValue<StopOrderType> {
+        crate::api::v1::EnumValue::from(self.stop_order_type)
+    }
+    /// Sets `stop_order_type` and returns message.
+    pub fn with_stop_order_type(mut self, value: StopOrderType) -> Self {
+        self.stop_order_type = value as i32;
+        self
+    }
+    /// Returns typed `exchange_order_type`, keeping values unknown to the contracts.
+    pub fn exchange_order_type_value(&self) -> crate::api::v1::EnumValue<ExchangeOrderType> {
+        crate::api::v1::EnumValue::from(self.exchange_order_type)
+    }
+    /// Sets `exchange_order_type` and returns message.
+    pub fn with_exchange_order_type(mut self, value: ExchangeOrderType) -> Self {
+        self.exchange_order_type = value as i32;
+        self
+    }
+    /// Returns typed `take_profit_type`, keeping values unknown to the contracts.
+    pub fn take_profit_type_value(&self) -> crate::api::v1::EnumValue<TakeProfitType> {
+        crate::api::v1::EnumValue::from(self.take_profit_type)
+    }
+    /// Sets `take_profit_type` and returns message.
+    pub fn with_take_profit_type(mut self, value: TakeProfitType) -> Self {
+        self.take_profit_type = value as i32;
+        self
+    }
+    /// Returns typed `price_type`, keeping values unknown to the contracts.
+    pub fn price_type_value(&self) -> crate::api::v1::EnumValue<PriceType> {
+        crate::api::v1::EnumValue::from(self.price_type)
+    }
+    /// Sets `price_type` and returns message.
+    pub fn with_price_type(mut self, value: PriceType) -> Self {
+        self.price_type = value as i32;
+        self
+    }
+}
 /// Nested message and enum types in `PostStopOrderRequest`.
 pub mod post_stop_order_request {
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
     pub struct TrailingData {
         /// Отступ.
         #[prost(message, optional, tag = "1")]
         pub indent: ::core::option::Option<super::Quotation>,
         /// Тип величины отступа.
         #[prost(enumeration = "super::TrailingValueType", tag = "2")]
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<super::TrailingValueType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<super::TrailingValueType, _>"))]
         pub indent_type: i32,
         /// Размер защитного спреда.
         #[prost(message, optional, tag = "3")]
         pub spread: ::core::option::Option<super::Quotation>,
         /// Тип величины защитного спреда.
         #[prost(enumeration = "super::TrailingValueType", tag = "4")]
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<super::TrailingValueType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<super::TrailingValueType, _>"))]
         pub spread_type: i32,
     }
+    impl TrailingData {
+        /// Returns typed `indent_type`, keeping values unknown to the contracts.
+        pub fn indent_type_value(&self) -> crate::api::v1::EnumValue<super::TrailingValueType> {
+            crate::api::v1::EnumValue::from(self.indent_type)
+        }
+        /// Sets `indent_type` and returns message.
+        pub fn with_indent_type(mut self, value: super::TrailingValueType) -> Self {
+            self.indent_type = value as i32;
+            self
+        }
+        /// Returns typed `spread_type`, keeping values unknown to the contracts.
+        pub fn spread_type_value(&self) -> crate::api::v1::EnumValue<super::TrailingValueType> {
+            crate::api::v1::EnumValue::from(self.spread_type)
+        }
+        /// Sets `spread_type` and returns message.
+        pub fn with_spread_type(mut self, value: super::TrailingValueType) -> Self {
+            self.spread_type = value as i32;
+            self
+        }
+    }
 }
 /// Результат выставления стоп-заявки.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct PostStopOrderResponse {
     /// Уникальный идентификатор стоп-заявки.
     #[prost(string, tag = "1")]
@@ -11597,22 +14364,40 @@ pub struct PostStopOrderResponse {
 }
 /// Запрос получения списка активных стоп-заявок.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetStopOrdersRequest {
     /// Идентификатор счёта клиента.
     #[prost(string, tag = "1")]
     pub account_id: ::prost::alloc::string::String,
     /// Статус заявок.
     #[prost(enumeration = "StopOrderStatusOption", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<StopOrderStatusOption, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<StopOrderStatusOption, _>"))]
     pub status: i32,
     /// Левая граница.
     #[prost(message, optional, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub from: ::core::option::Option<::prost_types::Timestamp>,
     /// Правая граница.
     #[prost(message, optional, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub to: ::core::option::Option<::prost_types::Timestamp>,
 }
+impl GetStopOrdersRequest {
+    /// Returns typed `status`, keeping values unknown to the contracts.
+    pub fn status_value(&self) -> crate::api::v1::EnumValue<StopOrderStatusOption> {
+        crate::api::v1::EnumValue::from(self.status)
+    }
+    /// Sets `status` and returns message.
+    pub fn with_status(mut self, value: StopOrderStatusOption) -> Self {
+        self.status = value as i32;
+        self
+    }
+}
 /// Список активных стоп-заявок.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct GetStopOrdersResponse {
     /// Массив стоп-заявок по счёту.
     #[prost(message, repeated, tag = "1")]
@@ -11620,6 +14405,8 @@ pub struct GetStopOrdersResponse {
 }
 /// Запрос отмены выставленной стоп-заявки.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct CancelStopOrderRequest {
     /// Идентификатор счёта клиента.
     #[prost(string, tag = "1")]
@@ -11630,40 +14417,51 @@ pub struct CancelStopOrderRequest {
 }
 /// Результат отмены выставленной стоп-заявки.
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct CancelStopOrderResponse {
     /// Время отмены заявки по UTC.
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub time: ::core::option::Option<::prost_types::Timestamp>,
 }
 /// Информация о стоп-заявке.
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct StopOrder {
     /// Уникальный идентификатор стоп-заявки.
     #[prost(string, tag = "1")]
     pub stop_order_id: ::prost::alloc::string::String,
     /// Запрошено лотов.
     #[prost(int64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::int64"))]
     pub lots_requested: i64,
     /// FIGI-идентификатор инструмента.
     #[prost(string, tag = "3")]
     pub figi: ::prost::alloc::string::String,
     /// Направление операции.
     #[prost(enumeration = "StopOrderDirection", tag = "4")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<StopOrderDirection, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<StopOrderDirection, _>"))]
     pub direction: i32,
     /// Валюта стоп-заявки.
     #[prost(string, tag = "5")]
     pub currency: ::prost::alloc::string::String,
     /// Тип стоп-заявки.
     #[prost(enumeration = "StopOrderType", tag = "6")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<StopOrderType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<StopOrderType, _>"))]
     pub order_type: i32,
     /// Дата и время выставления заявки по UTC.
     #[prost(message, optional, tag = "7")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub create_date: ::core::option::Option<::prost_types::Timestamp>,
     /// Дата и время конвертации стоп-заявки в биржевую по UTC.
     #[prost(message, optional, tag = "8")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub activation_date_time: ::core::option::Option<::prost_types::Timestamp>,
     /// Дата и время снятия заявки по UTC.
     #[prost(message, optional, tag = "9")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::api::v1::serialization::timestamp"))]
     pub expiration_time: ::core::option::Option<::prost_types::Timestamp>,
     /// Цена заявки за 1 инструмент. Чтобы получить стоимость лота, нужно умножить на лотность инструмента.
     #[prost(message, optional, tag = "10")]
@@ -11676,38 +14474,93 @@ pub struct StopOrder {
     pub instrument_uid: ::prost::alloc::string::String,
     /// Подтип стоп-заявки — `TakeProfit`.
     #[prost(enumeration = "TakeProfitType", tag = "13")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<TakeProfitType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<TakeProfitType, _>"))]
     pub take_profit_type: i32,
     /// Параметры трейлинг-стопа.
     #[prost(message, optional, tag = "14")]
     pub trailing_data: ::core::option::Option<stop_order::TrailingData>,
     /// Статус заявки.
     #[prost(enumeration = "StopOrderStatusOption", tag = "15")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<StopOrderStatusOption, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<StopOrderStatusOption, _>"))]
     pub status: i32,
     /// Тип дочерней биржевой заявки для тейкпрофита.
     #[prost(enumeration = "ExchangeOrderType", tag = "16")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<ExchangeOrderType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<ExchangeOrderType, _>"))]
     pub exchange_order_type: i32,
     /// Идентификатор биржевой заявки.
     #[prost(string, optional, tag = "17")]
     pub exchange_order_id: ::core::option::Option<::prost::alloc::string::String>,
 }
+impl StopOrder {
+    /// Returns typed `direction`, keeping values unknown to the contracts.
+    pub fn direction_value(&self) -> crate::api::v1::EnumValue<StopOrderDirection> {
+        crate::api::v1::EnumValue::from(self.direction)
+    }
+    /// Sets `direction` and returns message.
+    pub fn with_direction(mut self, value: StopOrderDirection) -> Self {
+        self.direction = value as i32;
+        self
+    }
+    /// Returns typed `order_type`, keeping values unknown to the contracts.
+    pub fn order_type_value(&self) -> crate::api::v1::EnumValue<StopOrderType> {
+        crate::api::v1::EnumValue::from(self.order_type)
+    }
+    /// Sets `order_type` and returns message.
+    pub fn with_order_type(mut self, value: StopOrderType) -> Self {
+        self.order_type = value as i32;
+        self
+    }
+    /// Returns typed `take_profit_type`, keeping values unknown to the contracts.
+    pub fn take_profit_type_value(&self) -> crate::api::v1::EnumValue<TakeProfitType> {
+        crate::api::v1::EnumValue::from(self.take_profit_type)
+    }
+    /// Sets `take_profit_type` and returns message.
+    pub fn with_take_profit_type(mut self, value: TakeProfitType) -> Self {
+        self.take_profit_type = value as i32;
+        self
+    }
+    /// Returns typed `status`, keeping values unknown to the contracts.
+    pub fn status_value(&self) -> crate::api::v1::EnumValue<StopOrderStatusOption> {
+        crate::api::v1::EnumValue::from(self.status)
+    }
+    /// Sets `status` and returns message.
+    pub fn with_status(mut self, value: StopOrderStatusOption) -> Self {
+        self.status = value as i32;
+        self
+    }
+    /// Returns typed `exchange_order_type`, keeping values unknown to the contracts.
+    pub fn exchange_order_type_value(&self) -> crate::api::v1::EnumValue<ExchangeOrderType> {
+        crate::api::v1::EnumValue::from(self.exchange_order_type)
+    }
+    /// Sets `exchange_order_type` and returns message.
+    pub fn with_exchange_order_type(mut self, value: ExchangeOrderType) -> Self {
+        self.exchange_order_type = value as i32;
+        self
+    }
+}
 /// Nested message and enum types in `StopOrder`.
 pub mod stop_order {
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
     pub struct TrailingData {
         /// Отступ.
         #[prost(message, optional, tag = "1")]
         pub indent: ::core::option::Option<super::Quotation>,
         /// Тип величины отступа.
         #[prost(enumeration = "super::TrailingValueType", tag = "2")]
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<super::TrailingValueType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<super::TrailingValueType, _>"))]
         pub indent_type: i32,
         /// Размер защитного спреда.
         #[prost(message, optional, tag = "3")]
         pub spread: ::core::option::Option<super::Quotation>,
         /// Тип величины защитного спреда.
         #[prost(enumeration = "super::TrailingValueType", tag = "4")]
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<super::TrailingValueType, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<super::TrailingValueType, _>"))]
         pub spread_type: i32,
         /// Статус трейлинг-стопа.
         #[prost(enumeration = "super::TrailingStopStatus", tag = "5")]
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::api::v1::serialization::enumeration::serialize::<super::TrailingStopStatus, _>", deserialize_with = "crate::api::v1::serialization::enumeration::deserialize::<super::TrailingStopStatus, _>"))]
         pub status: i32,
         /// Цена исполнения.
         #[prost(message, optional, tag = "7")]
@@ -11716,6 +14569,35 @@ pub mod stop_order {
         #[prost(message, optional, tag = "8")]
         pub extr: ::core::option::Option<super::Quotation>,
     }
+    impl TrailingData {
+        /// Returns typed `indent_type`, keeping values unknown to the contracts.
+        pub fn indent_type_value(&self) -> crate::api::v1::EnumValue<super::TrailingValueType> {
+            crate::api::v1::EnumValue::from(self.indent_type)
+        }
+        /// Sets `indent_type` and returns message.
+        pub fn with_indent_type(mut self, value: super::TrailingValueType) -> Self {
+            self.indent_type = value as i32;
+            self
+        }
+        /// Returns typed `spread_type`, keeping values unknown to the contracts.
+        pub fn spread_type_value(&self) -> crate::api::v1::EnumValue<super::TrailingValueType> {
+            crate::api::v1::EnumValue::from(self.spread_type)
+        }
+        /// Sets `spread_type` and returns message.
+        pub fn with_spread_type(mut self, value: super::TrailingValueType) -> Self {
+            self.spread_type = value as i32;
+            self
+        }
+        /// Returns typed `status`, keeping values unknown to the contracts.
+        pub fn status_value(&self) -> crate::api::v1::EnumValue<super::TrailingStopStatus> {
+            crate::api::v1::EnumValue::from(self.status)
+        }
+        /// Sets `status` and returns message.
+        pub fn with_status(mut self, value: super::TrailingStopStatus) -> Self {
+            self.status = value as i32;
+            self
+        }
+    }
 }
 /// Направление сделки стоп-заявки.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
@@ -11750,6 +14632,15 @@ impl StopOrderDirection {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for StopOrderDirection {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Тип экспирации стоп-заявке.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -11783,6 +14674,15 @@ impl StopOrderExpirationType {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for StopOrderExpirationType {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Тип стоп-заявки.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -11820,6 +14720,15 @@ impl StopOrderType {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for StopOrderType {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Статус стоп-заяки.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -11865,6 +14774,15 @@ impl StopOrderStatusOption {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for StopOrderStatusOption {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Тип выставляемой заявки.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -11898,6 +14816,15 @@ impl ExchangeOrderType {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for ExchangeOrderType {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Тип TakeProfit-заявки.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -11931,6 +14858,15 @@ impl TakeProfitType {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for TakeProfitType {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Тип параметров значений трейлинг-стопа.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -11964,6 +14900,15 @@ impl TrailingValueType {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for TrailingValueType {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Статус трейлинг-стопа.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -11997,6 +14942,15 @@ impl TrailingStopStatus {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl crate::api::v1::serialization::ProtoEnum for TrailingStopStatus {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// Generated client implementations.
 pub mod stop_orders_service_client {
     #![allow(
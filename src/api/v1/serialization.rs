@@ -0,0 +1,360 @@
+//! Serde helpers for generated messages, following proto3 JSON mapping
+//!
+//! * enums are written as proto names, e.g. `ORDER_DIRECTION_BUY`
+//! * `Timestamp` is written as RFC3339 string in UTC
+//! * `int64` is written as string
+//! * `Quotation` is written as decimal string, `MoneyValue` as `{"currency", "value"}` with decimal string value
+//!
+//! Deserialization also accepts numbers for enums, `int64` and `Quotation`.
+
+// region:    --- Modules
+
+use super::{MoneyValue, Quotation};
+use chrono::{SecondsFormat, Utc};
+use prost_types::Timestamp;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+// endregion: --- Modules
+
+/// Proto enumeration with names from contracts
+pub trait ProtoEnum: Sized + Into<i32> + TryFrom<i32> {
+    /// Returns name of the value as in proto contracts
+    fn proto_name(&self) -> &'static str;
+
+    /// Creates value from name as in proto contracts
+    fn from_proto_name(value: &str) -> Option<Self>;
+}
+
+/// Value that may be written as string or as number
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StringOrNumber<N> {
+    String(String),
+    Number(N),
+}
+
+// region:    --- Enumerations
+
+fn enum_to_name<E: ProtoEnum>(value: i32) -> Result<&'static str, i32> {
+    E::try_from(value)
+        .map(|e| e.proto_name())
+        .map_err(|_| value)
+}
+
+fn enum_from_value<E: ProtoEnum, Er: de::Error>(value: StringOrNumber<i32>) -> Result<i32, Er> {
+    match value {
+        StringOrNumber::Number(number) => Ok(number),
+        StringOrNumber::String(name) => E::from_proto_name(&name)
+            .map(Into::into)
+            .ok_or_else(|| Er::custom(format!("unknown enum value '{name}'"))),
+    }
+}
+
+/// `i32` enumeration field
+pub mod enumeration {
+    use super::*;
+
+    pub fn serialize<E: ProtoEnum, S: Serializer>(
+        value: &i32,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match enum_to_name::<E>(*value) {
+            Ok(name) => serializer.serialize_str(name),
+            Err(number) => serializer.serialize_i32(number),
+        }
+    }
+
+    pub fn deserialize<'de, E: ProtoEnum, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<i32, D::Error> {
+        enum_from_value::<E, _>(StringOrNumber::deserialize(deserializer)?)
+    }
+}
+
+/// `Option<i32>` enumeration field
+pub mod enumeration_option {
+    use super::*;
+
+    pub fn serialize<E: ProtoEnum, S: Serializer>(
+        value: &Option<i32>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(value) => enumeration::serialize::<E, S>(value, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, E: ProtoEnum, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<i32>, D::Error> {
+        Option::<StringOrNumber<i32>>::deserialize(deserializer)?
+            .map(enum_from_value::<E, _>)
+            .transpose()
+    }
+}
+
+/// `Vec<i32>` enumeration field
+pub mod enumeration_vec {
+    use super::*;
+    use serde::ser::SerializeSeq;
+
+    pub fn serialize<E: ProtoEnum, S: Serializer>(
+        values: &[i32],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(values.len()))?;
+
+        for value in values {
+            match enum_to_name::<E>(*value) {
+                Ok(name) => seq.serialize_element(name)?,
+                Err(number) => seq.serialize_element(&number)?,
+            }
+        }
+
+        seq.end()
+    }
+
+    pub fn deserialize<'de, E: ProtoEnum, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<i32>, D::Error> {
+        Vec::<StringOrNumber<i32>>::deserialize(deserializer)?
+            .into_iter()
+            .map(enum_from_value::<E, _>)
+            .collect()
+    }
+}
+
+// endregion: --- Enumerations
+
+// region:    --- Timestamps
+
+fn timestamp_to_string(timestamp: &Timestamp) -> Option<String> {
+    chrono::DateTime::<Utc>::from_timestamp(timestamp.seconds, timestamp.nanos as u32)
+        .map(|datetime| datetime.to_rfc3339_opts(SecondsFormat::AutoSi, true))
+}
+
+fn timestamp_from_str<Er: de::Error>(value: &str) -> Result<Timestamp, Er> {
+    let datetime = chrono::DateTime::parse_from_rfc3339(value).map_err(Er::custom)?;
+
+    Ok(Timestamp {
+        seconds: datetime.timestamp(),
+        nanos: datetime.timestamp_subsec_nanos() as i32,
+    })
+}
+
+/// `Option<Timestamp>` field
+pub mod timestamp {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        value: &Option<Timestamp>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(timestamp) => timestamp_to_string(timestamp)
+                .ok_or_else(|| serde::ser::Error::custom("timestamp out of range"))?
+                .serialize(serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Timestamp>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .map(|value| timestamp_from_str(&value))
+            .transpose()
+    }
+}
+
+/// `Vec<Timestamp>` field
+pub mod timestamp_vec {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        values: &[Timestamp],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        values
+            .iter()
+            .map(|timestamp| {
+                timestamp_to_string(timestamp)
+                    .ok_or_else(|| serde::ser::Error::custom("timestamp out of range"))
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<Timestamp>, D::Error> {
+        Vec::<String>::deserialize(deserializer)?
+            .iter()
+            .map(|value| timestamp_from_str(value))
+            .collect()
+    }
+}
+
+// endregion: --- Timestamps
+
+// region:    --- Numbers
+
+/// `i64` field
+pub mod int64 {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(value: &i64, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i64, D::Error> {
+        match StringOrNumber::<i64>::deserialize(deserializer)? {
+            StringOrNumber::Number(number) => Ok(number),
+            StringOrNumber::String(value) => value.parse().map_err(de::Error::custom),
+        }
+    }
+}
+
+// endregion: --- Numbers
+
+// region:    --- Money
+
+impl Serialize for Quotation {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_decimal_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Quotation {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match StringOrNumber::<f64>::deserialize(deserializer)? {
+            StringOrNumber::Number(number) => Ok(Quotation::from_f64(number)),
+            StringOrNumber::String(value) => value.parse().map_err(de::Error::custom),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct MoneyValueRepr {
+    currency: String,
+    value: Quotation,
+}
+
+impl Serialize for MoneyValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        MoneyValueRepr {
+            currency: self.currency.clone(),
+            value: self.to_quotation(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for MoneyValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = MoneyValueRepr::deserialize(deserializer)?;
+
+        Ok(MoneyValue::new(
+            repr.currency,
+            repr.value.units,
+            repr.value.nano,
+        ))
+    }
+}
+
+// endregion: --- Money
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    type Error = Box<dyn std::error::Error>;
+    type Result<T> = core::result::Result<T, Error>; // For tests.
+
+    use super::*;
+    use crate::api::v1::{
+        market_data_request, MarketDataRequest, OperationItem, OperationType, OrderDirection,
+        OrderState, OrderType, PostOrderRequest, SubscribeLastPriceRequest,
+    };
+    use serde_json::json;
+
+    #[test]
+    fn test_post_order_request_json_ok() -> Result<()> {
+        let request = PostOrderRequest {
+            quantity: 10,
+            price: Some(Quotation::new(114, 250_000_000)),
+            direction: OrderDirection::Buy as i32,
+            account_id: "account".to_string(),
+            order_type: OrderType::Limit as i32,
+            instrument_id: "instrument".to_string(),
+            ..Default::default()
+        };
+
+        let value = serde_json::to_value(&request)?;
+
+        assert_eq!(value["quantity"], json!("10"));
+        assert_eq!(value["price"], json!("114.25"));
+        assert_eq!(value["direction"], json!("ORDER_DIRECTION_BUY"));
+        assert_eq!(value["orderType"], json!("ORDER_TYPE_LIMIT"));
+        assert_eq!(value["accountId"], json!("account"));
+
+        let roundtrip: PostOrderRequest = serde_json::from_value(value)?;
+        assert_eq!(roundtrip, request);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_order_state_roundtrip_ok() -> Result<()> {
+        let state = OrderState {
+            order_id: "order".to_string(),
+            lots_requested: 3,
+            total_order_amount: Some(MoneyValue::new("rub", -12, -500_000_000)),
+            order_date: Some(Timestamp {
+                seconds: 1640995200,
+                nanos: 5_000_000,
+            }),
+            ..Default::default()
+        };
+
+        let value = serde_json::to_value(&state)?;
+
+        assert_eq!(value["orderDate"], json!("2022-01-01T00:00:00.005Z"));
+        assert_eq!(
+            value["totalOrderAmount"],
+            json!({"currency": "rub", "value": "-12.5"})
+        );
+
+        let roundtrip: OrderState = serde_json::from_value(value)?;
+        assert_eq!(roundtrip, state);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_oneof_and_lenient_input_ok() -> Result<()> {
+        let request = MarketDataRequest {
+            payload: Some(market_data_request::Payload::SubscribeLastPriceRequest(
+                SubscribeLastPriceRequest::default(),
+            )),
+        };
+
+        let value = serde_json::to_value(&request)?;
+        assert!(value.get("subscribeLastPriceRequest").is_some());
+
+        let roundtrip: MarketDataRequest = serde_json::from_value(value)?;
+        assert_eq!(roundtrip, request);
+
+        // Numbers are accepted for enums and int64, missing fields are defaulted
+        let operation: OperationItem = serde_json::from_value(json!({"type": 15, "quantity": 7}))?;
+
+        assert_eq!(operation.r#type, OperationType::Buy as i32);
+        assert_eq!(operation.quantity, 7);
+        assert_eq!(operation.price, None);
+
+        Ok(())
+    }
+}
+
+// endregion: --- Tests
@@ -0,0 +1,290 @@
+//! Composable interceptors
+//!
+//! `InterceptorStack` calls interceptors in order they were added, so auth, request id,
+//! tracing and user-defined interceptors can be layered without copying each other.
+
+// region:    --- Modules
+
+use super::IntercemptorWithNew;
+use crate::{extensions::MetadataExt, SecretToken, TokenProvider};
+use std::sync::Arc;
+use tonic::metadata::{Ascii, MetadataValue};
+use tonic::{service::Interceptor, GrpcMethod, Request, Status};
+use uuid::Uuid;
+
+// endregion: --- Modules
+
+// region:    --- Interceptor Stack
+
+/// Interceptor calling other interceptors in order
+#[derive(Clone, Default)]
+pub struct InterceptorStack {
+    layers: Vec<Layer>,
+}
+
+#[derive(Clone)]
+struct Layer {
+    name: &'static str,
+    interceptor: Box<dyn CloneInterceptor>,
+}
+
+/// Interceptor that can be cloned with the stack
+trait CloneInterceptor: Interceptor + Send + Sync {
+    fn clone_box(&self) -> Box<dyn CloneInterceptor>;
+}
+
+impl<T: Interceptor + Clone + Send + Sync + 'static> CloneInterceptor for T {
+    fn clone_box(&self) -> Box<dyn CloneInterceptor> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn CloneInterceptor> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+
+impl InterceptorStack {
+    /// Creates empty stack
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates stack with `AuthInterceptor`, `RequestIdInterceptor` and `AppNameInterceptor`,
+    /// used by `TinkoffInterceptor`
+    pub fn tinkoff(token: Arc<dyn TokenProvider>, app_name: Option<String>) -> Self {
+        let stack = Self::new()
+            .with(AuthInterceptor::new(token))
+            .with(RequestIdInterceptor);
+
+        match app_name {
+            Some(app_name) => stack.with(AppNameInterceptor::new(app_name)),
+            None => stack,
+        }
+    }
+
+    /// Adds interceptor to the end of the stack, closures
+    /// `FnMut(Request<()>) -> Result<Request<()>, Status>` are interceptors as well
+    pub fn with<I>(mut self, interceptor: I) -> Self
+    where
+        I: Interceptor + Clone + Send + Sync + 'static,
+    {
+        self.layers.push(Layer {
+            name: std::any::type_name::<I>(),
+            interceptor: Box::new(interceptor),
+        });
+        self
+    }
+
+    /// Returns number of interceptors
+    pub fn len(&self) -> usize {
+        self.layers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+}
+
+impl core::fmt::Debug for InterceptorStack {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list()
+            .entries(self.layers.iter().map(|layer| layer.name))
+            .finish()
+    }
+}
+
+impl Interceptor for InterceptorStack {
+    fn call(&mut self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        for layer in &mut self.layers {
+            request = layer.interceptor.call(request)?;
+        }
+
+        Ok(request)
+    }
+}
+
+/// Allows `generate_client!` to create clients over the stack
+impl IntercemptorWithNew<InterceptorStack> for InterceptorStack {
+    fn new(data: InterceptorStack) -> Self {
+        data
+    }
+}
+
+// endregion: --- Interceptor Stack
+
+// region:    --- Interceptors
+
+/// Sets `authorization` header with token from provider
+#[derive(Debug, Clone)]
+pub struct AuthInterceptor {
+    token: Arc<dyn TokenProvider>,
+}
+
+impl AuthInterceptor {
+    pub fn new(token: Arc<dyn TokenProvider>) -> Self {
+        Self { token }
+    }
+}
+
+impl Interceptor for AuthInterceptor {
+    fn call(&mut self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        let token = self
+            .token
+            .token()
+            .map_err(|err| Status::unauthenticated(format!("Failed to get token: {err}")))?;
+
+        request
+            .metadata_mut()
+            .append("authorization", authorization(&token)?);
+
+        Ok(request)
+    }
+}
+
+/// Sets random `x-request-id`, if it was not set with `RequestExt::with_request_id`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RequestIdInterceptor;
+
+impl Interceptor for RequestIdInterceptor {
+    fn call(&mut self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        let metadata = request.metadata_mut();
+
+        if !metadata.contains_key("x-request-id") {
+            metadata.safe_append(
+                "x-request-id",
+                Uuid::new_v4().to_string(),
+                "Failed to insert request id",
+            );
+        }
+
+        Ok(request)
+    }
+}
+
+/// Sets `x-app-name`, if it was not set for the request
+#[derive(Debug, Clone)]
+pub struct AppNameInterceptor {
+    app_name: String,
+}
+
+impl AppNameInterceptor {
+    pub fn new(app_name: impl Into<String>) -> Self {
+        Self {
+            app_name: app_name.into(),
+        }
+    }
+}
+
+impl Interceptor for AppNameInterceptor {
+    fn call(&mut self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        let metadata = request.metadata_mut();
+
+        if !metadata.contains_key("x-app-name") {
+            metadata.safe_append("x-app-name", &self.app_name, "Failed to insert app name");
+        }
+
+        Ok(request)
+    }
+}
+
+/// Logs each request with `tracing` at debug level
+///
+/// Add it after `RequestIdInterceptor` to log generated request ids.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TracingInterceptor;
+
+impl Interceptor for TracingInterceptor {
+    fn call(&mut self, request: Request<()>) -> Result<Request<()>, Status> {
+        let method = request.extensions().get::<GrpcMethod>();
+        let request_id = request
+            .metadata()
+            .get("x-request-id")
+            .and_then(|value| value.to_str().ok());
+
+        tracing::debug!(
+            service = method.map(|method| method.service()),
+            method = method.map(|method| method.method()),
+            request_id,
+            "gRPC request"
+        );
+
+        Ok(request)
+    }
+}
+
+/// Returns sensitive `authorization` header value, without keeping copies of token besides it
+fn authorization(token: &SecretToken) -> Result<MetadataValue<Ascii>, Status> {
+    let mut value = MetadataValue::try_from(token.bearer().as_str())
+        .map_err(|_| Status::unauthenticated("Token is not a valid header value"))?;
+    value.set_sensitive(true);
+
+    Ok(value)
+}
+
+// endregion: --- Interceptors
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    type Error = Box<dyn std::error::Error>;
+    type Result<T> = core::result::Result<T, Error>; // For tests.
+
+    use super::*;
+    use crate::api::v1::{InterceptorData, TinkoffInterceptor};
+
+    #[test]
+    fn test_interceptor_stack_order_ok() -> Result<()> {
+        let stack = InterceptorStack::tinkoff(Arc::new(SecretToken::new("token")), None)
+            .with(TracingInterceptor)
+            .with(|mut request: Request<()>| {
+                // Sees headers set by previous interceptors
+                let request_id = request.metadata().get("x-request-id").cloned();
+                assert!(request_id.is_some());
+
+                request
+                    .metadata_mut()
+                    .insert("x-custom", "custom".parse().unwrap());
+                Ok(request)
+            });
+
+        assert_eq!(stack.len(), 4);
+
+        let request = stack.clone().call(Request::new(()))?;
+
+        assert_eq!(
+            request.metadata().get("authorization").unwrap(),
+            "Bearer token"
+        );
+        assert_eq!(request.metadata().get("x-custom").unwrap(), "custom");
+        assert!(request.metadata().get("x-app-name").is_none());
+
+        let failing = stack.with(|_| Err(Status::permission_denied("blocked")));
+        assert!(failing.clone().call(Request::new(())).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_tinkoff_interceptor_ok() -> Result<()> {
+        let mut interceptor = TinkoffInterceptor::new(InterceptorData {
+            token: Arc::new(SecretToken::new("token")),
+            app_name: Some("app".to_string()),
+        });
+
+        let request = interceptor.call(Request::new(()))?;
+        let metadata = request.metadata();
+
+        assert_eq!(metadata.get("authorization").unwrap(), "Bearer token");
+        assert!(metadata.get("authorization").unwrap().is_sensitive());
+        assert!(metadata.get("x-request-id").is_some());
+        assert_eq!(metadata.get("x-app-name").unwrap(), "app");
+        assert_eq!(interceptor.into_stack().len(), 3);
+
+        Ok(())
+    }
+}
+
+// endregion: --- Tests
@@ -0,0 +1,189 @@
+//! Bond analytics
+//!
+//! Prices from percents of nominal and accrued interest, cash-flow schedule from coupons and bond
+//! events, yield to maturity, duration and convexity. Amounts are per one bond in its currency,
+//! times are in years of 365 days.
+//!
+//! ```ignore
+//! let schedule = CashFlowSchedule::new(&bond)
+//!     .with_coupons(&coupons.events)
+//!     .with_events(&events.events);
+//! let price = BondPrice::from_bond(&bond, &last_price);
+//!
+//! let metrics = schedule.metrics(&DateTime::now(), price.dirty());
+//! ```
+
+// region:    --- Modules
+
+mod price;
+mod schedule;
+mod yields;
+
+pub use price::BondPrice;
+pub use schedule::{CashFlow, CashFlowKind, CashFlowSchedule};
+pub use yields::BondMetrics;
+
+// endregion: --- Modules
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    type Error = Box<dyn std::error::Error>;
+    type Result<T> = core::result::Result<T, Error>; // For tests.
+
+    use super::*;
+    use crate::api::v1::{
+        get_bond_events_request::EventType, get_bond_events_response::BondEvent, Bond, Coupon,
+        MoneyValue, Quotation,
+    };
+    use crate::DateTime;
+
+    fn date(value: &str) -> Result<DateTime> {
+        Ok(value.parse()?)
+    }
+
+    fn coupon(at: &str, amount: f64) -> Result<Coupon> {
+        Ok(Coupon {
+            coupon_date: Some(date(at)?.timestamp()),
+            pay_one_bond: Some(MoneyValue::from_f64("rub", amount)),
+            ..Default::default()
+        })
+    }
+
+    fn bond(maturity: &str) -> Result<Bond> {
+        Ok(Bond {
+            currency: "rub".to_string(),
+            nominal: Some(MoneyValue::from_f64("rub", 1000.0)),
+            aci_value: Some(MoneyValue::from_f64("rub", 12.5)),
+            maturity_date: Some(date(maturity)?.timestamp()),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_bond_metrics_ok() -> Result<()> {
+        // Two annual coupons of 10%, 365 days apart
+        let schedule = CashFlowSchedule::new(&bond("2024-12-31")?)
+            .with_coupons(&[coupon("2024-01-01", 100.0)?, coupon("2024-12-31", 100.0)?]);
+        let settlement = date("2023-01-01")?;
+
+        let metrics = schedule.metrics(&settlement, 1000.0).ok_or("no metrics")?;
+
+        assert!((metrics.yield_to_maturity - 0.1).abs() < 1e-9);
+        // (100 / 1.1 + 2 * 1100 / 1.21) / 1000
+        assert!((metrics.macaulay_duration - 1.909090909).abs() < 1e-6);
+        assert!((metrics.modified_duration - 1.909090909 / 1.1).abs() < 1e-6);
+        assert!(metrics.convexity > 0.0);
+        assert_eq!(
+            schedule.yield_to_maturity(&date("2025-01-01")?, 1000.0),
+            None
+        );
+
+        let price = BondPrice::from_bond(&bond("2024-12-31")?, &Quotation::from_f64(98.5));
+        assert_eq!(price.clean(), 985.0);
+        assert_eq!(price.dirty(), 997.5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bond_schedule_ok() -> Result<()> {
+        let event = |kind: EventType, at: &str, value: f64| -> Result<BondEvent> {
+            Ok(BondEvent {
+                event_date: Some(date(at)?.timestamp()),
+                value: Some(Quotation::from_f64(value)),
+                ..Default::default()
+            }
+            .with_event_type(kind))
+        };
+
+        let schedule = CashFlowSchedule::new(&bond("2026-01-01")?)
+            .with_coupons(&[
+                coupon("2024-07-01", 50.0)?,
+                coupon("2025-01-01", 0.0)?,
+                coupon("2025-07-01", 0.0)?,
+                coupon("2026-01-01", 0.0)?,
+            ])
+            .with_events(&[
+                // Same coupon as in coupons
+                event(EventType::Cpn, "2024-07-01", 0.0)?,
+                event(EventType::Mty, "2025-01-01", 50.0)?,
+                event(EventType::Mty, "2026-01-01", 50.0)?,
+                event(EventType::Call, "2025-07-01", 100.0)?,
+            ]);
+
+        let kinds: Vec<_> = schedule.flows.iter().map(|flow| flow.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                CashFlowKind::Coupon,
+                CashFlowKind::Coupon,
+                CashFlowKind::Amortization,
+                CashFlowKind::Coupon,
+                CashFlowKind::Offer,
+                CashFlowKind::Coupon,
+                CashFlowKind::Maturity,
+            ]
+        );
+        assert!(schedule.flows[1].estimated);
+        assert_eq!(schedule.flows[1].amount, 50.0);
+        assert_eq!(schedule.outstanding(&date("2025-03-01")?), 500.0);
+
+        let offer = schedule
+            .next_offer(&date("2024-01-01")?)
+            .ok_or("no offer")?;
+        assert_eq!(offer.amount, 500.0);
+
+        let to_offer = schedule.until_offer(offer);
+        assert_eq!(to_offer.flows.len(), 5);
+        assert_eq!(
+            to_offer.flows.last().map(|flow| flow.kind),
+            Some(CashFlowKind::Maturity)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bond_schedule_amortizing_coupons_ok() -> Result<()> {
+        let event = |kind: EventType, at: &str, value: f64, rate: f64| -> Result<BondEvent> {
+            Ok(BondEvent {
+                event_date: Some(date(at)?.timestamp()),
+                value: Some(Quotation::from_f64(value)),
+                coupon_interest_rate: Some(Quotation::from_f64(rate)),
+                coupon_period: 365,
+                ..Default::default()
+            }
+            .with_event_type(kind))
+        };
+
+        // Coupons by rate come before repayments they depend on
+        let schedule = CashFlowSchedule::new(&bond("2026-01-01")?).with_events(&[
+            event(EventType::Cpn, "2025-01-01", 0.0, 10.0)?,
+            event(EventType::Cpn, "2026-01-01", 0.0, 10.0)?,
+            event(EventType::Mty, "2025-01-01", 50.0, 0.0)?,
+            event(EventType::Mty, "2026-01-01", 50.0, 0.0)?,
+        ]);
+
+        let flows: Vec<_> = schedule
+            .flows
+            .iter()
+            .map(|flow| (flow.kind, flow.amount))
+            .collect();
+        assert_eq!(
+            flows,
+            [
+                // Nominal amortized on the same day is still outstanding for coupon
+                (CashFlowKind::Coupon, 100.0),
+                (CashFlowKind::Amortization, 500.0),
+                (CashFlowKind::Coupon, 50.0),
+                (CashFlowKind::Maturity, 500.0),
+            ]
+        );
+
+        Ok(())
+    }
+}
+
+// endregion: --- Tests
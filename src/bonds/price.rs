@@ -0,0 +1,53 @@
+//! Prices quoted in percents of nominal
+
+use crate::api::v1::{AccruedInterest, Bond, Quotation};
+
+/// Price of one bond in its currency
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct BondPrice {
+    /// Quote in percents of nominal
+    pub percent: f64,
+    /// Current nominal, after amortizations
+    pub nominal: f64,
+    /// Accrued coupon income
+    pub aci: f64,
+}
+
+impl BondPrice {
+    pub fn new(percent: f64, nominal: f64, aci: f64) -> Self {
+        Self {
+            percent,
+            nominal,
+            aci,
+        }
+    }
+
+    /// Takes nominal and accrued interest of bond, e.g. quote from `get_last_prices`
+    pub fn from_bond(bond: &Bond, price: &Quotation) -> Self {
+        Self::new(
+            price.to_f64(),
+            bond.nominal
+                .as_ref()
+                .map_or(0.0, |nominal| nominal.to_f64()),
+            bond.aci_value.as_ref().map_or(0.0, |aci| aci.to_f64()),
+        )
+    }
+
+    /// Replaces accrued interest with value from `get_accrued_interests`
+    pub fn with_accrued_interest(mut self, accrued: &AccruedInterest) -> Self {
+        if let Some(value) = &accrued.value {
+            self.aci = value.to_f64();
+        }
+        self
+    }
+
+    /// Price without accrued interest
+    pub fn clean(&self) -> f64 {
+        self.percent * self.nominal / 100.0
+    }
+
+    /// Price paid by buyer, with accrued interest
+    pub fn dirty(&self) -> f64 {
+        self.clean() + self.aci
+    }
+}
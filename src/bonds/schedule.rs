@@ -0,0 +1,311 @@
+//! Future payments of bond from instrument, coupons and bond events
+
+use crate::api::v1::{
+    get_bond_events_request::EventType, get_bond_events_response::BondEvent, Bond, Coupon,
+    MoneyValue, Quotation,
+};
+use crate::{DateTime, TimeZone};
+use prost_types::Timestamp;
+
+const YEAR_DAYS: f64 = 365.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CashFlowKind {
+    Coupon,
+    /// Partial repayment of nominal
+    Amortization,
+    /// Repayment of the rest of nominal
+    Maturity,
+    /// Buyback at holder's option, not included in yield to maturity
+    Offer,
+}
+
+/// Payment per one bond in bond currency
+#[derive(Debug, Clone, PartialEq)]
+pub struct CashFlow {
+    pub date: DateTime,
+    pub kind: CashFlowKind,
+    /// For offers buyback price of outstanding nominal
+    pub amount: f64,
+    /// Coupon is not known yet and equals previous coupon
+    pub estimated: bool,
+}
+
+impl CashFlow {
+    pub fn new(date: DateTime, kind: CashFlowKind, amount: f64) -> Self {
+        Self {
+            date,
+            kind,
+            amount,
+            estimated: false,
+        }
+    }
+
+    fn is_principal(&self) -> bool {
+        matches!(
+            self.kind,
+            CashFlowKind::Amortization | CashFlowKind::Maturity
+        )
+    }
+}
+
+/// Payments of bond sorted by date
+///
+/// ```ignore
+/// let schedule = CashFlowSchedule::new(&bond)
+///     .with_coupons(&coupons.events)
+///     .with_events(&events.events);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CashFlowSchedule {
+    /// Currency of payments, lowercase
+    pub currency: String,
+    /// Initial nominal, amortizations in percents are taken of it
+    pub nominal: f64,
+    pub flows: Vec<CashFlow>,
+}
+
+impl CashFlowSchedule {
+    /// Creates schedule with repayment of current nominal at maturity, perpetual bonds have none
+    pub fn new(bond: &Bond) -> Self {
+        let nominal = amount(&bond.nominal);
+        let mut schedule = Self {
+            currency: bond.currency.to_lowercase(),
+            nominal: bond
+                .initial_nominal
+                .as_ref()
+                .map_or(nominal, MoneyValue::to_f64),
+            flows: Vec::new(),
+        };
+
+        if let Some(maturity) = date(&bond.maturity_date).filter(|_| !bond.perpetual_flag) {
+            schedule
+                .flows
+                .push(CashFlow::new(maturity, CashFlowKind::Maturity, nominal));
+        }
+
+        schedule
+    }
+
+    /// Adds coupons from `get_bond_coupons`, unknown coupons are estimated from previous ones
+    pub fn with_coupons(mut self, coupons: &[Coupon]) -> Self {
+        for coupon in coupons {
+            if let Some(date) = date(&coupon.coupon_date) {
+                self.flows.push(CashFlow::new(
+                    date,
+                    CashFlowKind::Coupon,
+                    amount(&coupon.pay_one_bond),
+                ));
+            }
+        }
+
+        self.normalize();
+        self
+    }
+
+    /// Adds events from `get_bond_events`
+    ///
+    /// Coupons are added for dates without coupon. Repayments replace maturity of `new`, their
+    /// `value` is taken in percents of initial nominal if payment is unknown. Coupons without
+    /// payment are computed by rate of nominal outstanding before repayment of the same day.
+    /// Offers are bought back at `value` percents of outstanding nominal, or at nominal.
+    pub fn with_events(mut self, events: &[BondEvent]) -> Self {
+        let mut repayments = Vec::new();
+        let mut rated = Vec::new();
+
+        for event in events {
+            let Some(date) = date(&event.pay_date).or_else(|| date(&event.event_date)) else {
+                continue;
+            };
+
+            match event.event_type_value().known() {
+                Some(EventType::Cpn) if !self.has_coupon(&date) => {
+                    match amount(&event.pay_one_bond) {
+                        amount if amount > 0.0 => {
+                            self.flows
+                                .push(CashFlow::new(date, CashFlowKind::Coupon, amount))
+                        }
+                        // Computed after repayments are known
+                        _ => rated.push((date, event)),
+                    }
+                }
+                Some(EventType::Mty) => {
+                    let amount = match amount(&event.pay_one_bond) {
+                        amount if amount > 0.0 => amount,
+                        _ => percent(&event.value) / 100.0 * self.nominal,
+                    };
+
+                    if amount > 0.0 {
+                        repayments.push(CashFlow::new(date, CashFlowKind::Amortization, amount));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if !repayments.is_empty() {
+            self.flows.retain(|flow| !flow.is_principal());
+            self.flows.extend(repayments);
+        }
+
+        // Coupon by rate of outstanding nominal for coupon period
+        for (date, event) in rated {
+            if self.has_coupon(&date) {
+                continue;
+            }
+
+            let amount = percent(&event.coupon_interest_rate) / 100.0
+                * self.outstanding_on(&date)
+                * event.coupon_period as f64
+                / YEAR_DAYS;
+
+            self.flows
+                .push(CashFlow::new(date, CashFlowKind::Coupon, amount));
+        }
+        self.normalize();
+
+        // Offers are priced after repayments are known
+        for event in events {
+            if event.event_type_value() != EventType::Call {
+                continue;
+            }
+            let Some(date) = date(&event.event_date).or_else(|| date(&event.pay_date)) else {
+                continue;
+            };
+
+            let outstanding = self.outstanding(&date);
+            let amount = match percent(&event.value) {
+                value if value > 0.0 => value / 100.0 * outstanding,
+                _ => outstanding,
+            };
+
+            self.flows
+                .push(CashFlow::new(date, CashFlowKind::Offer, amount));
+        }
+
+        self.normalize();
+        self
+    }
+
+    /// Returns payments after `at`
+    pub fn after<'a>(&'a self, at: &'a DateTime) -> impl Iterator<Item = &'a CashFlow> {
+        self.flows.iter().filter(move |flow| &flow.date > at)
+    }
+
+    pub fn next_offer(&self, at: &DateTime) -> Option<&CashFlow> {
+        self.flows
+            .iter()
+            .find(|flow| flow.kind == CashFlowKind::Offer && &flow.date > at)
+    }
+
+    /// Returns schedule ending with offer as maturity, for yield to offer
+    pub fn until_offer(&self, offer: &CashFlow) -> Self {
+        let mut flows: Vec<CashFlow> = self
+            .flows
+            .iter()
+            .filter(|flow| {
+                flow.date <= offer.date
+                    && matches!(flow.kind, CashFlowKind::Coupon | CashFlowKind::Amortization)
+            })
+            .cloned()
+            .collect();
+        flows.push(CashFlow::new(
+            offer.date.clone(),
+            CashFlowKind::Maturity,
+            offer.amount,
+        ));
+
+        let mut schedule = Self {
+            currency: self.currency.clone(),
+            nominal: self.nominal,
+            flows,
+        };
+        schedule.normalize();
+
+        schedule
+    }
+
+    /// Nominal repaid after `at`
+    pub fn outstanding(&self, at: &DateTime) -> f64 {
+        self.after(at)
+            .filter(|flow| flow.is_principal())
+            .map(|flow| flow.amount)
+            .sum()
+    }
+
+    /// Nominal repaid on day of `date` or later, coupon of that day is paid on it
+    fn outstanding_on(&self, date: &DateTime) -> f64 {
+        let day = date.truncate_day(TimeZone::Utc);
+
+        self.flows
+            .iter()
+            .filter(|flow| flow.is_principal() && flow.date.truncate_day(TimeZone::Utc) >= day)
+            .map(|flow| flow.amount)
+            .sum()
+    }
+
+    fn has_coupon(&self, date: &DateTime) -> bool {
+        let day = date.truncate_day(TimeZone::Utc);
+
+        self.flows.iter().any(|flow| {
+            flow.kind == CashFlowKind::Coupon && flow.date.truncate_day(TimeZone::Utc) == day
+        })
+    }
+
+    /// Sorts flows, removes duplicate coupons, marks last repayment as maturity and estimates coupons
+    fn normalize(&mut self) {
+        self.flows
+            .sort_by(|left, right| (&left.date, left.kind).cmp(&(&right.date, right.kind)));
+
+        // Known coupon is kept for the same day
+        self.flows.dedup_by(|next, kept| {
+            let same = next.kind == CashFlowKind::Coupon
+                && kept.kind == CashFlowKind::Coupon
+                && next.date.truncate_day(TimeZone::Utc) == kept.date.truncate_day(TimeZone::Utc);
+            if same && kept.amount <= 0.0 {
+                kept.amount = next.amount;
+            }
+            same
+        });
+
+        for flow in self.flows.iter_mut().filter(|flow| flow.is_principal()) {
+            flow.kind = CashFlowKind::Amortization;
+        }
+        if let Some(last) = self.flows.iter_mut().rev().find(|flow| flow.is_principal()) {
+            last.kind = CashFlowKind::Maturity;
+        }
+
+        let mut previous = None;
+        for flow in self
+            .flows
+            .iter_mut()
+            .filter(|flow| flow.kind == CashFlowKind::Coupon)
+        {
+            if flow.amount > 0.0 && !flow.estimated {
+                previous = Some(flow.amount);
+            } else if let Some(amount) = previous {
+                flow.amount = amount;
+                flow.estimated = true;
+            }
+        }
+    }
+}
+
+// region:    --- Helpers
+
+fn date(value: &Option<Timestamp>) -> Option<DateTime> {
+    value
+        .as_ref()
+        .filter(|timestamp| timestamp.seconds > 0)
+        .map(|timestamp| DateTime::from_timestamp(*timestamp))
+}
+
+fn amount(value: &Option<MoneyValue>) -> f64 {
+    value.as_ref().map_or(0.0, MoneyValue::to_f64)
+}
+
+fn percent(value: &Option<Quotation>) -> f64 {
+    value.as_ref().map_or(0.0, Quotation::to_f64)
+}
+
+// endregion: --- Helpers
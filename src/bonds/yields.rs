@@ -0,0 +1,98 @@
+//! Yield, duration and convexity of cash flows
+
+use super::{CashFlowKind, CashFlowSchedule};
+use crate::DateTime;
+
+const YEAR_SECONDS: f64 = 365.0 * 86400.0;
+/// Yields are searched between -99% and 1000%
+const MIN_YIELD: f64 = -0.99;
+const MAX_YIELD: f64 = 10.0;
+
+/// Risk measures at yield to maturity, times are in years
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct BondMetrics {
+    pub dirty_price: f64,
+    /// Effective annual yield
+    pub yield_to_maturity: f64,
+    pub macaulay_duration: f64,
+    /// Relative price change for change of yield, `macaulay_duration / (1 + yield)`
+    pub modified_duration: f64,
+    pub convexity: f64,
+}
+
+impl CashFlowSchedule {
+    /// Returns present value of payments after settlement at effective annual `rate`
+    pub fn present_value(&self, settlement: &DateTime, rate: f64) -> f64 {
+        self.times(settlement)
+            .map(|(time, amount)| amount / (1.0 + rate).powf(time))
+            .sum()
+    }
+
+    /// Returns effective annual yield, at which present value of payments equals dirty price
+    ///
+    /// Offers are skipped, use `until_offer` for yield to offer.
+    pub fn yield_to_maturity(&self, settlement: &DateTime, dirty_price: f64) -> Option<f64> {
+        if dirty_price <= 0.0 || self.times(settlement).next().is_none() {
+            return None;
+        }
+
+        // Present value decreases with rate, so root is found by bisection
+        let excess = |rate| self.present_value(settlement, rate) - dirty_price;
+        let (mut low, mut high) = (MIN_YIELD, MAX_YIELD);
+        if excess(low) < 0.0 || excess(high) > 0.0 {
+            return None;
+        }
+
+        for _ in 0..200 {
+            let middle = (low + high) / 2.0;
+            if excess(middle) > 0.0 {
+                low = middle;
+            } else {
+                high = middle;
+            }
+            if high - low < 1e-12 {
+                break;
+            }
+        }
+
+        Some((low + high) / 2.0)
+    }
+
+    /// Returns yield to maturity, durations and convexity for dirty price
+    pub fn metrics(&self, settlement: &DateTime, dirty_price: f64) -> Option<BondMetrics> {
+        let rate = self.yield_to_maturity(settlement, dirty_price)?;
+
+        let (mut value, mut weighted, mut convex) = (0.0, 0.0, 0.0);
+        for (time, amount) in self.times(settlement) {
+            let present = amount / (1.0 + rate).powf(time);
+            value += present;
+            weighted += time * present;
+            convex += time * (time + 1.0) * present;
+        }
+
+        let macaulay_duration = weighted / value;
+
+        Some(BondMetrics {
+            dirty_price,
+            yield_to_maturity: rate,
+            macaulay_duration,
+            modified_duration: macaulay_duration / (1.0 + rate),
+            convexity: convex / (value * (1.0 + rate).powi(2)),
+        })
+    }
+
+    /// Years from settlement and amounts of payments included in yield to maturity
+    fn times<'a>(&'a self, settlement: &'a DateTime) -> impl Iterator<Item = (f64, f64)> + 'a {
+        let start = settlement.timestamp();
+
+        self.after(settlement)
+            .filter(|flow| flow.kind != CashFlowKind::Offer && flow.amount > 0.0)
+            .map(move |flow| {
+                let at = flow.date.timestamp();
+                let seconds =
+                    (at.seconds - start.seconds) as f64 + (at.nanos - start.nanos) as f64 / 1e9;
+
+                (seconds / YEAR_SECONDS, flow.amount)
+            })
+    }
+}
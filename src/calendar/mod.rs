@@ -0,0 +1,370 @@
+//! Exchange trading calendar
+//!
+//! Caches `TradingDay` records from `InstrumentsService/TradingSchedules` per exchange and
+//! answers which session is running and when the next one opens or closes.
+
+// region:    --- Modules
+
+use crate::api::v1::{
+    IntercemptorWithNew, InvestApiTrait, TradingDay, TradingSchedulesRequest,
+    TradingSchedulesResponse,
+};
+use crate::{DateTime, Result};
+use prost_types::Timestamp;
+use std::collections::{BTreeMap, HashMap};
+use std::time::Duration;
+use tonic::transport::Channel;
+
+// endregion: --- Modules
+
+const DAY_SECONDS: i64 = 86400;
+
+/// Trading session of exchange
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Session {
+    Premarket,
+    OpeningAuction,
+    Main,
+    ClosingAuction,
+    Clearing,
+    EveningOpeningAuction,
+    Evening,
+    Closed,
+}
+
+impl Session {
+    /// Returns `true` if orders are executed or collected for auction
+    pub fn is_open(&self) -> bool {
+        !matches!(self, Self::Clearing | Self::Closed)
+    }
+}
+
+// region:    --- Trading Calendar
+
+/// Cache of trading schedules per exchange, e.g. `MOEX`
+#[derive(Debug, Clone, Default)]
+pub struct TradingCalendar {
+    /// Days by exchange and start of the day in seconds
+    exchanges: HashMap<String, BTreeMap<i64, TradingDay>>,
+}
+
+impl TradingCalendar {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates calendar from schedules response
+    pub fn from_response(response: TradingSchedulesResponse) -> Self {
+        let mut calendar = Self::new();
+        calendar.insert(response);
+
+        calendar
+    }
+
+    /// Adds schedules to cache, replacing cached days
+    pub fn insert(&mut self, response: TradingSchedulesResponse) {
+        for schedule in response.exchanges {
+            let days = self.exchanges.entry(schedule.exchange).or_default();
+
+            for day in schedule.days {
+                if let Some(date) = day.date {
+                    days.insert(date.seconds, day);
+                }
+            }
+        }
+    }
+
+    /// Requests schedules for period and adds them to cache, all exchanges if `exchange` is `None`
+    pub async fn fetch<D, I>(
+        &mut self,
+        api: &impl InvestApiTrait<D, I>,
+        channel: &Channel,
+        exchange: Option<&str>,
+        from: &DateTime,
+        to: &DateTime,
+    ) -> Result<()>
+    where
+        I: IntercemptorWithNew<D>,
+    {
+        let request = TradingSchedulesRequest {
+            exchange: exchange.map(str::to_string),
+            from: Some(from.timestamp()),
+            to: Some(to.timestamp()),
+        };
+
+        let response = api
+            .instruments(channel)?
+            .trading_schedules(request)
+            .await?
+            .into_inner();
+
+        self.insert(response);
+
+        Ok(())
+    }
+
+    /// Returns cached exchanges
+    pub fn exchanges(&self) -> impl Iterator<Item = &str> {
+        self.exchanges.keys().map(String::as_str)
+    }
+
+    /// Returns cached day containing `at`, date is taken in UTC as in schedules
+    pub fn trading_day(&self, exchange: &str, at: &DateTime) -> Option<&TradingDay> {
+        let seconds = at.timestamp().seconds;
+        let start = seconds - seconds.rem_euclid(DAY_SECONDS);
+
+        self.exchanges.get(exchange)?.get(&start)
+    }
+
+    /// Returns `None` if day is not cached
+    pub fn is_trading_day(&self, exchange: &str, at: &DateTime) -> Option<bool> {
+        self.trading_day(exchange, at).map(|day| day.is_trading_day)
+    }
+
+    /// Returns session running at `at`, `Closed` if schedule is not cached
+    ///
+    /// Sessions include start and exclude end, so at the end of one the next one is running.
+    pub fn current_session(&self, exchange: &str, at: &DateTime) -> Session {
+        let at = at.timestamp();
+
+        self.days_around(exchange, &at)
+            .filter(|day| day.is_trading_day)
+            .flat_map(sessions)
+            .find(|(_, start, end)| is_before(start, &at) && !is_before(end, &at))
+            .map(|(session, _, _)| session)
+            .unwrap_or(Session::Closed)
+    }
+
+    /// Returns time when trading opens after `at`, if it is cached
+    pub fn next_open(&self, exchange: &str, at: &DateTime) -> Option<DateTime> {
+        self.boundaries(exchange, at, |(session, start, _)| {
+            session.is_open().then_some(start)
+        })
+        .find(|start| {
+            let before = DateTime::from_timestamp(*start).add_seconds(-1);
+            !self.current_session(exchange, &before).is_open()
+        })
+        .map(DateTime::from_timestamp)
+    }
+
+    /// Returns time when trading closes after `at`, if it is cached
+    pub fn next_close(&self, exchange: &str, at: &DateTime) -> Option<DateTime> {
+        self.boundaries(exchange, at, |(session, _, end)| {
+            session.is_open().then_some(end)
+        })
+        .find(|end| {
+            !self
+                .current_session(exchange, &DateTime::from_timestamp(*end))
+                .is_open()
+        })
+        .map(DateTime::from_timestamp)
+    }
+
+    /// Waits until `at`, e.g. boundary from `next_open` or `next_close`
+    pub async fn wait_until(at: &DateTime) {
+        let now = DateTime::now().timestamp();
+        let at = at.timestamp();
+
+        let nanos =
+            (at.seconds - now.seconds) as i128 * 1_000_000_000 + (at.nanos - now.nanos) as i128;
+
+        if nanos > 0 {
+            tokio::time::sleep(Duration::from_nanos(nanos as u64)).await;
+        }
+    }
+
+    /// Days that may contain sessions running at `at`, evening sessions may end after midnight
+    fn days_around<'a>(
+        &'a self,
+        exchange: &str,
+        at: &Timestamp,
+    ) -> impl Iterator<Item = &'a TradingDay> {
+        let start = at.seconds - at.seconds.rem_euclid(DAY_SECONDS);
+
+        self.exchanges
+            .get(exchange)
+            .into_iter()
+            .flat_map(move |days| days.range(start - DAY_SECONDS..=start).map(|(_, day)| day))
+    }
+
+    /// Session timestamps picked by `pick` after `at`, sorted
+    fn boundaries(
+        &self,
+        exchange: &str,
+        at: &DateTime,
+        pick: impl Fn((Session, Timestamp, Timestamp)) -> Option<Timestamp>,
+    ) -> impl Iterator<Item = Timestamp> {
+        let at = at.timestamp();
+        let start = at.seconds - at.seconds.rem_euclid(DAY_SECONDS) - DAY_SECONDS;
+
+        let mut boundaries: Vec<_> = self
+            .exchanges
+            .get(exchange)
+            .into_iter()
+            .flat_map(|days| days.range(start..).map(|(_, day)| day))
+            .filter(|day| day.is_trading_day)
+            .flat_map(sessions)
+            .filter_map(pick)
+            .filter(|boundary| is_before(&at, boundary) && at != *boundary)
+            .collect();
+
+        boundaries.sort_by_key(|boundary| (boundary.seconds, boundary.nanos));
+        boundaries.into_iter()
+    }
+}
+
+// endregion: --- Trading Calendar
+
+// region:    --- Helpers
+
+/// Sessions of the day with start and end, auctions go before sessions containing them
+fn sessions(day: &TradingDay) -> Vec<(Session, Timestamp, Timestamp)> {
+    let main_end = day.closing_auction_start_time.or(day.end_time);
+
+    [
+        (
+            Session::Premarket,
+            day.premarket_start_time,
+            day.premarket_end_time,
+        ),
+        (
+            Session::OpeningAuction,
+            day.opening_auction_start_time,
+            day.opening_auction_end_time.or(day.start_time),
+        ),
+        (
+            Session::ClosingAuction,
+            day.closing_auction_start_time,
+            day.closing_auction_end_time,
+        ),
+        (Session::Main, day.start_time, main_end),
+        (
+            Session::Clearing,
+            day.clearing_start_time,
+            day.clearing_end_time,
+        ),
+        (
+            Session::EveningOpeningAuction,
+            day.evening_opening_auction_start_time,
+            day.evening_start_time,
+        ),
+        (
+            Session::Evening,
+            day.evening_start_time,
+            day.evening_end_time,
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(session, start, end)| Some((session, start?, end?)))
+    .filter(|(_, start, end)| is_before(start, end) && start != end)
+    .collect()
+}
+
+/// Returns `true` if `left` is before or equal to `right`
+fn is_before(left: &Timestamp, right: &Timestamp) -> bool {
+    (left.seconds, left.nanos) <= (right.seconds, right.nanos)
+}
+
+// endregion: --- Helpers
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    type Error = Box<dyn std::error::Error>;
+    type Result<T> = core::result::Result<T, Error>; // For tests.
+
+    use super::*;
+    use crate::api::v1::TradingSchedule;
+
+    /// 2022-01-03, Monday
+    const DAY: i64 = 1641168000;
+
+    fn at(hours: i64, minutes: i64) -> DateTime {
+        DateTime::from_timestamp(time(hours, minutes))
+    }
+
+    fn time(hours: i64, minutes: i64) -> Timestamp {
+        Timestamp {
+            seconds: DAY + hours * 3600 + minutes * 60,
+            nanos: 0,
+        }
+    }
+
+    fn calendar() -> TradingCalendar {
+        // Times in UTC, as in MOEX schedule
+        let trading_day = TradingDay {
+            date: Some(time(0, 0)),
+            is_trading_day: true,
+            opening_auction_start_time: Some(time(6, 50)),
+            start_time: Some(time(7, 0)),
+            closing_auction_start_time: Some(time(15, 40)),
+            closing_auction_end_time: Some(time(15, 50)),
+            end_time: Some(time(15, 50)),
+            clearing_start_time: Some(time(15, 50)),
+            clearing_end_time: Some(time(16, 5)),
+            evening_start_time: Some(time(16, 5)),
+            evening_end_time: Some(time(20, 50)),
+            ..Default::default()
+        };
+        let weekend = TradingDay {
+            date: Some(time(24, 0)),
+            is_trading_day: false,
+            ..Default::default()
+        };
+
+        TradingCalendar::from_response(TradingSchedulesResponse {
+            exchanges: vec![TradingSchedule {
+                exchange: "MOEX".to_string(),
+                days: vec![trading_day, weekend],
+            }],
+        })
+    }
+
+    #[test]
+    fn test_calendar_sessions_ok() -> Result<()> {
+        let calendar = calendar();
+
+        assert_eq!(calendar.current_session("MOEX", &at(6, 0)), Session::Closed);
+        assert_eq!(
+            calendar.current_session("MOEX", &at(6, 55)),
+            Session::OpeningAuction
+        );
+        assert_eq!(calendar.current_session("MOEX", &at(12, 0)), Session::Main);
+        assert_eq!(
+            calendar.current_session("MOEX", &at(15, 45)),
+            Session::ClosingAuction
+        );
+        assert_eq!(
+            calendar.current_session("MOEX", &at(16, 0)),
+            Session::Clearing
+        );
+        assert_eq!(
+            calendar.current_session("MOEX", &at(18, 0)),
+            Session::Evening
+        );
+        assert_eq!(calendar.current_session("SPB", &at(12, 0)), Session::Closed);
+
+        assert_eq!(calendar.is_trading_day("MOEX", &at(12, 0)), Some(true));
+        assert_eq!(calendar.is_trading_day("MOEX", &at(36, 0)), Some(false));
+        assert_eq!(calendar.is_trading_day("MOEX", &at(60, 0)), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_calendar_next_boundaries_ok() -> Result<()> {
+        let calendar = calendar();
+
+        assert_eq!(calendar.next_open("MOEX", &at(5, 0)), Some(at(6, 50)));
+        // Evening session opens after clearing
+        assert_eq!(calendar.next_open("MOEX", &at(12, 0)), Some(at(16, 5)));
+        assert_eq!(calendar.next_close("MOEX", &at(12, 0)), Some(at(15, 50)));
+        assert_eq!(calendar.next_close("MOEX", &at(17, 0)), Some(at(20, 50)));
+        assert_eq!(calendar.next_open("MOEX", &at(21, 0)), None);
+
+        Ok(())
+    }
+}
+
+// endregion: --- Tests
@@ -0,0 +1,246 @@
+//! Tower layer counting requests in flight on channel
+
+// region:    --- Modules
+
+use http_body::{Body, Frame, SizeHint};
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    task::{Context, Poll},
+};
+use tower_layer::Layer;
+use tower_service::Service;
+
+// endregion: --- Modules
+
+// region:    --- Layer
+
+/// Wraps service, e.g. `Channel`, into `InFlightService` sharing counter of this layer
+#[derive(Debug, Clone, Default)]
+pub struct InFlightLayer {
+    in_flight: Arc<AtomicUsize>,
+}
+
+impl InFlightLayer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns number of requests in flight on services of this layer
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.load(Ordering::Relaxed)
+    }
+}
+
+impl<S> Layer<S> for InFlightLayer {
+    type Service = InFlightService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        InFlightService {
+            inner,
+            in_flight: self.in_flight.clone(),
+        }
+    }
+}
+
+// endregion: --- Layer
+
+// region:    --- Service
+
+/// Service counting request from call until its response body ends or is dropped
+///
+/// Streams are counted while they are open.
+#[derive(Debug, Clone)]
+pub struct InFlightService<S> {
+    inner: S,
+    in_flight: Arc<AtomicUsize>,
+}
+
+impl<S> InFlightService<S> {
+    /// Returns number of requests in flight, including ones on clones of this service
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.load(Ordering::Relaxed)
+    }
+
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S, ReqBody, ResBody> Service<http::Request<ReqBody>> for InFlightService<S>
+where
+    S: Service<http::Request<ReqBody>, Response = http::Response<ResBody>>,
+    S::Future: Send + 'static,
+{
+    type Response = http::Response<InFlightBody<ResBody>>;
+    type Error = S::Error;
+    type Future =
+        Pin<Box<dyn Future<Output = core::result::Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<core::result::Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: http::Request<ReqBody>) -> Self::Future {
+        // Released with future on error or cancellation
+        let guard = InFlightGuard::new(self.in_flight.clone());
+        let future = self.inner.call(request);
+
+        Box::pin(async move {
+            let response = future.await?;
+
+            Ok(response.map(|body| InFlightBody {
+                inner: body,
+                guard: Some(guard),
+            }))
+        })
+    }
+}
+
+// endregion: --- Service
+
+// region:    --- Body
+
+/// Response body keeping request counted until it ends
+///
+/// Default body, required by intercepted clients, is not counted.
+#[derive(Debug, Default)]
+pub struct InFlightBody<B> {
+    inner: B,
+    guard: Option<InFlightGuard>,
+}
+
+impl<B: Body + Unpin> Body for InFlightBody<B> {
+    type Data = B::Data;
+    type Error = B::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<core::result::Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.get_mut();
+        let poll = Pin::new(&mut this.inner).poll_frame(cx);
+
+        if let Poll::Ready(None) = poll {
+            this.guard.take();
+        }
+
+        poll
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+/// Decrements counter on drop
+#[derive(Debug)]
+struct InFlightGuard(Arc<AtomicUsize>);
+
+impl InFlightGuard {
+    fn new(in_flight: Arc<AtomicUsize>) -> Self {
+        in_flight.fetch_add(1, Ordering::Relaxed);
+        Self(in_flight)
+    }
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+// endregion: --- Body
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    type Error = Box<dyn std::error::Error>;
+    type Result<T> = core::result::Result<T, Error>; // For tests.
+
+    use super::*;
+    use tonic::body::BoxBody;
+
+    #[derive(Clone)]
+    struct Stub {
+        fail: bool,
+    }
+
+    impl Service<http::Request<BoxBody>> for Stub {
+        type Response = http::Response<BoxBody>;
+        type Error = tonic::Status;
+        type Future = std::future::Ready<core::result::Result<Self::Response, Self::Error>>;
+
+        fn poll_ready(
+            &mut self,
+            _: &mut Context<'_>,
+        ) -> Poll<core::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, _: http::Request<BoxBody>) -> Self::Future {
+            std::future::ready(match self.fail {
+                true => Err(tonic::Status::unavailable("down")),
+                false => Ok(http::Response::new(tonic::body::empty_body())),
+            })
+        }
+    }
+
+    #[test]
+    fn test_in_flight_service_counts_ok() -> Result<()> {
+        let runtime = tokio::runtime::Runtime::new()?;
+        let layer = InFlightLayer::new();
+        let mut service = layer.layer(Stub { fail: false });
+        let mut failing = layer.layer(Stub { fail: true });
+        let request = || http::Request::new(tonic::body::empty_body());
+
+        let pending = service.call(request());
+        assert_eq!(layer.in_flight(), 1);
+
+        // Counted until body is read to the end
+        let mut response = runtime.block_on(pending)?;
+        assert_eq!(service.in_flight(), 1);
+        let frame = runtime.block_on(std::future::poll_fn(|cx| {
+            Pin::new(response.body_mut()).poll_frame(cx)
+        }));
+        assert!(frame.is_none());
+        assert_eq!(layer.in_flight(), 0);
+
+        // Dropped response and failed request are released
+        let response = runtime.block_on(service.call(request()))?;
+        assert_eq!(layer.in_flight(), 1);
+        drop(response);
+        assert!(runtime.block_on(failing.call(request())).is_err());
+        assert_eq!(layer.in_flight(), 0);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_in_flight_service_intercepted_ok() -> Result<()> {
+        use crate::api::v1::{InvestApi, InvestApiTrait};
+
+        let runtime = tokio::runtime::Runtime::new()?;
+        let layer = InFlightLayer::new();
+        let api = InvestApi::with_token("token");
+
+        // Empty response without grpc status fails the call and releases the request
+        let result = runtime.block_on(api.inspect_token(layer.layer(Stub { fail: false })));
+        assert!(result.is_err());
+        assert_eq!(layer.in_flight(), 0);
+
+        Ok(())
+    }
+}
+
+// endregion: --- Tests
@@ -0,0 +1,340 @@
+#![allow(clippy::result_large_err)]
+
+// region:    --- Modules
+
+mod in_flight;
+mod pool;
+mod proxy;
+
+pub use in_flight::{InFlightBody, InFlightLayer, InFlightService};
+pub use pool::{Balance, ChannelPool, InFlightChannel, PooledChannel};
+pub use proxy::{Proxy, ProxyAuth, ProxyConnector, ProxyKind};
+
+use crate::{config, ApiConfig, Error, Profile, Result};
+use std::{path::Path, time::Duration};
+use tonic::transport::{Certificate, Channel, ClientTlsConfig, Endpoint, Identity, Uri};
+
+// endregion: --- Modules
+
+/// Channel builder
+///
+/// Settings are applied to `Endpoint` when channel is created, so they can be set in any order.
+#[derive(Debug, Clone)]
+pub struct ChannelBuilder {
+    uri: Uri,
+    tls: Option<ClientTlsConfig>,
+    proxy: Option<Proxy>,
+    connect_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    tcp_keepalive: Option<Duration>,
+    http2_keep_alive_interval: Option<Duration>,
+    keep_alive_timeout: Option<Duration>,
+    keep_alive_while_idle: Option<bool>,
+    initial_stream_window_size: Option<u32>,
+    initial_connection_window_size: Option<u32>,
+    concurrency_limit: Option<usize>,
+    rate_limit: Option<(u64, Duration)>,
+}
+
+impl ChannelBuilder {
+    /// Creates channel builder with default tls config, endpoint of active profile and timeouts from global config
+    #[allow(clippy::should_implement_trait)]
+    pub fn default() -> Result<Self> {
+        Self::from_config(config()?)
+    }
+
+    /// Creates channel builder with default tls config, endpoint of active profile and timeouts from config
+    pub fn from_config(config: &ApiConfig) -> Result<Self> {
+        let mut builder = Self::from_profile(&config.active_profile())?;
+        builder.connect_timeout = config.connect_timeout;
+        builder.timeout = config.request_timeout;
+        builder.rate_limit = config
+            .rate_limit
+            .requests_per_minute
+            .map(|limit| (limit.into(), Duration::from_secs(60)));
+
+        Ok(builder)
+    }
+
+    /// Creates channel builder with default tls config and endpoint of profile
+    pub fn from_profile(profile: &Profile) -> Result<Self> {
+        let tls = ClientTlsConfig::new().with_native_roots();
+
+        Self::new(profile.endpoint.clone())?.tls(tls)
+    }
+
+    /// Creates channel builder without tls
+    pub fn new(url: impl Into<String>) -> Result<Self> {
+        Ok(Self::from_uri(parse_uri(url)?))
+    }
+
+    /// Creates channel builder without tls
+    pub fn from_uri(uri: Uri) -> Self {
+        Self {
+            uri,
+            tls: None,
+            proxy: None,
+            connect_timeout: None,
+            timeout: None,
+            tcp_keepalive: None,
+            http2_keep_alive_interval: None,
+            keep_alive_timeout: None,
+            keep_alive_while_idle: None,
+            initial_stream_window_size: None,
+            initial_connection_window_size: None,
+            concurrency_limit: None,
+            rate_limit: None,
+        }
+    }
+
+    /// Sets channel link
+    pub fn url(mut self, url: impl Into<String>) -> Result<Self> {
+        self.uri = parse_uri(url)?;
+        Ok(self)
+    }
+
+    /// Sets channel link
+    pub fn uri(mut self, uri: Uri) -> Self {
+        self.uri = uri;
+        self
+    }
+
+    /// Sets tls config
+    pub fn tls(mut self, tls: ClientTlsConfig) -> Result<Self> {
+        // Fail early on invalid tls config
+        Endpoint::from(self.uri.clone()).tls_config(tls.clone())?;
+
+        self.tls = Some(tls);
+        Ok(self)
+    }
+
+    // region:    --- Tls Settings
+
+    /// Adds CA certificates from PEM file to trusted ones
+    ///
+    /// Native roots are trusted as well, unless tls config was set with `tls`.
+    pub fn ca_certificate_file(self, path: impl AsRef<Path>) -> Result<Self> {
+        let pem = read_pem(path.as_ref())?;
+
+        Ok(self.ca_certificate_pem(pem))
+    }
+
+    /// Adds CA certificates in PEM format to trusted ones
+    pub fn ca_certificate_pem(mut self, pem: impl AsRef<[u8]>) -> Self {
+        let tls = self.take_tls().ca_certificate(Certificate::from_pem(pem));
+
+        self.tls = Some(tls);
+        self
+    }
+
+    /// Sets client certificate and private key from PEM files
+    pub fn identity_files(self, cert: impl AsRef<Path>, key: impl AsRef<Path>) -> Result<Self> {
+        let identity = Identity::from_pem(read_pem(cert.as_ref())?, read_pem(key.as_ref())?);
+
+        Ok(self.identity(identity))
+    }
+
+    /// Sets client identity
+    pub fn identity(mut self, identity: Identity) -> Self {
+        let tls = self.take_tls().identity(identity);
+
+        self.tls = Some(tls);
+        self
+    }
+
+    /// Overrides server name used for SNI and certificate verification
+    pub fn tls_domain_name(mut self, domain_name: impl Into<String>) -> Self {
+        let tls = self.take_tls().domain_name(domain_name);
+
+        self.tls = Some(tls);
+        self
+    }
+
+    /// Returns current tls config or default one with native roots
+    fn take_tls(&mut self) -> ClientTlsConfig {
+        self.tls
+            .take()
+            .unwrap_or_else(|| ClientTlsConfig::new().with_native_roots())
+    }
+
+    // endregion: --- Tls Settings
+
+    /// Sets proxy for connections, e.g. `Proxy::from_url("http://proxy:3128")?`
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    // region:    --- Endpoint Settings
+
+    /// Sets timeout for connecting
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets timeout for each request
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets TCP keepalive, `None` to disable
+    pub fn tcp_keepalive(mut self, keepalive: Option<Duration>) -> Self {
+        self.tcp_keepalive = keepalive;
+        self
+    }
+
+    /// Sets interval of HTTP/2 keepalive pings
+    pub fn http2_keep_alive_interval(mut self, interval: Duration) -> Self {
+        self.http2_keep_alive_interval = Some(interval);
+        self
+    }
+
+    /// Sets timeout for HTTP/2 keepalive ping acknowledgement
+    pub fn keep_alive_timeout(mut self, timeout: Duration) -> Self {
+        self.keep_alive_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets whether HTTP/2 keepalive pings are sent without active streams
+    pub fn keep_alive_while_idle(mut self, enabled: bool) -> Self {
+        self.keep_alive_while_idle = Some(enabled);
+        self
+    }
+
+    /// Sets HTTP/2 stream-level flow control window size
+    pub fn initial_stream_window_size(mut self, size: u32) -> Self {
+        self.initial_stream_window_size = Some(size);
+        self
+    }
+
+    /// Sets HTTP/2 connection-level flow control window size
+    pub fn initial_connection_window_size(mut self, size: u32) -> Self {
+        self.initial_connection_window_size = Some(size);
+        self
+    }
+
+    /// Sets maximum number of concurrent requests on channel
+    pub fn concurrency_limit(mut self, limit: usize) -> Self {
+        self.concurrency_limit = Some(limit);
+        self
+    }
+
+    /// Sets maximum number of requests on channel per period
+    pub fn rate_limit(mut self, limit: u64, period: Duration) -> Self {
+        self.rate_limit = Some((limit, period));
+        self
+    }
+
+    // endregion: --- Endpoint Settings
+
+    /// Creates endpoint with specified parameters
+    pub fn endpoint(&self) -> Result<Endpoint> {
+        let mut endpoint = Endpoint::from(self.uri.clone())
+            .tcp_keepalive(self.tcp_keepalive)
+            .initial_stream_window_size(self.initial_stream_window_size)
+            .initial_connection_window_size(self.initial_connection_window_size);
+
+        if let Some(tls) = &self.tls {
+            endpoint = endpoint.tls_config(tls.clone())?;
+        }
+        if let Some(timeout) = self.connect_timeout {
+            endpoint = endpoint.connect_timeout(timeout);
+        }
+        if let Some(timeout) = self.timeout {
+            endpoint = endpoint.timeout(timeout);
+        }
+        if let Some(interval) = self.http2_keep_alive_interval {
+            endpoint = endpoint.http2_keep_alive_interval(interval);
+        }
+        if let Some(timeout) = self.keep_alive_timeout {
+            endpoint = endpoint.keep_alive_timeout(timeout);
+        }
+        if let Some(enabled) = self.keep_alive_while_idle {
+            endpoint = endpoint.keep_alive_while_idle(enabled);
+        }
+        if let Some(limit) = self.concurrency_limit {
+            endpoint = endpoint.concurrency_limit(limit);
+        }
+        if let Some((limit, period)) = self.rate_limit {
+            endpoint = endpoint.rate_limit(limit, period);
+        }
+
+        Ok(endpoint)
+    }
+
+    /// Creates connection with specified parameters
+    pub async fn connect(self) -> Result<Channel> {
+        let endpoint = self.endpoint()?;
+
+        Ok(match self.proxy_connector() {
+            Some(connector) => endpoint.connect_with_connector(connector).await?,
+            None => endpoint.connect().await?,
+        })
+    }
+
+    /// Creates channel, that connects on first request
+    pub fn connect_lazy(self) -> Result<Channel> {
+        let endpoint = self.endpoint()?;
+
+        Ok(match self.proxy_connector() {
+            Some(connector) => endpoint.connect_with_connector_lazy(connector),
+            None => endpoint.connect_lazy(),
+        })
+    }
+
+    /// Returns connector for proxy, endpoint TCP settings are not used with custom connector
+    fn proxy_connector(&self) -> Option<ProxyConnector> {
+        let mut proxy = self.proxy.clone()?;
+        proxy.connect_timeout = proxy.connect_timeout.or(self.connect_timeout);
+        proxy.tcp_keepalive = proxy.tcp_keepalive.or(self.tcp_keepalive);
+
+        Some(ProxyConnector::new(proxy))
+    }
+}
+
+// region:    --- Helpers
+
+fn read_pem(path: &Path) -> Result<Vec<u8>> {
+    std::fs::read(path).map_err(|_| Error::CertificateFileNotFound(path.display().to_string()))
+}
+
+fn parse_uri(url: impl Into<String>) -> Result<Uri> {
+    let url = url.into();
+
+    Uri::try_from(url.as_str()).map_err(|_| Error::InvalidUrl(url))
+}
+
+// endregion: --- Helpers
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    type Error = Box<dyn std::error::Error>;
+    type Result<T> = core::result::Result<T, Error>; // For tests.
+
+    use super::*;
+
+    #[test]
+    fn test_channel_builder_owned_url_ok() -> Result<()> {
+        let url = String::from("http://localhost:50051");
+
+        let endpoint = ChannelBuilder::new(url)?
+            .connect_timeout(Duration::from_secs(1))
+            .http2_keep_alive_interval(Duration::from_secs(30))
+            .concurrency_limit(16)
+            .endpoint()?;
+
+        assert_eq!(endpoint.uri(), &Uri::from_static("http://localhost:50051"));
+        assert!(matches!(
+            ChannelBuilder::new("not a url"),
+            Err(crate::Error::InvalidUrl(_))
+        ));
+
+        Ok(())
+    }
+}
+
+// endregion: --- Tests
@@ -0,0 +1,426 @@
+//! Pool of HTTP/2 connections
+//!
+//! Unary calls are spread over pooled connections, long-lived streams get dedicated ones,
+//! so market data streams do not contend with order requests.
+
+#![allow(clippy::result_large_err)]
+
+// region:    --- Modules
+
+use super::{ChannelBuilder, InFlightLayer, InFlightService};
+use crate::{Error, Result};
+use std::future::Future;
+use std::ops::Deref;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+use tonic::transport::Channel;
+use tower_layer::Layer;
+
+// endregion: --- Modules
+
+/// Default limit of dedicated connections
+const DEFAULT_MAX_DEDICATED: usize = 4;
+
+/// Pooled channel counting requests in flight
+pub type InFlightChannel = InFlightService<Channel>;
+
+/// Distribution of calls over pooled connections
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Balance {
+    #[default]
+    RoundRobin,
+    /// Connection with the least number of requests in flight, streams are counted while open
+    LeastInFlight,
+}
+
+// region:    --- Channel Pool
+
+/// Pool of connections created with the same `ChannelBuilder`
+#[derive(Debug)]
+pub struct ChannelPool {
+    builder: ChannelBuilder,
+    lazy: bool,
+    slots: Vec<Arc<Slot>>,
+    dedicated: Mutex<Dedicated>,
+    max_dedicated: usize,
+    next: AtomicUsize,
+    balance: Balance,
+}
+
+/// Dedicated connections and number of ones being opened
+#[derive(Debug, Default)]
+struct Dedicated {
+    slots: Vec<Arc<Slot>>,
+    connecting: usize,
+}
+
+#[derive(Debug)]
+struct Slot {
+    channel: RwLock<Channel>,
+    in_flight: InFlightLayer,
+    checked_out: Arc<AtomicUsize>,
+}
+
+impl Slot {
+    fn new(channel: Channel) -> Self {
+        Self {
+            channel: RwLock::new(channel),
+            in_flight: InFlightLayer::new(),
+            checked_out: Arc::default(),
+        }
+    }
+
+    fn channel(&self) -> Channel {
+        self.channel
+            .read()
+            .unwrap_or_else(|err| err.into_inner())
+            .clone()
+    }
+
+    fn checked_out(&self) -> usize {
+        self.checked_out.load(Ordering::Relaxed)
+    }
+
+    fn check_out(&self, index: usize) -> PooledChannel {
+        self.checked_out.fetch_add(1, Ordering::Relaxed);
+
+        PooledChannel {
+            index,
+            channel: self.in_flight.layer(self.channel()),
+            checked_out: self.checked_out.clone(),
+        }
+    }
+
+    fn replace(&self, channel: Channel) {
+        *self.channel.write().unwrap_or_else(|err| err.into_inner()) = channel;
+    }
+}
+
+impl ChannelPool {
+    /// Opens `size` connections, at least one
+    pub async fn connect(builder: ChannelBuilder, size: usize) -> Result<Self> {
+        let mut slots = Vec::with_capacity(size.max(1));
+
+        for _ in 0..size.max(1) {
+            slots.push(Arc::new(Slot::new(builder.clone().connect().await?)));
+        }
+
+        Ok(Self::with_slots(builder, slots, false))
+    }
+
+    /// Creates `size` connections, at least one, that connect on first request
+    ///
+    /// Dedicated connections of this pool are lazy too.
+    pub fn connect_lazy(builder: ChannelBuilder, size: usize) -> Result<Self> {
+        let slots = (0..size.max(1))
+            .map(|_| builder.clone().connect_lazy().map(Slot::new).map(Arc::new))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self::with_slots(builder, slots, true))
+    }
+
+    fn with_slots(builder: ChannelBuilder, slots: Vec<Arc<Slot>>, lazy: bool) -> Self {
+        Self {
+            builder,
+            lazy,
+            slots,
+            dedicated: Mutex::default(),
+            max_dedicated: DEFAULT_MAX_DEDICATED,
+            next: AtomicUsize::new(0),
+            balance: Balance::default(),
+        }
+    }
+
+    /// Sets distribution of calls, round-robin by default
+    pub fn with_balance(mut self, balance: Balance) -> Self {
+        self.balance = balance;
+        self
+    }
+
+    /// Sets limit of dedicated connections, 4 by default
+    pub fn with_max_dedicated(mut self, max_dedicated: usize) -> Self {
+        self.max_dedicated = max_dedicated;
+        self
+    }
+
+    /// Returns number of pooled connections
+    pub fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Always `false`, pool has at least one connection
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+
+    /// Returns number of checked out channels per pooled connection
+    pub fn checked_out(&self) -> Vec<usize> {
+        self.slots.iter().map(|slot| slot.checked_out()).collect()
+    }
+
+    /// Returns number of requests in flight per pooled connection
+    pub fn in_flight(&self) -> Vec<usize> {
+        self.slots
+            .iter()
+            .map(|slot| slot.in_flight.in_flight())
+            .collect()
+    }
+
+    /// Returns number of opened dedicated connections, including free ones
+    pub fn dedicated_len(&self) -> usize {
+        self.dedicated_slots().len()
+    }
+
+    /// Checks out channel for unary calls
+    pub fn get(&self) -> PooledChannel {
+        let index = match self.balance {
+            Balance::RoundRobin => self.next.fetch_add(1, Ordering::Relaxed) % self.slots.len(),
+            Balance::LeastInFlight => self
+                .slots
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, slot)| slot.in_flight.in_flight())
+                .map(|(index, _)| index)
+                .unwrap_or_default(),
+        };
+
+        self.slots[index].check_out(index)
+    }
+
+    /// Checks out dedicated connection for long-lived stream, keep it while stream is open
+    ///
+    /// Free dedicated connection is reused, new one is opened if all are in use and limit
+    /// is not reached.
+    pub async fn dedicated(&self) -> Result<PooledChannel> {
+        // Slot is reserved before connecting, so concurrent calls cannot exceed limit
+        let reservation = match self.reserve_dedicated()? {
+            Ok(channel) => return Ok(channel),
+            Err(reservation) => reservation,
+        };
+
+        let channel = if self.lazy {
+            self.builder.clone().connect_lazy()?
+        } else {
+            self.builder.clone().connect().await?
+        };
+
+        let mut dedicated = reservation.lock();
+        let slot = Arc::new(Slot::new(channel));
+        dedicated.slots.push(slot.clone());
+
+        Ok(slot.check_out(dedicated.slots.len() - 1))
+    }
+
+    /// Checks out free dedicated connection or reserves slot for new one if limit allows
+    fn reserve_dedicated(&self) -> Result<core::result::Result<PooledChannel, Reservation<'_>>> {
+        let mut dedicated = self.lock_dedicated();
+
+        if let Some((index, slot)) = dedicated
+            .slots
+            .iter()
+            .enumerate()
+            .find(|(_, slot)| slot.checked_out() == 0)
+        {
+            return Ok(Ok(slot.check_out(index)));
+        }
+        if dedicated.slots.len() + dedicated.connecting >= self.max_dedicated {
+            return Err(Error::DedicatedChannelLimit(self.max_dedicated));
+        }
+
+        dedicated.connecting += 1;
+
+        Ok(Err(Reservation { pool: self }))
+    }
+
+    fn lock_dedicated(&self) -> std::sync::MutexGuard<'_, Dedicated> {
+        self.dedicated.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    fn dedicated_slots(&self) -> Vec<Arc<Slot>> {
+        self.lock_dedicated().slots.clone()
+    }
+
+    /// Runs `probe` on each pooled and dedicated connection and replaces ones it fails on
+    ///
+    /// Returns number of replaced connections, connections that cannot be reopened are kept.
+    /// Streams already opened on replaced dedicated connection keep the old one.
+    ///
+    /// ```ignore
+    /// pool.check_health(|channel| async move {
+    ///     api.users(&channel)?.get_info(GetInfoRequest {}).await?;
+    ///     Ok(())
+    /// })
+    /// .await?;
+    /// ```
+    pub async fn check_health<F, Fut>(&self, probe: F) -> Result<usize>
+    where
+        F: Fn(Channel) -> Fut,
+        Fut: Future<Output = Result<()>>,
+    {
+        let mut replaced = 0;
+
+        for slot in self.slots.iter().chain(&self.dedicated_slots()) {
+            if probe(slot.channel()).await.is_ok() {
+                continue;
+            }
+
+            match self.builder.clone().connect().await {
+                Ok(channel) => {
+                    slot.replace(channel);
+                    replaced += 1;
+                }
+                Err(err) => tracing::warn!("Failed to reopen pooled connection: {err}"),
+            }
+        }
+
+        Ok(replaced)
+    }
+}
+
+/// Dedicated slot reserved while connection is opened, released on drop, e.g. if connecting fails
+struct Reservation<'a> {
+    pool: &'a ChannelPool,
+}
+
+impl<'a> Reservation<'a> {
+    /// Releases reservation and locks dedicated connections for adding reserved one
+    fn lock(self) -> std::sync::MutexGuard<'a, Dedicated> {
+        let mut dedicated = self.pool.lock_dedicated();
+        dedicated.connecting -= 1;
+        std::mem::forget(self);
+
+        dedicated
+    }
+}
+
+impl Drop for Reservation<'_> {
+    fn drop(&mut self) {
+        self.pool.lock_dedicated().connecting -= 1;
+    }
+}
+
+// endregion: --- Channel Pool
+
+// region:    --- Pooled Channel
+
+/// Channel checked out from pool, counted as checked out until dropped
+///
+/// Requests are counted as in flight only if sent through it, e.g. with
+/// `api.intercept(channel.clone())`, not through `channel.inner()`.
+#[derive(Debug)]
+pub struct PooledChannel {
+    index: usize,
+    channel: InFlightChannel,
+    checked_out: Arc<AtomicUsize>,
+}
+
+impl PooledChannel {
+    /// Returns index of connection in pool, or among dedicated connections
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns channel without counting of requests
+    pub fn inner(&self) -> Channel {
+        self.channel.clone().into_inner()
+    }
+}
+
+impl Deref for PooledChannel {
+    type Target = InFlightChannel;
+
+    fn deref(&self) -> &Self::Target {
+        &self.channel
+    }
+}
+
+impl Drop for PooledChannel {
+    fn drop(&mut self) {
+        self.checked_out.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+// endregion: --- Pooled Channel
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    type Error = Box<dyn std::error::Error>;
+    type Result<T> = core::result::Result<T, Error>; // For tests.
+
+    use super::*;
+
+    #[test]
+    fn test_channel_pool_balance_ok() -> Result<()> {
+        // Lazy channels are driven by runtime
+        let runtime = tokio::runtime::Runtime::new()?;
+        let _guard = runtime.enter();
+
+        let builder = ChannelBuilder::new("http://localhost:50051")?;
+
+        let pool = ChannelPool::connect_lazy(builder.clone(), 3)?;
+        let indexes: Vec<_> = (0..4).map(|_| pool.get().index()).collect();
+        assert_eq!(indexes, vec![0, 1, 2, 0]);
+
+        let pool = ChannelPool::connect_lazy(builder, 2)?.with_balance(Balance::LeastInFlight);
+        let first = pool.get();
+        assert_eq!(first.index(), 0);
+
+        // Held handle is not counted, request is
+        let second = pool.get();
+        assert_eq!(second.index(), 0);
+        let mut service = second.clone();
+        runtime.block_on(std::future::poll_fn(|cx| {
+            tower_service::Service::poll_ready(&mut service, cx)
+        }))?;
+        let request = http::Request::new(tonic::body::empty_body());
+        let pending = tower_service::Service::call(&mut service, request);
+        assert_eq!(pool.in_flight(), vec![1, 0]);
+        assert_eq!(pool.get().index(), 1);
+
+        drop(pending);
+        assert_eq!(pool.in_flight(), vec![0, 0]);
+        assert_eq!(pool.get().index(), 0);
+        assert_eq!(pool.checked_out(), vec![2, 0]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_channel_pool_dedicated_ok() -> Result<()> {
+        let runtime = tokio::runtime::Runtime::new()?;
+
+        runtime.block_on(async {
+            let builder = ChannelBuilder::new("http://localhost:50051")?;
+            let pool = ChannelPool::connect_lazy(builder, 1)?.with_max_dedicated(2);
+
+            let first = pool.dedicated().await?;
+            let second = pool.dedicated().await?;
+            assert_eq!((first.index(), second.index()), (0, 1));
+            assert!(matches!(
+                pool.dedicated().await,
+                Err(crate::Error::DedicatedChannelLimit(2))
+            ));
+
+            // Free connection is reused
+            drop(first);
+            assert_eq!(pool.dedicated().await?.index(), 0);
+            assert_eq!(pool.dedicated_len(), 2);
+            assert_eq!(pool.checked_out(), vec![0]);
+
+            // Failed connection releases reserved slot
+            let mut failing =
+                ChannelPool::connect_lazy(ChannelBuilder::new("http://127.0.0.1:1")?, 1)?
+                    .with_max_dedicated(1);
+            failing.lazy = false;
+            assert!(failing.dedicated().await.is_err());
+            assert_eq!(failing.dedicated_len(), 0);
+            assert_eq!(failing.lock_dedicated().connecting, 0);
+
+            Ok(())
+        })
+    }
+}
+
+// endregion: --- Tests
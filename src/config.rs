@@ -1,42 +1,920 @@
 //! Crate config
+//!
+//! Config is merged from layers, later layers override earlier ones:
+//! 1. defaults
+//! 2. TOML or YAML file (`config-file` feature), from `ApiConfigLoader::file` or `<PREFIX>CONFIG` env variable
+//! 3. environment variables with prefix, `TINKOFF_` by default
+//! 4. programmatic overrides from `ApiConfigLoader`
+//!
+//! Named profiles, e.g. `prod`, `sandbox` or `readonly`, keep their own token, endpoint and
+//! application name, missing values except token are taken from top level ones. Active profile is selected by
+//! `ApiConfigLoader::profile`, `<PREFIX>PROFILE` env variable or `profile` key in file.
+
+#![allow(clippy::result_large_err)]
+
+// region:    --- Modules
 
 use crate::error::{Error, Result};
-use std::{env, str::FromStr, sync::OnceLock};
+use crate::SecretToken;
+use std::{
+    collections::BTreeMap, env, future::Future, str::FromStr, sync::OnceLock, time::Duration,
+};
+use tonic::{Code, Status};
+
+#[cfg(feature = "config-file")]
+use std::path::{Path, PathBuf};
 
-pub fn config() -> &'static ApiConfig {
+// endregion: --- Modules
+
+/// Default API endpoint
+pub const DEFAULT_ENDPOINT: &str = "https://invest-public-api.tinkoff.ru:443/";
+/// Default sandbox API endpoint
+pub const DEFAULT_SANDBOX_ENDPOINT: &str = "https://sandbox-invest-public-api.tinkoff.ru:443/";
+/// Default prefix of environment variables
+pub const DEFAULT_ENV_PREFIX: &str = "TINKOFF_";
+
+/// Returns global config, loaded once with `ApiConfig::load`
+pub fn config() -> Result<&'static ApiConfig> {
     static INSTANCE: OnceLock<ApiConfig> = OnceLock::new();
 
-    INSTANCE.get_or_init(|| {
-        ApiConfig::load_from_env()
-            .unwrap_or_else(|ex| panic!("FATAL - WHOLE LOADING CONF - Cause: {ex:?}"))
-    })
+    if let Some(config) = INSTANCE.get() {
+        return Ok(config);
+    }
+
+    let config = ApiConfig::load()?;
+
+    Ok(INSTANCE.get_or_init(|| config))
 }
 
-#[allow(non_snake_case)]
-#[derive(Debug)]
+// region:    --- Api Config
+
+/// API config
+///
+/// `token`, `endpoint` and `app_name` are taken from active profile.
+#[derive(Debug, Clone)]
 pub struct ApiConfig {
-    // API
-    pub TINKOFF_API: String,
-    pub TINKOFF_TOKEN: String,
+    pub token: SecretToken,
+    pub endpoint: String,
+    pub sandbox_endpoint: String,
+    pub app_name: Option<String>,
+    pub connect_timeout: Option<Duration>,
+    pub request_timeout: Option<Duration>,
+    pub retry: RetryConfig,
+    pub rate_limit: RateLimitConfig,
+    /// Name of active profile, `None` if top level values are used
+    pub profile_name: Option<String>,
+    base: ProfileLayer,
+    profiles: BTreeMap<String, ProfileLayer>,
+    env_prefix: String,
+}
+
+/// Named set of token, endpoint and application name
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Profile {
+    pub name: String,
+    pub token: SecretToken,
+    pub endpoint: String,
+    pub app_name: Option<String>,
+}
+
+impl Profile {
+    /// Name of profile built from top level values
+    pub const DEFAULT_NAME: &'static str = "default";
+
+    /// Creates profile for production endpoint
+    pub fn new(name: impl Into<String>, token: impl Into<SecretToken>) -> Self {
+        Self {
+            name: name.into(),
+            token: token.into(),
+            endpoint: DEFAULT_ENDPOINT.to_string(),
+            app_name: None,
+        }
+    }
+
+    /// Creates profile for sandbox endpoint
+    pub fn sandbox(name: impl Into<String>, token: impl Into<SecretToken>) -> Self {
+        Self::new(name, token).with_endpoint(DEFAULT_SANDBOX_ENDPOINT)
+    }
+
+    /// Sets endpoint
+    pub fn with_endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.endpoint = endpoint.into();
+        self
+    }
+
+    /// Sets application name
+    pub fn with_app_name(mut self, app_name: impl Into<String>) -> Self {
+        self.app_name = Some(app_name.into());
+        self
+    }
+}
+
+/// Retry settings for failed requests
+///
+/// Used by `RetryConfig::run`, only `UNAVAILABLE` and `RESOURCE_EXHAUSTED` statuses are retried.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryConfig {
+    /// Maximum number of attempts, including the first one
+    pub max_attempts: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+        }
+    }
+}
+
+impl RetryConfig {
+    /// Returns delay after failed attempt, starting from 1, doubled after each one up to `max_backoff`
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+
+    /// Sends request until it succeeds, fails with status that is not retried or attempts are exhausted
+    ///
+    /// After `RESOURCE_EXHAUSTED` waits at least until limit is reset, as told by `x-ratelimit-reset` header.
+    pub async fn run<T, F, Fut>(&self, mut request: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = core::result::Result<T, Status>>,
+    {
+        let mut attempt = 1;
+
+        loop {
+            let status = match request().await {
+                Ok(response) => return Ok(response),
+                Err(status) => status,
+            };
+
+            if attempt >= self.max_attempts || !is_retryable(&status) {
+                return Err(status.into());
+            }
+
+            let delay = self.backoff(attempt).max(rate_limit_reset(&status));
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+}
+
+fn is_retryable(status: &Status) -> bool {
+    matches!(status.code(), Code::Unavailable | Code::ResourceExhausted)
+}
+
+/// Returns time until rate limit is reset, zero if unknown
+fn rate_limit_reset(status: &Status) -> Duration {
+    status
+        .metadata()
+        .get("x-ratelimit-reset")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse().ok())
+        .map(Duration::from_secs)
+        .unwrap_or_default()
+}
+
+/// Client side rate limit settings
+///
+/// Applied to channels created by `ChannelBuilder::from_config`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RateLimitConfig {
+    /// Maximum number of requests per minute, `None` for unlimited
+    pub requests_per_minute: Option<u32>,
 }
 
 impl ApiConfig {
-    fn load_from_env() -> Result<ApiConfig> {
+    /// Loads config from file, environment variables with default prefix and defaults
+    pub fn load() -> Result<ApiConfig> {
+        ApiConfigLoader::default().load()
+    }
+
+    /// Creates loader for customizing config layers
+    pub fn loader() -> ApiConfigLoader {
+        ApiConfigLoader::default()
+    }
+
+    /// Returns active profile
+    pub fn active_profile(&self) -> Profile {
+        Profile {
+            name: self
+                .profile_name
+                .clone()
+                .unwrap_or_else(|| Profile::DEFAULT_NAME.to_string()),
+            token: self.token.clone(),
+            endpoint: self.endpoint.clone(),
+            app_name: self.app_name.clone(),
+        }
+    }
+
+    /// Returns profile by name, missing values except token are taken from top level ones
+    pub fn named_profile(&self, name: &str) -> Result<Profile> {
+        let name = name.to_lowercase();
+
+        if name == Profile::DEFAULT_NAME && !self.profiles.contains_key(&name) {
+            return ProfileLayer::default().resolve(
+                Profile::DEFAULT_NAME,
+                &self.base,
+                &self.sandbox_endpoint,
+                || format!("{}TOKEN", self.env_prefix),
+            );
+        }
+
+        let layer = self
+            .profiles
+            .get(&name)
+            .ok_or_else(|| Error::ConfigProfileNotFound(name.clone()))?;
+
+        self.base.resolve(&name, layer, &self.sandbox_endpoint, || {
+            profile_env_name(&self.env_prefix, &name, "TOKEN")
+        })
+    }
+
+    /// Returns names of configured profiles
+    pub fn profile_names(&self) -> impl Iterator<Item = &str> {
+        self.profiles.keys().map(String::as_str)
+    }
+}
+
+// endregion: --- Api Config
+
+// region:    --- Loader
+
+/// Loader of `ApiConfig` with programmatic overrides
+#[derive(Debug, Clone, Default)]
+pub struct ApiConfigLoader {
+    #[cfg(feature = "config-file")]
+    file: Option<PathBuf>,
+    env_prefix: Option<String>,
+    /// Variables used instead of process environment
+    env_vars: Option<EnvVars>,
+    overrides: ConfigLayer,
+}
+
+impl ApiConfigLoader {
+    /// Sets TOML or YAML config file, format is chosen by extension
+    #[cfg(feature = "config-file")]
+    pub fn file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.file = Some(path.into());
+        self
+    }
+
+    /// Sets prefix of environment variables, `TINKOFF_` by default
+    pub fn env_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.env_prefix = Some(prefix.into());
+        self
+    }
+
+    /// Reads variables from map instead of process environment, e.g. in tests
+    pub fn env_vars<K, V>(mut self, vars: impl IntoIterator<Item = (K, V)>) -> Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.env_vars = Some(
+            vars.into_iter()
+                .map(|(name, value)| (name.into(), value.into()))
+                .collect(),
+        );
+        self
+    }
+
+    /// Overrides token
+    pub fn token(mut self, token: impl Into<SecretToken>) -> Self {
+        self.overrides.token = Some(token.into());
+        self
+    }
+
+    /// Overrides API endpoint
+    pub fn endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.overrides.endpoint = Some(endpoint.into());
+        self
+    }
+
+    /// Overrides sandbox API endpoint
+    pub fn sandbox_endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.overrides.sandbox_endpoint = Some(endpoint.into());
+        self
+    }
+
+    /// Overrides application name
+    pub fn app_name(mut self, app_name: impl Into<String>) -> Self {
+        self.overrides.app_name = Some(app_name.into());
+        self
+    }
+
+    /// Overrides connect timeout
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.overrides.connect_timeout_ms = Some(timeout.as_millis() as u64);
+        self
+    }
+
+    /// Overrides request timeout
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.overrides.request_timeout_ms = Some(timeout.as_millis() as u64);
+        self
+    }
+
+    /// Overrides retry settings
+    pub fn retry(mut self, retry: RetryConfig) -> Self {
+        self.overrides.retry = RetryLayer {
+            max_attempts: Some(retry.max_attempts),
+            initial_backoff_ms: Some(retry.initial_backoff.as_millis() as u64),
+            max_backoff_ms: Some(retry.max_backoff.as_millis() as u64),
+        };
+        self
+    }
+
+    /// Overrides rate limit settings
+    pub fn rate_limit(mut self, rate_limit: RateLimitConfig) -> Self {
+        self.overrides.rate_limit = RateLimitLayer {
+            requests_per_minute: rate_limit.requests_per_minute,
+        };
+        self
+    }
+
+    /// Selects active profile
+    pub fn profile(mut self, name: impl Into<String>) -> Self {
+        self.overrides.profile = Some(name.into());
+        self
+    }
+
+    /// Adds or replaces named profile
+    pub fn add_profile(mut self, profile: Profile) -> Self {
+        self.overrides.profiles.insert(
+            profile.name,
+            ProfileLayer {
+                token: Some(profile.token),
+                endpoint: Some(profile.endpoint),
+                app_name: profile.app_name,
+                sandbox: None,
+            },
+        );
+        self
+    }
+
+    /// Merges all layers into config
+    pub fn load(self) -> Result<ApiConfig> {
+        let prefix = self
+            .env_prefix
+            .unwrap_or_else(|| DEFAULT_ENV_PREFIX.to_string());
+        // Environment is read once, so all layers see the same values
+        let vars = self.env_vars.unwrap_or_else(|| env::vars().collect());
+
+        #[cfg(feature = "config-file")]
+        let layer = match self
+            .file
+            .or_else(|| vars.get(&format!("{prefix}CONFIG")).map(PathBuf::from))
+        {
+            Some(path) => ConfigLayer::from_file(&path)?,
+            None => ConfigLayer::default(),
+        };
+        #[cfg(not(feature = "config-file"))]
+        let layer = ConfigLayer::default();
+
+        layer
+            .merge(ConfigLayer::from_env(&vars, &prefix)?)
+            .merge(self.overrides)
+            .build(&prefix)
+    }
+}
+
+// endregion: --- Loader
+
+// region:    --- Layers
+
+/// Partial config, all values are optional
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "config-file", derive(serde::Deserialize))]
+#[cfg_attr(feature = "config-file", serde(default, deny_unknown_fields))]
+struct ConfigLayer {
+    token: Option<SecretToken>,
+    endpoint: Option<String>,
+    sandbox_endpoint: Option<String>,
+    app_name: Option<String>,
+    connect_timeout_ms: Option<u64>,
+    request_timeout_ms: Option<u64>,
+    retry: RetryLayer,
+    rate_limit: RateLimitLayer,
+    profile: Option<String>,
+    profiles: BTreeMap<String, ProfileLayer>,
+}
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "config-file", derive(serde::Deserialize))]
+#[cfg_attr(feature = "config-file", serde(default, deny_unknown_fields))]
+struct ProfileLayer {
+    token: Option<SecretToken>,
+    endpoint: Option<String>,
+    app_name: Option<String>,
+    /// Uses sandbox endpoint if endpoint is not set
+    sandbox: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "config-file", derive(serde::Deserialize))]
+#[cfg_attr(feature = "config-file", serde(default, deny_unknown_fields))]
+struct RetryLayer {
+    max_attempts: Option<u32>,
+    initial_backoff_ms: Option<u64>,
+    max_backoff_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "config-file", derive(serde::Deserialize))]
+#[cfg_attr(feature = "config-file", serde(default, deny_unknown_fields))]
+struct RateLimitLayer {
+    requests_per_minute: Option<u32>,
+}
+
+impl ConfigLayer {
+    #[cfg(feature = "config-file")]
+    fn from_file(path: &Path) -> Result<Self> {
+        let name = path.display().to_string();
+        let content =
+            std::fs::read_to_string(path).map_err(|_| Error::ConfigFileNotFound(name.clone()))?;
+
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("yaml" | "yml") => serde_yaml::from_str(&content)
+                .map_err(|err| Error::ConfigWrongFormat(format!("{name}: {err}"))),
+            _ => toml::from_str(&content)
+                .map_err(|err| Error::ConfigWrongFormat(format!("{name}: {err}"))),
+        }
+    }
+
+    fn from_env(vars: &EnvVars, prefix: &str) -> Result<Self> {
+        let name = |key: &str| format!("{prefix}{key}");
+
+        Ok(Self {
+            token: get_env_parse(vars, &name("TOKEN"))?,
+            endpoint: get_env_parse(vars, &name("API"))?,
+            sandbox_endpoint: get_env_parse(vars, &name("SANDBOX_API"))?,
+            app_name: get_env_parse(vars, &name("APP_NAME"))?,
+            connect_timeout_ms: get_env_parse(vars, &name("CONNECT_TIMEOUT_MS"))?,
+            request_timeout_ms: get_env_parse(vars, &name("REQUEST_TIMEOUT_MS"))?,
+            retry: RetryLayer {
+                max_attempts: get_env_parse(vars, &name("RETRY_MAX_ATTEMPTS"))?,
+                initial_backoff_ms: get_env_parse(vars, &name("RETRY_INITIAL_BACKOFF_MS"))?,
+                max_backoff_ms: get_env_parse(vars, &name("RETRY_MAX_BACKOFF_MS"))?,
+            },
+            rate_limit: RateLimitLayer {
+                requests_per_minute: get_env_parse(vars, &name("RATE_LIMIT_PER_MINUTE"))?,
+            },
+            profile: get_env_parse(vars, &name("PROFILE"))?,
+            profiles: profiles_from_env(vars, prefix)?,
+        })
+    }
+
+    /// Overrides values with values present in other layer
+    fn merge(self, other: ConfigLayer) -> Self {
+        Self {
+            token: other.token.or(self.token),
+            endpoint: other.endpoint.or(self.endpoint),
+            sandbox_endpoint: other.sandbox_endpoint.or(self.sandbox_endpoint),
+            app_name: other.app_name.or(self.app_name),
+            connect_timeout_ms: other.connect_timeout_ms.or(self.connect_timeout_ms),
+            request_timeout_ms: other.request_timeout_ms.or(self.request_timeout_ms),
+            retry: RetryLayer {
+                max_attempts: other.retry.max_attempts.or(self.retry.max_attempts),
+                initial_backoff_ms: other
+                    .retry
+                    .initial_backoff_ms
+                    .or(self.retry.initial_backoff_ms),
+                max_backoff_ms: other.retry.max_backoff_ms.or(self.retry.max_backoff_ms),
+            },
+            rate_limit: RateLimitLayer {
+                requests_per_minute: other
+                    .rate_limit
+                    .requests_per_minute
+                    .or(self.rate_limit.requests_per_minute),
+            },
+            profile: other.profile.or(self.profile),
+            profiles: merge_profiles(self.profiles, other.profiles),
+        }
+    }
+
+    /// Builds config, filling missing values with defaults
+    fn build(self, prefix: &str) -> Result<ApiConfig> {
+        let retry = RetryConfig::default();
+        let sandbox_endpoint = self
+            .sandbox_endpoint
+            .unwrap_or_else(|| DEFAULT_SANDBOX_ENDPOINT.to_string());
+        let base = ProfileLayer {
+            token: self.token,
+            endpoint: self.endpoint,
+            app_name: self.app_name,
+            sandbox: None,
+        };
+        let profiles: BTreeMap<_, _> = self
+            .profiles
+            .into_iter()
+            .map(|(name, layer)| (name.to_lowercase(), layer))
+            .collect();
+
+        let profile_name = self.profile.map(|name| name.to_lowercase());
+        let active = match &profile_name {
+            Some(name) => {
+                let layer = profiles
+                    .get(name)
+                    .ok_or_else(|| Error::ConfigProfileNotFound(name.clone()))?;
+
+                base.resolve(name, layer, &sandbox_endpoint, || {
+                    profile_env_name(prefix, name, "TOKEN")
+                })?
+            }
+            None => ProfileLayer::default().resolve(
+                Profile::DEFAULT_NAME,
+                &base,
+                &sandbox_endpoint,
+                || format!("{prefix}TOKEN"),
+            )?,
+        };
+
         Ok(ApiConfig {
-            // API
-            TINKOFF_API: get_env_parse("TINKOFF_API")
-                .unwrap_or(String::from("https://invest-public-api.tinkoff.ru:443/")),
-            TINKOFF_TOKEN: get_env_parse("TINKOFF_TOKEN")?,
+            token: active.token,
+            endpoint: active.endpoint,
+            sandbox_endpoint,
+            app_name: active.app_name,
+            connect_timeout: self.connect_timeout_ms.map(Duration::from_millis),
+            request_timeout: self.request_timeout_ms.map(Duration::from_millis),
+            retry: RetryConfig {
+                max_attempts: self.retry.max_attempts.unwrap_or(retry.max_attempts),
+                initial_backoff: self
+                    .retry
+                    .initial_backoff_ms
+                    .map(Duration::from_millis)
+                    .unwrap_or(retry.initial_backoff),
+                max_backoff: self
+                    .retry
+                    .max_backoff_ms
+                    .map(Duration::from_millis)
+                    .unwrap_or(retry.max_backoff),
+            },
+            rate_limit: RateLimitConfig {
+                requests_per_minute: self.rate_limit.requests_per_minute,
+            },
+            profile_name,
+            base,
+            profiles,
+            env_prefix: prefix.to_string(),
+        })
+    }
+}
+
+impl ProfileLayer {
+    /// Overrides values with values present in other layer
+    fn merge(self, other: ProfileLayer) -> Self {
+        Self {
+            token: other.token.or(self.token),
+            endpoint: other.endpoint.or(self.endpoint),
+            app_name: other.app_name.or(self.app_name),
+            sandbox: other.sandbox.or(self.sandbox),
+        }
+    }
+
+    /// Builds profile from `profile` layer, taking missing values except token from `self`
+    ///
+    /// Token is never inherited, so a profile can't use top level token of another environment.
+    fn resolve(
+        &self,
+        name: &str,
+        profile: &ProfileLayer,
+        sandbox_endpoint: &str,
+        token_name: impl FnOnce() -> String,
+    ) -> Result<Profile> {
+        let sandbox = profile
+            .sandbox
+            .unwrap_or(false)
+            .then(|| sandbox_endpoint.to_string());
+
+        Ok(Profile {
+            name: name.to_string(),
+            token: profile
+                .token
+                .clone()
+                .ok_or_else(|| Error::ConfigMissingEnv(token_name()))?,
+            endpoint: profile
+                .endpoint
+                .clone()
+                .or(sandbox)
+                .or_else(|| self.endpoint.clone())
+                .unwrap_or_else(|| DEFAULT_ENDPOINT.to_string()),
+            app_name: profile.app_name.clone().or_else(|| self.app_name.clone()),
         })
     }
 }
 
-fn get_env(name: &'static str) -> Result<String> {
-    env::var(name).map_err(|_| Error::ConfigMissingEnv(name))
+fn merge_profiles(
+    mut profiles: BTreeMap<String, ProfileLayer>,
+    other: BTreeMap<String, ProfileLayer>,
+) -> BTreeMap<String, ProfileLayer> {
+    for (name, layer) in other {
+        let merged = profiles.remove(&name).unwrap_or_default().merge(layer);
+        profiles.insert(name, merged);
+    }
+
+    profiles
 }
 
-fn get_env_parse<T: FromStr>(name: &'static str) -> Result<T> {
-    let val = get_env(name)?;
+/// Collects profiles from `<PREFIX>PROFILE_<NAME>_<KEY>` variables
+fn profiles_from_env(vars: &EnvVars, prefix: &str) -> Result<BTreeMap<String, ProfileLayer>> {
+    const KEYS: [&str; 4] = ["_APP_NAME", "_TOKEN", "_API", "_SANDBOX"];
+
+    let profile_prefix = format!("{prefix}PROFILE_");
+    let mut profiles = BTreeMap::<String, ProfileLayer>::new();
+
+    for (var, value) in vars {
+        let Some(rest) = var.strip_prefix(&profile_prefix) else {
+            continue;
+        };
+        let Some((name, key)) = KEYS
+            .iter()
+            .find_map(|key| rest.strip_suffix(key).map(|name| (name, *key)))
+            .filter(|(name, _)| !name.is_empty())
+        else {
+            continue;
+        };
+
+        let layer = profiles.entry(name.to_lowercase()).or_default();
+
+        match key {
+            "_APP_NAME" => layer.app_name = Some(value.clone()),
+            "_TOKEN" => layer.token = Some(value.clone().into()),
+            "_API" => layer.endpoint = Some(value.clone()),
+            _ => layer.sandbox = get_env_parse(vars, var)?,
+        }
+    }
+
+    Ok(profiles)
+}
+
+fn profile_env_name(prefix: &str, name: &str, key: &str) -> String {
+    format!("{prefix}PROFILE_{}_{key}", name.to_uppercase())
+}
+
+// endregion: --- Layers
+
+// region:    --- Env Helpers
+
+/// Snapshot of environment variables
+type EnvVars = BTreeMap<String, String>;
+
+/// Returns `None` if variable is not set, error if it cannot be parsed
+fn get_env_parse<T: FromStr>(vars: &EnvVars, name: &str) -> Result<Option<T>> {
+    vars.get(name)
+        .map(|val| {
+            val.parse::<T>()
+                .map_err(|_| Error::ConfigWrongFormat(name.to_string()))
+        })
+        .transpose()
+}
+
+// endregion: --- Env Helpers
+
+// region:    --- Tests
 
-    val.parse::<T>().map_err(|_| Error::ConfigWrongFormat(name))
+#[cfg(test)]
+mod tests {
+    type Error = Box<dyn std::error::Error>;
+    type Result<T> = core::result::Result<T, Error>; // For tests.
+
+    use super::*;
+
+    // Variables are passed to loader, as process environment is shared by parallel tests
+
+    #[test]
+    fn test_config_env_and_overrides_ok() -> Result<()> {
+        let config = ApiConfig::loader()
+            .env_prefix("TEST_OVERRIDES_")
+            .env_vars([
+                ("TEST_OVERRIDES_TOKEN", "env-token"),
+                ("TEST_OVERRIDES_APP_NAME", "env-app"),
+                ("TEST_OVERRIDES_RETRY_MAX_ATTEMPTS", "5"),
+            ])
+            .app_name("override-app")
+            .load()?;
+
+        assert_eq!(config.token.expose(), "env-token");
+        assert_eq!(config.app_name.as_deref(), Some("override-app"));
+        assert_eq!(config.endpoint, DEFAULT_ENDPOINT);
+        assert_eq!(config.retry.max_attempts, 5);
+        assert_eq!(config.retry.max_backoff, RetryConfig::default().max_backoff);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_config_missing_token_err() -> Result<()> {
+        let result = ApiConfig::loader()
+            .env_prefix("TEST_MISSING_")
+            .env_vars(BTreeMap::<String, String>::new())
+            .load();
+
+        assert!(matches!(
+            result,
+            Err(crate::Error::ConfigMissingEnv(name)) if name == "TEST_MISSING_TOKEN"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_config_wrong_format_err() -> Result<()> {
+        let result = ApiConfig::loader()
+            .env_prefix("TEST_WRONG_")
+            .env_vars([
+                ("TEST_WRONG_TOKEN", "token"),
+                ("TEST_WRONG_CONNECT_TIMEOUT_MS", "soon"),
+            ])
+            .load();
+
+        assert!(matches!(
+            result,
+            Err(crate::Error::ConfigWrongFormat(name)) if name == "TEST_WRONG_CONNECT_TIMEOUT_MS"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_config_env_profiles_ok() -> Result<()> {
+        let config = ApiConfig::loader()
+            .env_prefix("TEST_PROFILES_")
+            .env_vars([
+                ("TEST_PROFILES_APP_NAME", "base-app"),
+                ("TEST_PROFILES_PROFILE", "sandbox"),
+                ("TEST_PROFILES_PROFILE_SANDBOX_TOKEN", "sandbox-token"),
+                ("TEST_PROFILES_PROFILE_SANDBOX_SANDBOX", "true"),
+                ("TEST_PROFILES_PROFILE_READ_ONLY_TOKEN", "readonly-token"),
+            ])
+            .add_profile(Profile::new("prod", "prod-token").with_app_name("prod-app"))
+            .load()?;
+
+        assert_eq!(config.profile_name.as_deref(), Some("sandbox"));
+        assert_eq!(config.token.expose(), "sandbox-token");
+        assert_eq!(config.endpoint, DEFAULT_SANDBOX_ENDPOINT);
+        assert_eq!(config.app_name.as_deref(), Some("base-app"));
+        assert_eq!(
+            config.profile_names().collect::<Vec<_>>(),
+            vec!["prod", "read_only", "sandbox"]
+        );
+
+        let readonly = config.named_profile("READ_ONLY")?;
+        assert_eq!(readonly.token.expose(), "readonly-token");
+        assert_eq!(readonly.endpoint, DEFAULT_ENDPOINT);
+
+        let prod = config.named_profile("prod")?;
+        assert_eq!(prod.app_name.as_deref(), Some("prod-app"));
+
+        assert!(matches!(
+            config.named_profile("default"),
+            Err(crate::Error::ConfigMissingEnv(name)) if name == "TEST_PROFILES_TOKEN"
+        ));
+        assert!(matches!(
+            config.named_profile("staging"),
+            Err(crate::Error::ConfigProfileNotFound(name)) if name == "staging"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_config_profile_token_not_inherited_err() -> Result<()> {
+        let result = ApiConfig::loader()
+            .env_prefix("TEST_INHERIT_")
+            .env_vars([
+                ("TEST_INHERIT_TOKEN", "prod-token"),
+                ("TEST_INHERIT_PROFILE", "sandbox"),
+                ("TEST_INHERIT_PROFILE_SANDBOX_SANDBOX", "true"),
+            ])
+            .load();
+
+        assert!(matches!(
+            result,
+            Err(crate::Error::ConfigMissingEnv(name)) if name == "TEST_INHERIT_PROFILE_SANDBOX_TOKEN"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_config_retry_run_ok() -> Result<()> {
+        let retry = RetryConfig {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(2),
+        };
+        let runtime = tokio::runtime::Runtime::new()?;
+
+        let mut attempts = 0;
+        let result = runtime.block_on(retry.run(|| {
+            attempts += 1;
+            let result = match attempts {
+                1 => Err(Status::unavailable("connection reset")),
+                2 => Err(Status::resource_exhausted("limit")),
+                _ => Ok(attempts),
+            };
+            async move { result }
+        }))?;
+        assert_eq!(result, 3);
+        assert_eq!(retry.backoff(1), Duration::from_millis(1));
+        assert_eq!(retry.backoff(3), Duration::from_millis(2));
+
+        // Not retried
+        let mut attempts = 0;
+        let result = runtime.block_on(retry.run(|| {
+            attempts += 1;
+            async { Err::<(), _>(Status::invalid_argument("figi")) }
+        }));
+        assert!(matches!(result, Err(crate::Error::StatusError(_))));
+        assert_eq!(attempts, 1);
+
+        // Attempts exhausted
+        let mut attempts = 0;
+        let result = runtime.block_on(retry.run(|| {
+            attempts += 1;
+            async { Err::<(), _>(Status::unavailable("down")) }
+        }));
+        assert!(result.is_err());
+        assert_eq!(attempts, 3);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "config-file")]
+    #[test]
+    fn test_config_file_wrong_format_err() -> Result<()> {
+        let path = env::temp_dir().join(format!(
+            "investments-tinkoff-test-wrong-config-{}.toml",
+            uuid::Uuid::new_v4()
+        ));
+        std::fs::write(&path, "connect_timeout_ms = \"soon\"")?;
+
+        let result = ApiConfig::loader()
+            .file(&path)
+            .env_prefix("TEST_WRONG_FILE_")
+            .env_vars([("TEST_WRONG_FILE_TOKEN", "token")])
+            .load();
+        std::fs::remove_file(&path)?;
+
+        assert!(matches!(
+            result,
+            Err(crate::Error::ConfigWrongFormat(message))
+                if message.contains("connect_timeout_ms") && message.contains("invalid type")
+        ));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "config-file")]
+    #[test]
+    fn test_config_file_layers_ok() -> Result<()> {
+        let path = env::temp_dir().join(format!(
+            "investments-tinkoff-test-config-{}.toml",
+            uuid::Uuid::new_v4()
+        ));
+        std::fs::write(
+            &path,
+            r#"
+            token = "file-token"
+            endpoint = "https://file.example:443/"
+            connect_timeout_ms = 1500
+
+            [rate_limit]
+            requests_per_minute = 100
+
+            [profiles.readonly]
+            token = "readonly-token"
+            "#,
+        )?;
+
+        let config = ApiConfig::loader()
+            .file(&path)
+            .env_prefix("TEST_FILE_")
+            .env_vars([("TEST_FILE_API", "https://env.example:443/")])
+            .load()?;
+
+        assert_eq!(config.token.expose(), "file-token");
+        assert_eq!(config.endpoint, "https://env.example:443/");
+        assert_eq!(config.connect_timeout, Some(Duration::from_millis(1500)));
+        assert_eq!(config.rate_limit.requests_per_minute, Some(100));
+
+        let readonly = config.named_profile("readonly")?;
+        assert_eq!(readonly.token.expose(), "readonly-token");
+        assert_eq!(readonly.endpoint, "https://env.example:443/");
+
+        std::fs::remove_file(path)?;
+
+        Ok(())
+    }
 }
+
+// endregion: --- Tests
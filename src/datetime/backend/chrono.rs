@@ -0,0 +1,29 @@
+use ::chrono::{DateTime, Local, Offset, TimeZone, Utc};
+use prost_types::Timestamp;
+
+pub(crate) fn parse_rfc3339(value: &str) -> Option<Timestamp> {
+    let datetime = DateTime::parse_from_rfc3339(value).ok()?;
+
+    Some(Timestamp {
+        seconds: datetime.timestamp(),
+        nanos: datetime.timestamp_subsec_nanos() as i32,
+    })
+}
+
+/// Returns offset of local time zone at UTC time, zero if it is out of range
+pub(crate) fn local_offset(seconds: i64) -> i32 {
+    DateTime::<Utc>::from_timestamp(seconds, 0)
+        .map(|datetime| Local.offset_from_utc_datetime(&datetime.naive_utc()))
+        .map(|offset| offset.fix().local_minus_utc())
+        .unwrap_or_default()
+}
+
+/// Converts local seconds to UTC, earliest time is taken for ambiguous local time
+pub(crate) fn resolve_local(local: i64) -> Option<i64> {
+    let local = DateTime::<Utc>::from_timestamp(local, 0)?.naive_utc();
+
+    Local
+        .from_local_datetime(&local)
+        .earliest()
+        .map(|datetime| datetime.timestamp())
+}
@@ -0,0 +1,14 @@
+//! Calendar library for RFC3339 parsing and local time zone
+//!
+//! `chrono` is used if it is enabled, also when both `datetime` and `datetime-time` are on,
+//! otherwise `time` with `datetime-time` feature.
+
+#[cfg(feature = "chrono")]
+mod chrono;
+#[cfg(not(feature = "chrono"))]
+mod time;
+
+#[cfg(feature = "chrono")]
+pub(super) use self::chrono::{local_offset, parse_rfc3339, resolve_local};
+#[cfg(not(feature = "chrono"))]
+pub(super) use self::time::{local_offset, parse_rfc3339, resolve_local};
@@ -0,0 +1,29 @@
+use ::time::{format_description::well_known::Rfc3339, OffsetDateTime, UtcOffset};
+use prost_types::Timestamp;
+
+pub(crate) fn parse_rfc3339(value: &str) -> Option<Timestamp> {
+    let datetime = OffsetDateTime::parse(value, &Rfc3339).ok()?;
+
+    Some(Timestamp {
+        seconds: datetime.unix_timestamp(),
+        nanos: datetime.nanosecond() as i32,
+    })
+}
+
+/// Returns offset of local time zone at UTC time
+///
+/// `time` may fail to determine local offset, e.g. in multithreaded programs on Unix, UTC is used then.
+pub(crate) fn local_offset(seconds: i64) -> i32 {
+    OffsetDateTime::from_unix_timestamp(seconds)
+        .ok()
+        .and_then(|datetime| UtcOffset::local_offset_at(datetime).ok())
+        .map(UtcOffset::whole_seconds)
+        .unwrap_or_default()
+}
+
+/// Converts local seconds to UTC, offset is taken at the resulting UTC time
+pub(crate) fn resolve_local(local: i64) -> Option<i64> {
+    let guess = local.checked_sub(local_offset(local) as i64)?;
+
+    local.checked_sub(local_offset(guess) as i64)
+}
@@ -0,0 +1,112 @@
+//! Proleptic Gregorian calendar arithmetic on days since Unix epoch
+//!
+//! Algorithms are from <http://howardhinnant.github.io/date_algorithms.html>.
+
+/// Returns days since epoch for valid date
+pub(super) fn days_from_date(year: i32, month: u32, day: u32) -> Option<i64> {
+    if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+        return None;
+    }
+
+    let year = year as i64 - (month <= 2) as i64;
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = month as i64;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    Some(era * 146097 + day_of_era - 719468)
+}
+
+/// Returns year, month and day for days since epoch
+pub(super) fn date_from_days(days: i64) -> (i32, u32, u32) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days.rem_euclid(146097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + (month <= 2) as i64;
+
+    (year as i32, month as u32, day as u32)
+}
+
+pub(super) fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Adds months to year and month, `None` on year overflow
+pub(super) fn add_months(year: i32, month: u32, months: i32) -> Option<(i32, u32)> {
+    let total = year as i64 * 12 + month as i64 - 1 + months as i64;
+    let year = i32::try_from(total.div_euclid(12)).ok()?;
+
+    Some((year, total.rem_euclid(12) as u32 + 1))
+}
+
+/// Returns day of week for days since epoch, 0 for Monday
+pub(super) fn weekday_from_monday(days: i64) -> i64 {
+    // 1970-01-01 is Thursday
+    (days + 3).rem_euclid(7)
+}
+
+/// Parses `YYYY-MM-DD` date
+pub(super) fn parse_date(value: &str) -> Option<(i32, u32, u32)> {
+    let mut parts = value.splitn(3, '-');
+    let (year, month, day) = (parts.next()?, parts.next()?, parts.next()?);
+
+    if year.len() != 4 || month.len() != 2 || day.len() != 2 {
+        return None;
+    }
+    if !value
+        .bytes()
+        .all(|byte| byte.is_ascii_digit() || byte == b'-')
+    {
+        return None;
+    }
+
+    Some((year.parse().ok()?, month.parse().ok()?, day.parse().ok()?))
+}
+
+/// Formats days since epoch as `YYYY-MM-DD`
+pub(super) fn format_date(days: i64) -> String {
+    let (year, month, day) = date_from_days(days);
+
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+/// Formats nanoseconds as fraction of second with 3, 6 or 9 digits, empty for whole seconds
+pub(super) fn format_fraction(nanos: u32) -> String {
+    if nanos == 0 {
+        String::new()
+    } else if nanos.is_multiple_of(1_000_000) {
+        format!(".{:03}", nanos / 1_000_000)
+    } else if nanos.is_multiple_of(1_000) {
+        format!(".{:06}", nanos / 1_000)
+    } else {
+        format!(".{nanos:09}")
+    }
+}
+
+/// Formats offset in seconds as `+HH:MM`
+pub(super) fn format_offset(offset: i32) -> String {
+    let sign = if offset < 0 { '-' } else { '+' };
+    let offset = offset.unsigned_abs();
+
+    format!("{sign}{:02}:{:02}", offset / 3600, offset % 3600 / 60)
+}
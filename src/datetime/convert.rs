@@ -0,0 +1,207 @@
+//! Conversions between `DateTime` and std, chrono and time types
+
+use super::{DateTime, Error};
+use prost_types::Timestamp;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const NANOS_PER_SECOND: i32 = 1_000_000_000;
+
+// region:    --- Std
+
+impl From<SystemTime> for DateTime {
+    fn from(time: SystemTime) -> Self {
+        match time.duration_since(UNIX_EPOCH) {
+            Ok(duration) => DateTime::default() + duration,
+            Err(err) => DateTime::default() - err.duration(),
+        }
+    }
+}
+
+impl TryFrom<DateTime> for SystemTime {
+    type Error = Error;
+
+    fn try_from(datetime: DateTime) -> Result<Self, Self::Error> {
+        let Timestamp { seconds, nanos } = normalize(datetime.timestamp);
+        let since_epoch = Duration::new(seconds.unsigned_abs(), 0);
+
+        let time = if seconds >= 0 {
+            UNIX_EPOCH.checked_add(since_epoch)
+        } else {
+            UNIX_EPOCH.checked_sub(since_epoch)
+        };
+
+        time.and_then(|time| time.checked_add(Duration::from_nanos(nanos as u64)))
+            .ok_or(Error::TimestampConversionError)
+    }
+}
+
+impl Add<Duration> for DateTime {
+    type Output = DateTime;
+
+    fn add(self, duration: Duration) -> Self::Output {
+        let timestamp = Timestamp {
+            seconds: self
+                .timestamp
+                .seconds
+                .saturating_add(i64::try_from(duration.as_secs()).unwrap_or(i64::MAX)),
+            nanos: self.timestamp.nanos + duration.subsec_nanos() as i32,
+        };
+
+        DateTime::from_timestamp(normalize(timestamp))
+    }
+}
+
+impl Sub<Duration> for DateTime {
+    type Output = DateTime;
+
+    fn sub(self, duration: Duration) -> Self::Output {
+        let timestamp = Timestamp {
+            seconds: self
+                .timestamp
+                .seconds
+                .saturating_sub(i64::try_from(duration.as_secs()).unwrap_or(i64::MAX)),
+            nanos: self.timestamp.nanos - duration.subsec_nanos() as i32,
+        };
+
+        DateTime::from_timestamp(normalize(timestamp))
+    }
+}
+
+impl AddAssign<Duration> for DateTime {
+    fn add_assign(&mut self, duration: Duration) {
+        *self = self.clone() + duration;
+    }
+}
+
+impl SubAssign<Duration> for DateTime {
+    fn sub_assign(&mut self, duration: Duration) {
+        *self = self.clone() - duration;
+    }
+}
+
+/// Returns `(is_negative, duration)` between timestamps, negative if `rhs` is later
+impl Sub<DateTime> for DateTime {
+    type Output = (bool, Duration);
+
+    fn sub(self, rhs: DateTime) -> Self::Output {
+        match self.duration_since(&rhs) {
+            Some(duration) => (false, duration),
+            None => (true, rhs.duration_since(&self).unwrap_or_default()),
+        }
+    }
+}
+
+impl DateTime {
+    /// Returns duration since `earlier`, `None` if it is later
+    pub fn duration_since(&self, earlier: &DateTime) -> Option<Duration> {
+        let left = normalize(self.timestamp);
+        let right = normalize(earlier.timestamp);
+
+        let mut seconds = left.seconds.checked_sub(right.seconds)?;
+        let mut nanos = left.nanos - right.nanos;
+        if nanos < 0 {
+            seconds -= 1;
+            nanos += NANOS_PER_SECOND;
+        }
+
+        u64::try_from(seconds)
+            .ok()
+            .map(|seconds| Duration::new(seconds, nanos as u32))
+    }
+}
+
+/// Moves nanoseconds into `0..1_000_000_000`
+fn normalize(timestamp: Timestamp) -> Timestamp {
+    let nanos = timestamp.nanos as i64;
+
+    Timestamp {
+        seconds: timestamp
+            .seconds
+            .saturating_add(nanos.div_euclid(NANOS_PER_SECOND as i64)),
+        nanos: nanos.rem_euclid(NANOS_PER_SECOND as i64) as i32,
+    }
+}
+
+// endregion: --- Std
+
+// region:    --- Chrono
+
+#[cfg(feature = "chrono")]
+impl From<chrono::DateTime<chrono::Utc>> for DateTime {
+    fn from(datetime: chrono::DateTime<chrono::Utc>) -> Self {
+        DateTime::from_timestamp(Timestamp {
+            seconds: datetime.timestamp(),
+            nanos: datetime.timestamp_subsec_nanos() as i32,
+        })
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<DateTime> for chrono::DateTime<chrono::Utc> {
+    type Error = Error;
+
+    fn try_from(datetime: DateTime) -> Result<Self, Self::Error> {
+        let Timestamp { seconds, nanos } = normalize(datetime.timestamp);
+
+        chrono::DateTime::from_timestamp(seconds, nanos as u32)
+            .ok_or(Error::TimestampConversionError)
+    }
+}
+
+/// Naive date and time is taken as UTC
+#[cfg(feature = "chrono")]
+impl From<chrono::NaiveDateTime> for DateTime {
+    fn from(datetime: chrono::NaiveDateTime) -> Self {
+        DateTime::from(datetime.and_utc())
+    }
+}
+
+/// Returns UTC date and time
+#[cfg(feature = "chrono")]
+impl TryFrom<DateTime> for chrono::NaiveDateTime {
+    type Error = Error;
+
+    fn try_from(datetime: DateTime) -> Result<Self, Self::Error> {
+        chrono::DateTime::<chrono::Utc>::try_from(datetime).map(|datetime| datetime.naive_utc())
+    }
+}
+
+// endregion: --- Chrono
+
+// region:    --- Time
+
+#[cfg(feature = "datetime-time")]
+impl From<time::OffsetDateTime> for DateTime {
+    fn from(datetime: time::OffsetDateTime) -> Self {
+        DateTime::from_timestamp(Timestamp {
+            seconds: datetime.unix_timestamp(),
+            nanos: datetime.nanosecond() as i32,
+        })
+    }
+}
+
+/// Returns date and time with UTC offset
+#[cfg(feature = "datetime-time")]
+impl TryFrom<DateTime> for time::OffsetDateTime {
+    type Error = Error;
+
+    fn try_from(datetime: DateTime) -> Result<Self, Self::Error> {
+        let Timestamp { seconds, nanos } = normalize(datetime.timestamp);
+
+        time::OffsetDateTime::from_unix_timestamp(seconds)
+            .ok()
+            .and_then(|datetime| datetime.replace_nanosecond(nanos as u32).ok())
+            .ok_or(Error::TimestampConversionError)
+    }
+}
+
+/// Primitive date and time is taken as UTC
+#[cfg(feature = "datetime-time")]
+impl From<time::PrimitiveDateTime> for DateTime {
+    fn from(datetime: time::PrimitiveDateTime) -> Self {
+        DateTime::from(datetime.assume_utc())
+    }
+}
+
+// endregion: --- Time
@@ -1,8 +1,20 @@
+mod backend;
+mod civil;
+mod convert;
 mod error;
+mod range;
+mod zone;
 
-use chrono::{NaiveDate, NaiveTime, Timelike, Utc};
+#[cfg(feature = "v1")]
+use crate::api::v1::CandleInterval;
 pub use error::{Error, Result};
 use prost_types::Timestamp;
+pub use range::DateRange;
+use std::str::FromStr;
+use std::time::SystemTime;
+pub use zone::TimeZone;
+
+const DAY_SECONDS: i64 = 86400;
 
 /// Wrapper for `prost_types::Timestamp`
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
@@ -12,14 +24,7 @@ pub struct DateTime {
 
 impl PartialOrd for DateTime {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        let left = self.timestamp();
-        let right = other.timestamp();
-
-        if left.seconds == right.seconds {
-            left.nanos.partial_cmp(&right.nanos)
-        } else {
-            left.seconds.partial_cmp(&right.seconds)
-        }
+        Some(self.cmp(other))
     }
 }
 
@@ -50,36 +55,53 @@ impl core::fmt::Display for DateTime {
 impl DateTime {
     /// Creates a new `DateTime` for current time
     pub fn now() -> DateTime {
-        let now = chrono::Utc::now();
-
-        let timestamp = Timestamp {
-            seconds: now.timestamp(),
-            nanos: now.nanosecond() as i32,
-        };
-
-        DateTime { timestamp }
+        DateTime::from(SystemTime::now())
     }
 
     /// Creates a new `DateTime` from year, month and day
     pub fn from_ymd(year: i32, month: u32, day: u32) -> Option<DateTime> {
-        if let Some(date) = NaiveDate::from_ymd_opt(year, month, day) {
-            let datetime = date.and_time(NaiveTime::default());
-
-            Some(DateTime {
-                timestamp: Timestamp {
-                    seconds: datetime.and_utc().timestamp(),
-                    nanos: datetime.nanosecond() as i32,
-                },
-            })
-        } else {
-            None
-        }
+        let days = civil::days_from_date(year, month, day)?;
+
+        Some(DateTime::from_seconds(days * DAY_SECONDS))
+    }
+
+    /// Creates a new `DateTime` for start of the day in time zone
+    pub fn from_ymd_in(zone: TimeZone, year: i32, month: u32, day: u32) -> Option<DateTime> {
+        let days = civil::days_from_date(year, month, day)?;
+
+        zone.resolve_local(days * DAY_SECONDS)
+            .map(DateTime::from_seconds)
     }
 
     /// Creates a new `DateTime` from `prost_types::Timestamp`
     pub fn from_timestamp(timestamp: Timestamp) -> DateTime {
         DateTime { timestamp }
     }
+
+    /// Parses RFC3339 string, e.g. `2022-01-01T10:00:00+03:00`, or date string `YYYY-MM-DD` as UTC midnight
+    pub fn parse(value: &str) -> Result<DateTime> {
+        if let Some(timestamp) = backend::parse_rfc3339(value) {
+            return Ok(DateTime::from_timestamp(timestamp));
+        }
+
+        civil::parse_date(value)
+            .and_then(|(year, month, day)| DateTime::from_ymd(year, month, day))
+            .ok_or_else(|| Error::ParseError(value.to_string()))
+    }
+
+    fn from_seconds(seconds: i64) -> DateTime {
+        DateTime {
+            timestamp: Timestamp { seconds, nanos: 0 },
+        }
+    }
+}
+
+impl FromStr for DateTime {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        DateTime::parse(value)
+    }
 }
 
 impl DateTime {
@@ -88,6 +110,14 @@ impl DateTime {
         self.timestamp
     }
 
+    /// Returns year, month and day in time zone
+    pub fn ymd(&self, zone: TimeZone) -> (i32, u32, u32) {
+        let seconds = self.timestamp.seconds;
+        let local = seconds.saturating_add(zone.offset_at(seconds) as i64);
+
+        civil::date_from_days(local.div_euclid(DAY_SECONDS))
+    }
+
     /// Add hours, minutes and seconds to current timestamp
     pub fn add_hms(&self, hours: i64, minutes: i64, seconds: i64) -> DateTime {
         let mut timestamp = self.timestamp;
@@ -96,17 +126,40 @@ impl DateTime {
         DateTime { timestamp }
     }
 
-    /// Add year, month and day to current timestamp
-    pub fn add_ymd(&self, year: i32, month: u32, day: u32) -> DateTime {
-        let mut timestamp = self.timestamp;
+    /// Add years, months and days to current timestamp, see `add_months`
+    pub fn add_ymd(&self, years: i32, months: u32, days: u32) -> DateTime {
+        self.add_months(years * 12 + months as i32)
+            .add_days(days as i64)
+    }
+
+    /// Add calendar months in UTC, day is clamped to the end of month, e.g. Jan 31 + 1 month is Feb 28
+    pub fn add_months(&self, months: i32) -> DateTime {
+        let seconds = self.timestamp.seconds;
+        let (days, time) = (
+            seconds.div_euclid(DAY_SECONDS),
+            seconds.rem_euclid(DAY_SECONDS),
+        );
+
+        let (year, month, day) = civil::date_from_days(days);
+        let Some((year, month)) = civil::add_months(year, month, months) else {
+            return self.clone();
+        };
+        let day = day.min(civil::days_in_month(year, month));
 
-        if let Some(date) = NaiveDate::from_ymd_opt(year, month, day) {
-            timestamp.seconds += date.and_time(NaiveTime::default()).and_utc().timestamp();
+        let mut timestamp = self.timestamp;
+        match civil::days_from_date(year, month, day) {
+            Some(days) => timestamp.seconds = days * DAY_SECONDS + time,
+            None => return self.clone(),
         }
 
         DateTime { timestamp }
     }
 
+    /// Add calendar years in UTC, Feb 29 becomes Feb 28 in non-leap years
+    pub fn add_years(&self, years: i32) -> DateTime {
+        self.add_months(years * 12)
+    }
+
     /// Add minutes to current timestamp
     pub fn add_minutes(&self, minutes: i64) -> DateTime {
         let mut timestamp = self.timestamp;
@@ -140,32 +193,129 @@ impl DateTime {
     }
 }
 
+// Truncation
+impl DateTime {
+    /// Returns start of the day in time zone
+    pub fn truncate_day(&self, zone: TimeZone) -> DateTime {
+        self.truncate_in(zone, |local| local - local.rem_euclid(DAY_SECONDS))
+    }
+
+    /// Returns start of the hour in time zone, zones with 30 minutes offsets differ from UTC
+    pub fn truncate_hour(&self, zone: TimeZone) -> DateTime {
+        self.truncate_in(zone, |local| local - local.rem_euclid(3600))
+    }
+
+    /// Returns start of the week in time zone, weeks start on Monday
+    pub fn truncate_week(&self, zone: TimeZone) -> DateTime {
+        self.truncate_in(zone, |local| {
+            let days = local.div_euclid(DAY_SECONDS);
+            (days - civil::weekday_from_monday(days)) * DAY_SECONDS
+        })
+    }
+
+    /// Returns start of the month in time zone
+    pub fn truncate_month(&self, zone: TimeZone) -> DateTime {
+        self.truncate_in(zone, |local| {
+            let days = local.div_euclid(DAY_SECONDS);
+            let (_, _, day) = civil::date_from_days(days);
+            (days - day as i64 + 1) * DAY_SECONDS
+        })
+    }
+
+    /// Returns start of the year in time zone
+    pub fn truncate_year(&self, zone: TimeZone) -> DateTime {
+        self.truncate_in(zone, |local| {
+            let (year, _, _) = civil::date_from_days(local.div_euclid(DAY_SECONDS));
+            civil::days_from_date(year, 1, 1).unwrap_or_default() * DAY_SECONDS
+        })
+    }
+
+    /// Returns start of the candle containing current timestamp
+    ///
+    /// Minute and hour candles are aligned to UTC, weeks start on Monday.
+    #[cfg(feature = "v1")]
+    pub fn truncate_interval(&self, interval: CandleInterval) -> DateTime {
+        let minutes = match interval {
+            CandleInterval::Unspecified => return self.clone(),
+            CandleInterval::CandleInterval1Min => 1,
+            CandleInterval::CandleInterval2Min => 2,
+            CandleInterval::CandleInterval3Min => 3,
+            CandleInterval::CandleInterval5Min => 5,
+            CandleInterval::CandleInterval10Min => 10,
+            CandleInterval::CandleInterval15Min => 15,
+            CandleInterval::CandleInterval30Min => 30,
+            CandleInterval::Hour => 60,
+            CandleInterval::CandleInterval2Hour => 120,
+            CandleInterval::CandleInterval4Hour => 240,
+            CandleInterval::Day => return self.truncate_day(TimeZone::Utc),
+            CandleInterval::Week => return self.truncate_week(TimeZone::Utc),
+            CandleInterval::Month => return self.truncate_month(TimeZone::Utc),
+        };
+
+        self.truncate_in(TimeZone::Utc, |local| {
+            local - local.rem_euclid(minutes * 60)
+        })
+    }
+
+    /// Truncates local seconds in zone with `truncate`
+    fn truncate_in(&self, zone: TimeZone, truncate: impl FnOnce(i64) -> i64) -> DateTime {
+        let seconds = self.timestamp.seconds;
+        let local = seconds + zone.offset_at(seconds) as i64;
+
+        zone.resolve_local(truncate(local))
+            .map(DateTime::from_seconds)
+            .unwrap_or_else(|| self.clone())
+    }
+}
+
 // To String conversions
 impl DateTime {
     /// Converts `DateTime` to RFC3339 string
     pub fn to_utc_string(&self) -> Result<String> {
-        let timestamp = self.timestamp();
-
-        if let Some(utc_datetime) =
-            chrono::DateTime::<Utc>::from_timestamp(timestamp.seconds, timestamp.nanos as u32)
-        {
-            Ok(utc_datetime.to_rfc3339())
-        } else {
-            Err(Error::TimestampConversionError)
-        }
+        self.to_zone_string(TimeZone::Utc)
     }
 
     /// Converts `DateTime` to date string (YYYY-MM-DD)
     pub fn to_utc_date_string(&self) -> Result<String> {
-        let timestamp = self.timestamp();
+        self.to_zone_date_string(TimeZone::Utc)
+    }
 
-        if let Some(utc_datetime) =
-            chrono::DateTime::<Utc>::from_timestamp(timestamp.seconds, timestamp.nanos as u32)
-        {
-            Ok(utc_datetime.date_naive().to_string())
-        } else {
-            Err(Error::TimestampConversionError)
+    /// Converts `DateTime` to RFC3339 string with offset of time zone
+    pub fn to_zone_string(&self, zone: TimeZone) -> Result<String> {
+        let (local, nanos, offset) = self.to_local(zone)?;
+        let time = local.rem_euclid(DAY_SECONDS);
+
+        Ok(format!(
+            "{}T{:02}:{:02}:{:02}{}{}",
+            civil::format_date(local.div_euclid(DAY_SECONDS)),
+            time / 3600,
+            time % 3600 / 60,
+            time % 60,
+            civil::format_fraction(nanos),
+            civil::format_offset(offset)
+        ))
+    }
+
+    /// Converts `DateTime` to date string (YYYY-MM-DD) in time zone
+    pub fn to_zone_date_string(&self, zone: TimeZone) -> Result<String> {
+        let (local, _, _) = self.to_local(zone)?;
+
+        Ok(civil::format_date(local.div_euclid(DAY_SECONDS)))
+    }
+
+    /// Returns local seconds, nanoseconds and offset of time zone
+    fn to_local(&self, zone: TimeZone) -> Result<(i64, u32, i32)> {
+        let Timestamp { seconds, nanos } = self.timestamp;
+        if !(0..1_000_000_000).contains(&nanos) {
+            return Err(Error::TimestampConversionError);
         }
+
+        let offset = zone.offset_at(seconds);
+        let local = seconds
+            .checked_add(offset as i64)
+            .ok_or(Error::TimestampConversionError)?;
+
+        Ok((local, nanos as u32, offset))
     }
 }
 
@@ -225,6 +375,158 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_add_months_clamps_ok() -> Result<()> {
+        let datetime = DateTime::parse("2024-01-31T10:00:00Z")?;
+
+        assert_eq!(
+            datetime.add_months(1).to_utc_string()?,
+            "2024-02-29T10:00:00+00:00"
+        );
+        assert_eq!(datetime.add_months(-2).to_utc_date_string()?, "2023-11-30");
+        assert_eq!(
+            DateTime::parse("2024-02-29")?
+                .add_ymd(1, 0, 1)
+                .to_utc_date_string()?,
+            "2025-03-01"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_moscow_time_ok() -> Result<()> {
+        let datetime: DateTime = "2022-01-01T22:30:00+00:00".parse()?;
+
+        assert_eq!(
+            datetime.to_zone_string(TimeZone::Moscow)?,
+            "2022-01-02T01:30:00+03:00"
+        );
+        assert_eq!(
+            datetime.to_zone_date_string(TimeZone::Moscow)?,
+            "2022-01-02"
+        );
+        assert_eq!(
+            datetime.truncate_day(TimeZone::Moscow),
+            DateTime::parse("2022-01-01T21:00:00Z")?
+        );
+        assert_eq!(
+            DateTime::from_ymd_in(TimeZone::Moscow, 2022, 1, 2),
+            Some(DateTime::parse("2022-01-01T21:00:00Z")?)
+        );
+        assert!(DateTime::parse("01.01.2022").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_moscow_time_history_ok() -> Result<()> {
+        let cases = [
+            ("2010-01-15T12:00:00Z", "2010-01-15T15:00:00+03:00"),
+            ("2010-07-15T12:00:00Z", "2010-07-15T16:00:00+04:00"),
+            // Last Sunday of March 2010 is 28th
+            ("2010-03-27T22:59:59Z", "2010-03-28T01:59:59+03:00"),
+            ("2010-03-27T23:00:00Z", "2010-03-28T03:00:00+04:00"),
+            ("2012-01-15T12:00:00Z", "2012-01-15T16:00:00+04:00"),
+            ("2014-10-25T21:59:59Z", "2014-10-26T01:59:59+04:00"),
+            ("2014-10-25T22:00:00Z", "2014-10-26T01:00:00+03:00"),
+            ("2015-07-15T12:00:00Z", "2015-07-15T15:00:00+03:00"),
+        ];
+
+        for (utc, moscow) in cases {
+            assert_eq!(DateTime::parse(utc)?.to_zone_string(TimeZone::Moscow)?, moscow);
+        }
+
+        assert_eq!(
+            DateTime::from_ymd_in(TimeZone::Moscow, 2012, 6, 1),
+            Some(DateTime::parse("2012-05-31T20:00:00Z")?)
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_truncate_interval_ok() -> Result<()> {
+        // Wednesday
+        let datetime = DateTime::parse("2022-01-05T10:47:12.5Z")?;
+
+        let truncated = |interval| datetime.truncate_interval(interval).to_utc_string();
+
+        assert_eq!(
+            truncated(CandleInterval::CandleInterval15Min)?,
+            "2022-01-05T10:45:00+00:00"
+        );
+        assert_eq!(
+            truncated(CandleInterval::CandleInterval4Hour)?,
+            "2022-01-05T08:00:00+00:00"
+        );
+        assert_eq!(
+            truncated(CandleInterval::Week)?,
+            "2022-01-03T00:00:00+00:00"
+        );
+        assert_eq!(
+            truncated(CandleInterval::Month)?,
+            "2022-01-01T00:00:00+00:00"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_datetime_conversions_ok() -> Result<()> {
+        use std::time::{Duration, UNIX_EPOCH};
+
+        let datetime = DateTime::parse("1969-12-31T23:59:58.750Z")?;
+        let time = SystemTime::try_from(datetime.clone())?;
+
+        assert_eq!(
+            UNIX_EPOCH.duration_since(time)?,
+            Duration::from_millis(1250)
+        );
+        assert_eq!(DateTime::from(time), datetime);
+
+        let later = datetime.clone() + Duration::from_millis(1500);
+        assert_eq!(later.to_utc_string()?, "1970-01-01T00:00:00.250+00:00");
+        assert_eq!(
+            later.duration_since(&datetime),
+            Some(Duration::from_millis(1500))
+        );
+        assert_eq!(datetime.duration_since(&later), None);
+        assert_eq!(
+            later.clone() - datetime.clone(),
+            (false, Duration::from_millis(1500))
+        );
+        assert_eq!(
+            datetime.clone() - later.clone(),
+            (true, Duration::from_millis(1500))
+        );
+        assert_eq!(
+            DateTime::default() + Duration::from_secs(u64::MAX),
+            DateTime::from_timestamp(Timestamp {
+                seconds: i64::MAX,
+                nanos: 0
+            })
+        );
+        assert_eq!(later - Duration::from_millis(1500), datetime);
+
+        #[cfg(feature = "chrono")]
+        {
+            let utc = chrono::DateTime::<chrono::Utc>::try_from(datetime.clone())?;
+            assert_eq!(utc.to_rfc3339(), "1969-12-31T23:59:58.750+00:00");
+            assert_eq!(DateTime::from(utc.naive_utc()), datetime);
+        }
+
+        #[cfg(feature = "datetime-time")]
+        {
+            let offset = time::OffsetDateTime::try_from(datetime.clone())?;
+            assert_eq!(offset.millisecond(), 750);
+            assert_eq!(DateTime::from(offset), datetime);
+        }
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_datetime_now_ok() -> Result<()> {
         let now = DateTime::now();
@@ -0,0 +1,219 @@
+//! Request windows with `from` and `to` timestamps
+
+use super::{DateTime, TimeZone};
+use std::time::Duration;
+
+/// Period from `from` (inclusive) to `to` (exclusive)
+///
+/// Fills `from` and `to` of requests, e.g. `GetCandlesRequest { interval, ..range.into() }`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateRange {
+    pub from: DateTime,
+    pub to: DateTime,
+}
+
+// Constructors
+impl DateRange {
+    pub fn new(from: DateTime, to: DateTime) -> Self {
+        Self { from, to }
+    }
+
+    /// Period of `days` days until now
+    pub fn last_n_days(days: i64) -> Self {
+        let now = DateTime::now();
+
+        Self::new(now.add_days(-days), now)
+    }
+
+    /// Period from start of the day in time zone until now
+    pub fn today(zone: TimeZone) -> Self {
+        let now = DateTime::now();
+
+        Self::new(now.truncate_day(zone), now)
+    }
+
+    /// Period from start of the year in time zone until now
+    pub fn year_to_date(zone: TimeZone) -> Self {
+        let now = DateTime::now();
+
+        Self::new(now.truncate_year(zone), now)
+    }
+}
+
+impl DateRange {
+    /// Returns `true` if `from` is not before `to`
+    pub fn is_empty(&self) -> bool {
+        self.from >= self.to
+    }
+
+    pub fn contains(&self, at: &DateTime) -> bool {
+        &self.from <= at && at < &self.to
+    }
+
+    /// Returns zero for empty range
+    pub fn duration(&self) -> Duration {
+        self.to.duration_since(&self.from).unwrap_or_default()
+    }
+
+    /// Splits range by calendar days in time zone, first and last days are cut to the range
+    pub fn days(&self, zone: TimeZone) -> impl Iterator<Item = DateRange> {
+        self.split_by(move |at| at.truncate_day(zone).add_hours(36).truncate_day(zone))
+    }
+
+    /// Splits range by weeks starting on Monday in time zone
+    pub fn weeks(&self, zone: TimeZone) -> impl Iterator<Item = DateRange> {
+        self.split_by(move |at| {
+            at.truncate_week(zone)
+                .add_days(7)
+                .add_hours(12)
+                .truncate_day(zone)
+        })
+    }
+
+    /// Splits range by calendar months in time zone
+    pub fn months(&self, zone: TimeZone) -> impl Iterator<Item = DateRange> {
+        self.split_by(move |at| at.truncate_month(zone).add_days(32).truncate_month(zone))
+    }
+
+    /// Splits range into chunks no longer than `max`, e.g. maximum window of candles request
+    ///
+    /// Zero `max` returns the whole range.
+    pub fn chunks(&self, max: Duration) -> impl Iterator<Item = DateRange> {
+        let to = self.to.clone();
+
+        self.split_by(move |at| {
+            if max.is_zero() {
+                to.clone()
+            } else {
+                at.clone() + max
+            }
+        })
+    }
+
+    /// Splits range at boundaries returned by `next` for start of each part
+    fn split_by(&self, next: impl Fn(&DateTime) -> DateTime) -> impl Iterator<Item = DateRange> {
+        let mut from = self.from.clone();
+        let to = self.to.clone();
+
+        std::iter::from_fn(move || {
+            if from >= to {
+                return None;
+            }
+
+            // Boundary is always moved forward, so iteration ends
+            let boundary = next(&from)
+                .max(from.clone() + Duration::from_nanos(1))
+                .min(to.clone());
+            let part = DateRange::new(from.clone(), boundary.clone());
+            from = boundary;
+
+            Some(part)
+        })
+    }
+}
+
+// region:    --- Requests
+
+/// Creates request with `from` and `to` of range and default other fields
+#[cfg(feature = "v1")]
+macro_rules! impl_from_range {
+    ($($request:ident),* $(,)?) => {
+        $(
+            impl From<DateRange> for crate::api::v1::$request {
+                fn from(range: DateRange) -> Self {
+                    Self {
+                        from: Some(range.from.timestamp()),
+                        to: Some(range.to.timestamp()),
+                        ..Default::default()
+                    }
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "v1")]
+impl_from_range!(
+    GetCandlesRequest,
+    GetLastTradesRequest,
+    GetTechAnalysisRequest,
+    OperationsRequest,
+    GetOperationsByCursorRequest,
+    GenerateBrokerReportRequest,
+    GenerateDividendsForeignIssuerReportRequest,
+    GetDividendsRequest,
+    GetBondCouponsRequest,
+    GetBondEventsRequest,
+    GetAccruedInterestsRequest,
+    GetAssetReportsRequest,
+    TradingSchedulesRequest,
+    GetSignalsRequest,
+    GetStopOrdersRequest,
+);
+
+// endregion: --- Requests
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    type Error = Box<dyn std::error::Error>;
+    type Result<T> = core::result::Result<T, Error>; // For tests.
+
+    use super::*;
+
+    fn range(from: &str, to: &str) -> Result<DateRange> {
+        Ok(DateRange::new(from.parse()?, to.parse()?))
+    }
+
+    fn bounds(parts: impl Iterator<Item = DateRange>) -> Vec<(String, String)> {
+        parts
+            .map(|part| (part.from.to_string(), part.to.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_date_range_split_ok() -> Result<()> {
+        let range = range("2024-01-30T12:00:00Z", "2024-02-01T06:00:00Z")?;
+
+        // Moscow days start at 21:00 UTC
+        assert_eq!(
+            bounds(range.days(TimeZone::Moscow)),
+            [
+                ("2024-01-30T12:00:00+00:00", "2024-01-30T21:00:00+00:00"),
+                ("2024-01-30T21:00:00+00:00", "2024-01-31T21:00:00+00:00"),
+                ("2024-01-31T21:00:00+00:00", "2024-02-01T06:00:00+00:00"),
+            ]
+            .map(|(from, to)| (from.to_string(), to.to_string()))
+        );
+        assert_eq!(range.months(TimeZone::Utc).count(), 2);
+        assert_eq!(range.weeks(TimeZone::Utc).count(), 1);
+
+        let chunks: Vec<_> = range.chunks(Duration::from_secs(86400)).collect();
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[1].duration(), Duration::from_secs(18 * 3600));
+        assert_eq!(range.chunks(Duration::ZERO).count(), 1);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_date_range_into_request_ok() -> Result<()> {
+        use crate::api::v1::GetCandlesRequest;
+
+        let range = range("2024-01-01", "2024-01-02")?;
+        let request = GetCandlesRequest {
+            limit: Some(10),
+            ..range.clone().into()
+        };
+
+        assert_eq!(request.from, Some(range.from.timestamp()));
+        assert_eq!(request.to, Some(range.to.timestamp()));
+        assert_eq!(request.limit, Some(10));
+
+        Ok(())
+    }
+}
+
+// endregion: --- Tests
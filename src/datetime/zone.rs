@@ -0,0 +1,75 @@
+use super::{backend, civil};
+
+const HOUR_SECONDS: i32 = 3600;
+const DAY_SECONDS: i64 = 86400;
+/// 2011-03-27 02:00 MSK, Moscow time became permanent UTC+4
+const MOSCOW_PERMANENT_DST: i64 = 1_301_180_400;
+/// 2014-10-26 02:00 MSK, Moscow time became permanent UTC+3
+const MOSCOW_PERMANENT_STANDARD: i64 = 1_414_274_400;
+
+/// Time zone for conversions, formatting and truncation
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum TimeZone {
+    #[default]
+    Utc,
+    /// `Europe/Moscow`, exchange time of MOEX
+    ///
+    /// UTC+3 since 26 Oct 2014, UTC+4 from 27 Mar 2011, UTC+3 with daylight saving time on
+    /// last Sundays of March and October before. Rules before 1993 were different, but are
+    /// not taken into account.
+    Moscow,
+    /// Time zone of the system
+    Local,
+}
+
+impl TimeZone {
+    /// Returns offset from UTC in seconds at UTC time
+    pub(super) fn offset_at(self, seconds: i64) -> i32 {
+        match self {
+            TimeZone::Utc => 0,
+            TimeZone::Moscow => moscow_offset(seconds),
+            TimeZone::Local => backend::local_offset(seconds),
+        }
+    }
+
+    /// Converts local time in this zone to UTC, earliest time is taken for ambiguous local time
+    pub(super) fn resolve_local(self, local: i64) -> Option<i64> {
+        match self {
+            TimeZone::Utc => Some(local),
+            // Daylight saving time is tried first, so earliest time is taken
+            TimeZone::Moscow => [4 * HOUR_SECONDS, 3 * HOUR_SECONDS]
+                .into_iter()
+                .filter_map(|offset| local.checked_sub(offset as i64))
+                .find(|&utc| local - utc == moscow_offset(utc) as i64),
+            TimeZone::Local => backend::resolve_local(local),
+        }
+    }
+}
+
+/// Returns offset of Moscow time at UTC time
+fn moscow_offset(seconds: i64) -> i32 {
+    if seconds >= MOSCOW_PERMANENT_STANDARD {
+        return 3 * HOUR_SECONDS;
+    }
+    if seconds >= MOSCOW_PERMANENT_DST {
+        return 4 * HOUR_SECONDS;
+    }
+
+    // Daylight saving time from 02:00 MSK of last Sunday of March
+    // to 03:00 MSD of last Sunday of October, both are 23:00 UTC of previous day
+    let (year, _, _) = civil::date_from_days(seconds.div_euclid(DAY_SECONDS));
+    let starts = last_sunday(year, 3).map(|days| days * DAY_SECONDS - HOUR_SECONDS as i64);
+    let ends = last_sunday(year, 10).map(|days| days * DAY_SECONDS - HOUR_SECONDS as i64);
+
+    match (starts, ends) {
+        (Some(starts), Some(ends)) if (starts..ends).contains(&seconds) => 4 * HOUR_SECONDS,
+        _ => 3 * HOUR_SECONDS,
+    }
+}
+
+/// Returns days since epoch of last Sunday of month
+fn last_sunday(year: i32, month: u32) -> Option<i64> {
+    let last = civil::days_from_date(year, month, civil::days_in_month(year, month))?;
+
+    Some(last - (civil::weekday_from_monday(last) + 1) % 7)
+}
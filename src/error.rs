@@ -2,24 +2,57 @@
 
 use derive_more::derive::From;
 
-#[cfg(feature = "datetime")]
+#[cfg(any(feature = "datetime", feature = "datetime-time"))]
 use crate::datetime;
+#[cfg(feature = "export")]
+use crate::export;
+#[cfg(all(feature = "v1", any(feature = "datetime", feature = "datetime-time")))]
+use crate::risk;
 
 pub type Result<T> = core::result::Result<T, Error>;
 
 #[derive(Debug, From)]
 pub enum Error {
     // -- Config
-    ConfigMissingEnv(&'static str),
-    ConfigWrongFormat(&'static str),
+    ConfigMissingEnv(String),
+    ConfigWrongFormat(String),
+    ConfigFileNotFound(String),
+    ConfigProfileNotFound(String),
+    /// Token file cannot be read, with kind of IO error
+    TokenFileError {
+        path: String,
+        kind: std::io::ErrorKind,
+    },
+
+    // -- Channel
+    InvalidUrl(String),
+    CertificateFileNotFound(String),
+    /// All dedicated connections of pool are in use, with limit
+    DedicatedChannelLimit(usize),
+
+    // -- Api
+    WrongDecimalFormat(String),
+    AccountAccessDenied(String),
+
+    // -- Analytics
+    /// Negative target weight or weights summing above 1.0
+    InvalidTargetWeights(String),
 
     // -- Modules
-    #[cfg(feature = "datetime")]
+    #[cfg(any(feature = "datetime", feature = "datetime-time"))]
+    #[from]
     DatetimeError(datetime::Error),
+    #[cfg(feature = "export")]
+    #[from]
+    ExportError(export::Error),
+    #[cfg(all(feature = "v1", any(feature = "datetime", feature = "datetime-time")))]
+    #[from]
+    RiskError(risk::Error),
 
     // -- Externals
     #[from]
     TransportError(tonic::transport::Error),
+    /// Large, so modules returning `Result` allow `clippy::result_large_err`
     #[from]
     StatusError(tonic::Status),
 }
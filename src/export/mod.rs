@@ -0,0 +1,120 @@
+//! Export of API collections to CSV
+//!
+//! Money columns are written as exact decimal strings with a separate currency column,
+//! times are written as RFC3339 strings in UTC.
+//!
+//! Parquet is not supported, CSV output can be converted with external tools.
+
+// region:    --- Modules
+
+mod error;
+mod records;
+
+pub use error::{Error, Result};
+
+use std::{fs::File, io::Write, path::Path};
+
+// endregion: --- Modules
+
+/// Type that can be written as a single CSV row
+pub trait CsvRecord {
+    /// Column names, written as the first row
+    fn headers() -> &'static [&'static str];
+
+    /// Row values, must have the same length as `headers`
+    fn record(&self) -> Vec<String>;
+}
+
+/// Writes items as CSV with header row into writer
+pub fn write_csv<'a, T, W>(writer: W, items: impl IntoIterator<Item = &'a T>) -> Result<()>
+where
+    T: CsvRecord + 'a,
+    W: Write,
+{
+    let mut writer = csv::Writer::from_writer(writer);
+
+    writer.write_record(T::headers())?;
+
+    for item in items {
+        writer.write_record(item.record())?;
+    }
+
+    writer.flush()?;
+
+    Ok(())
+}
+
+/// Writes items as CSV with header row into file, file is created or truncated
+pub fn to_csv_file<'a, T>(
+    path: impl AsRef<Path>,
+    items: impl IntoIterator<Item = &'a T>,
+) -> Result<()>
+where
+    T: CsvRecord + 'a,
+{
+    write_csv(File::create(path)?, items)
+}
+
+/// Writes items as CSV with header row into string
+pub fn to_csv_string<'a, T>(items: impl IntoIterator<Item = &'a T>) -> Result<String>
+where
+    T: CsvRecord + 'a,
+{
+    let mut buffer = Vec::new();
+    write_csv(&mut buffer, items)?;
+
+    String::from_utf8(buffer).map_err(|_| Error::NotUtf8Output)
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    type Error = Box<dyn std::error::Error>;
+    type Result<T> = core::result::Result<T, Error>; // For tests.
+
+    use super::*;
+    use crate::api::v1::{HistoricCandle, MoneyValue, Quotation};
+    use prost_types::Timestamp;
+
+    #[test]
+    fn test_candles_to_csv_ok() -> Result<()> {
+        let fx_result = "time,open,high,low,close,volume,is_complete,candle_source\n\
+            2022-01-01T00:00:00+00:00,100.5,101,99.25,100,42,true,CANDLE_SOURCE_EXCHANGE\n";
+
+        let candle = HistoricCandle {
+            open: Some(Quotation::new(100, 500_000_000)),
+            high: Some(Quotation::new(101, 0)),
+            low: Some(Quotation::new(99, 250_000_000)),
+            close: Some(Quotation::new(100, 0)),
+            volume: 42,
+            time: Some(Timestamp {
+                seconds: 1640995200,
+                nanos: 0,
+            }),
+            is_complete: true,
+            candle_source_type: 1,
+        };
+
+        assert_eq!(to_csv_string(&[candle])?, fx_result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_empty_money_columns_ok() -> Result<()> {
+        let dividend = crate::api::v1::Dividend {
+            dividend_net: Some(MoneyValue::new("rub", 12, 0)),
+            ..Default::default()
+        };
+
+        let csv = to_csv_string(&[dividend])?;
+        let row = csv.lines().nth(1).ok_or("missing row")?;
+
+        assert!(row.starts_with("12,rub,,"));
+
+        Ok(())
+    }
+}
+
+// endregion: --- Tests
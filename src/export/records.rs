@@ -0,0 +1,373 @@
+// region:    --- Modules
+
+use super::CsvRecord;
+use crate::api::v1::{
+    BrokerReport, CandleSource, Coupon, CouponType, Dividend, HistoricCandle, InstrumentType,
+    MoneyValue, OperationItem, OperationState, OperationType, PortfolioPosition, Quotation,
+};
+use crate::DateTime;
+use prost_types::Timestamp;
+
+// endregion: --- Modules
+
+// region:    --- Column Helpers
+
+fn time(value: &Option<Timestamp>) -> String {
+    value
+        .map(|timestamp| {
+            let datetime = DateTime::from_timestamp(timestamp);
+            datetime
+                .to_utc_string()
+                .unwrap_or_else(|_| datetime.to_string())
+        })
+        .unwrap_or_default()
+}
+
+fn quotation(value: &Option<Quotation>) -> String {
+    value.map(|v| v.to_decimal_string()).unwrap_or_default()
+}
+
+/// Amount and currency columns
+fn money(value: &Option<MoneyValue>) -> [String; 2] {
+    match value {
+        Some(v) => [v.to_decimal_string(), v.currency.clone()],
+        None => [String::new(), String::new()],
+    }
+}
+
+/// Proto name of the enum value, or raw number if value is unknown
+fn enumeration<E>(value: i32, name: impl Fn(E) -> &'static str) -> String
+where
+    E: TryFrom<i32>,
+{
+    E::try_from(value)
+        .map(|e| name(e).to_string())
+        .unwrap_or_else(|_| value.to_string())
+}
+
+// endregion: --- Column Helpers
+
+impl CsvRecord for HistoricCandle {
+    fn headers() -> &'static [&'static str] {
+        &[
+            "time",
+            "open",
+            "high",
+            "low",
+            "close",
+            "volume",
+            "is_complete",
+            "candle_source",
+        ]
+    }
+
+    fn record(&self) -> Vec<String> {
+        vec![
+            time(&self.time),
+            quotation(&self.open),
+            quotation(&self.high),
+            quotation(&self.low),
+            quotation(&self.close),
+            self.volume.to_string(),
+            self.is_complete.to_string(),
+            enumeration(self.candle_source_type, |e: CandleSource| e.as_str_name()),
+        ]
+    }
+}
+
+impl CsvRecord for OperationItem {
+    fn headers() -> &'static [&'static str] {
+        &[
+            "id",
+            "parent_operation_id",
+            "broker_account_id",
+            "date",
+            "type",
+            "state",
+            "name",
+            "description",
+            "instrument_uid",
+            "figi",
+            "instrument_kind",
+            "position_uid",
+            "asset_uid",
+            "quantity",
+            "quantity_rest",
+            "quantity_done",
+            "payment",
+            "payment_currency",
+            "price",
+            "price_currency",
+            "commission",
+            "commission_currency",
+            "yield",
+            "yield_currency",
+            "yield_relative",
+            "accrued_int",
+            "accrued_int_currency",
+            "cancel_date_time",
+            "cancel_reason",
+        ]
+    }
+
+    fn record(&self) -> Vec<String> {
+        let [payment, payment_currency] = money(&self.payment);
+        let [price, price_currency] = money(&self.price);
+        let [commission, commission_currency] = money(&self.commission);
+        let [yield_value, yield_currency] = money(&self.r#yield);
+        let [accrued_int, accrued_int_currency] = money(&self.accrued_int);
+
+        vec![
+            self.id.clone(),
+            self.parent_operation_id.clone(),
+            self.broker_account_id.clone(),
+            time(&self.date),
+            enumeration(self.r#type, |e: OperationType| e.as_str_name()),
+            enumeration(self.state, |e: OperationState| e.as_str_name()),
+            self.name.clone(),
+            self.description.clone(),
+            self.instrument_uid.clone(),
+            self.figi.clone(),
+            enumeration(self.instrument_kind, |e: InstrumentType| e.as_str_name()),
+            self.position_uid.clone(),
+            self.asset_uid.clone(),
+            self.quantity.to_string(),
+            self.quantity_rest.to_string(),
+            self.quantity_done.to_string(),
+            payment,
+            payment_currency,
+            price,
+            price_currency,
+            commission,
+            commission_currency,
+            yield_value,
+            yield_currency,
+            quotation(&self.yield_relative),
+            accrued_int,
+            accrued_int_currency,
+            time(&self.cancel_date_time),
+            self.cancel_reason.clone(),
+        ]
+    }
+}
+
+impl CsvRecord for PortfolioPosition {
+    fn headers() -> &'static [&'static str] {
+        &[
+            "figi",
+            "instrument_uid",
+            "position_uid",
+            "instrument_type",
+            "quantity",
+            "blocked",
+            "blocked_lots",
+            "average_position_price",
+            "average_position_price_currency",
+            "average_position_price_fifo",
+            "average_position_price_fifo_currency",
+            "current_price",
+            "current_price_currency",
+            "current_nkd",
+            "current_nkd_currency",
+            "expected_yield",
+            "expected_yield_fifo",
+            "daily_yield",
+            "daily_yield_currency",
+            "var_margin",
+            "var_margin_currency",
+        ]
+    }
+
+    fn record(&self) -> Vec<String> {
+        let [average_price, average_price_currency] = money(&self.average_position_price);
+        let [average_price_fifo, average_price_fifo_currency] =
+            money(&self.average_position_price_fifo);
+        let [current_price, current_price_currency] = money(&self.current_price);
+        let [current_nkd, current_nkd_currency] = money(&self.current_nkd);
+        let [daily_yield, daily_yield_currency] = money(&self.daily_yield);
+        let [var_margin, var_margin_currency] = money(&self.var_margin);
+
+        vec![
+            self.figi.clone(),
+            self.instrument_uid.clone(),
+            self.position_uid.clone(),
+            self.instrument_type.clone(),
+            quotation(&self.quantity),
+            self.blocked.to_string(),
+            quotation(&self.blocked_lots),
+            average_price,
+            average_price_currency,
+            average_price_fifo,
+            average_price_fifo_currency,
+            current_price,
+            current_price_currency,
+            current_nkd,
+            current_nkd_currency,
+            quotation(&self.expected_yield),
+            quotation(&self.expected_yield_fifo),
+            daily_yield,
+            daily_yield_currency,
+            var_margin,
+            var_margin_currency,
+        ]
+    }
+}
+
+impl CsvRecord for Dividend {
+    fn headers() -> &'static [&'static str] {
+        &[
+            "dividend_net",
+            "dividend_net_currency",
+            "payment_date",
+            "declared_date",
+            "last_buy_date",
+            "record_date",
+            "dividend_type",
+            "regularity",
+            "close_price",
+            "close_price_currency",
+            "yield_value",
+            "created_at",
+        ]
+    }
+
+    fn record(&self) -> Vec<String> {
+        let [dividend_net, dividend_net_currency] = money(&self.dividend_net);
+        let [close_price, close_price_currency] = money(&self.close_price);
+
+        vec![
+            dividend_net,
+            dividend_net_currency,
+            time(&self.payment_date),
+            time(&self.declared_date),
+            time(&self.last_buy_date),
+            time(&self.record_date),
+            self.dividend_type.clone(),
+            self.regularity.clone(),
+            close_price,
+            close_price_currency,
+            quotation(&self.yield_value),
+            time(&self.created_at),
+        ]
+    }
+}
+
+impl CsvRecord for Coupon {
+    fn headers() -> &'static [&'static str] {
+        &[
+            "figi",
+            "coupon_number",
+            "coupon_date",
+            "fix_date",
+            "coupon_start_date",
+            "coupon_end_date",
+            "coupon_period",
+            "coupon_type",
+            "pay_one_bond",
+            "pay_one_bond_currency",
+        ]
+    }
+
+    fn record(&self) -> Vec<String> {
+        let [pay_one_bond, pay_one_bond_currency] = money(&self.pay_one_bond);
+
+        vec![
+            self.figi.clone(),
+            self.coupon_number.to_string(),
+            time(&self.coupon_date),
+            time(&self.fix_date),
+            time(&self.coupon_start_date),
+            time(&self.coupon_end_date),
+            self.coupon_period.to_string(),
+            enumeration(self.coupon_type, |e: CouponType| e.as_str_name()),
+            pay_one_bond,
+            pay_one_bond_currency,
+        ]
+    }
+}
+
+impl CsvRecord for BrokerReport {
+    fn headers() -> &'static [&'static str] {
+        &[
+            "trade_id",
+            "order_id",
+            "figi",
+            "ticker",
+            "class_code",
+            "name",
+            "exchange",
+            "execute_sign",
+            "direction",
+            "trade_datetime",
+            "price",
+            "price_currency",
+            "quantity",
+            "order_amount",
+            "order_amount_currency",
+            "aci_value",
+            "total_order_amount",
+            "total_order_amount_currency",
+            "broker_commission",
+            "broker_commission_currency",
+            "exchange_commission",
+            "exchange_commission_currency",
+            "exchange_clearing_commission",
+            "exchange_clearing_commission_currency",
+            "repo_rate",
+            "party",
+            "clear_value_date",
+            "sec_value_date",
+            "broker_status",
+            "separate_agreement_type",
+            "separate_agreement_number",
+            "separate_agreement_date",
+            "delivery_type",
+        ]
+    }
+
+    fn record(&self) -> Vec<String> {
+        let [price, price_currency] = money(&self.price);
+        let [order_amount, order_amount_currency] = money(&self.order_amount);
+        let [total_order_amount, total_order_amount_currency] = money(&self.total_order_amount);
+        let [broker_commission, broker_commission_currency] = money(&self.broker_commission);
+        let [exchange_commission, exchange_commission_currency] = money(&self.exchange_commission);
+        let [clearing_commission, clearing_commission_currency] =
+            money(&self.exchange_clearing_commission);
+
+        vec![
+            self.trade_id.clone(),
+            self.order_id.clone(),
+            self.figi.clone(),
+            self.ticker.clone(),
+            self.class_code.clone(),
+            self.name.clone(),
+            self.exchange.clone(),
+            self.execute_sign.clone(),
+            self.direction.clone(),
+            time(&self.trade_datetime),
+            price,
+            price_currency,
+            self.quantity.to_string(),
+            order_amount,
+            order_amount_currency,
+            quotation(&self.aci_value),
+            total_order_amount,
+            total_order_amount_currency,
+            broker_commission,
+            broker_commission_currency,
+            exchange_commission,
+            exchange_commission_currency,
+            clearing_commission,
+            clearing_commission_currency,
+            quotation(&self.repo_rate),
+            self.party.clone(),
+            time(&self.clear_value_date),
+            time(&self.sec_value_date),
+            self.broker_status.clone(),
+            self.separate_agreement_type.clone(),
+            self.separate_agreement_number.clone(),
+            self.separate_agreement_date.clone(),
+            self.delivery_type.clone(),
+        ]
+    }
+}
@@ -1,3 +1,5 @@
 mod metadata_ext;
+mod request_ext;
 
 pub use metadata_ext::MetadataExt;
+pub use request_ext::RequestExt;
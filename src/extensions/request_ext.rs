@@ -0,0 +1,84 @@
+use super::MetadataExt;
+use std::time::Duration;
+
+/// Extension trait for 'tonic::Request', setting per-request options
+///
+/// Values set here are kept by `TinkoffInterceptor`, e.g. `x-request-id` is generated
+/// only if it was not set.
+pub trait RequestExt: Sized {
+    /// Sets `x-request-id`, e.g. to correlate own trace id with support tickets
+    fn with_request_id(self, request_id: impl Into<String>) -> Self;
+
+    /// Sets deadline of the request, sent as `grpc-timeout`
+    fn with_timeout(self, timeout: Duration) -> Self;
+
+    /// Appends header, invalid values are skipped with warning
+    fn with_header(self, key: &'static str, value: impl Into<String>) -> Self;
+}
+
+impl<T> RequestExt for tonic::Request<T> {
+    fn with_request_id(mut self, request_id: impl Into<String>) -> Self {
+        let metadata = self.metadata_mut();
+
+        metadata.remove("x-request-id");
+        metadata.safe_append("x-request-id", request_id, "Failed to insert request id");
+
+        self
+    }
+
+    fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.set_timeout(timeout);
+        self
+    }
+
+    fn with_header(mut self, key: &'static str, value: impl Into<String>) -> Self {
+        self.metadata_mut()
+            .safe_append(key, value, format!("Failed to insert header '{key}'"));
+
+        self
+    }
+}
+
+// region:    --- Tests
+
+#[cfg(all(test, feature = "v1"))]
+mod tests {
+    type Error = Box<dyn std::error::Error>;
+    type Result<T> = core::result::Result<T, Error>; // For tests.
+
+    use super::*;
+    use crate::api::v1::{IntercemptorWithNew, InterceptorData, TinkoffInterceptor};
+    use crate::SecretToken;
+    use std::sync::Arc;
+    use tonic::service::Interceptor;
+
+    #[test]
+    fn test_request_options_kept_by_interceptor_ok() -> Result<()> {
+        let mut interceptor = TinkoffInterceptor::new(InterceptorData {
+            token: Arc::new(SecretToken::new("token")),
+            app_name: Some("app".to_string()),
+        });
+
+        let request = tonic::Request::new(())
+            .with_request_id("trace-42")
+            .with_timeout(Duration::from_secs(2))
+            .with_header("x-tracking-id", "tracking");
+
+        let request = interceptor.call(request)?;
+        let metadata = request.metadata();
+
+        assert_eq!(metadata.get_all("x-request-id").iter().count(), 1);
+        assert_eq!(metadata.get("x-request-id").unwrap(), "trace-42");
+        assert_eq!(metadata.get("grpc-timeout").unwrap(), "2000000u");
+        assert_eq!(metadata.get("x-tracking-id").unwrap(), "tracking");
+        assert_eq!(metadata.get("authorization").unwrap(), "Bearer token");
+
+        // Request id is generated if not set
+        let request = interceptor.call(tonic::Request::new(()))?;
+        assert!(request.metadata().get("x-request-id").is_some());
+
+        Ok(())
+    }
+}
+
+// endregion: --- Tests
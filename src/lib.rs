@@ -1,19 +1,36 @@
 // region:    --- Modules
 
 // -- Modules
+#[cfg(feature = "v1")]
+pub mod analytics;
 pub mod api;
+#[cfg(all(feature = "v1", any(feature = "datetime", feature = "datetime-time")))]
+pub mod bonds;
+#[cfg(all(feature = "v1", any(feature = "datetime", feature = "datetime-time")))]
+pub mod calendar;
 pub mod channel;
+#[cfg(feature = "export")]
+pub mod export;
 pub mod extensions;
+#[cfg(feature = "otel")]
+pub mod otel;
+#[cfg(all(feature = "v1", any(feature = "datetime", feature = "datetime-time")))]
+pub mod risk;
+pub mod token;
 
 mod config;
-#[cfg(feature = "datetime")]
+#[cfg(any(feature = "datetime", feature = "datetime-time"))]
 mod datetime;
 mod error;
 
 // -- Flatten
-pub use config::config;
-#[cfg(feature = "datetime")]
-pub use datetime::DateTime;
+pub use config::{
+    config, ApiConfig, ApiConfigLoader, Profile, RateLimitConfig, RetryConfig, DEFAULT_ENDPOINT,
+    DEFAULT_ENV_PREFIX, DEFAULT_SANDBOX_ENDPOINT,
+};
+#[cfg(any(feature = "datetime", feature = "datetime-time"))]
+pub use datetime::{DateRange, DateTime, TimeZone};
 pub use error::{Error, Result};
+pub use token::{SecretToken, TokenProvider};
 
 // endregion: --- Modules
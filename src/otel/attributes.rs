@@ -0,0 +1,118 @@
+//! Span attributes taken from request messages
+
+/// Request message with ids recorded in RPC span
+pub trait RpcAttributes {
+    fn account_id(&self) -> Option<&str> {
+        None
+    }
+
+    fn instrument_id(&self) -> Option<&str> {
+        None
+    }
+}
+
+macro_rules! rpc_attribute {
+    ($field:ident, string) => {
+        fn $field(&self) -> Option<&str> {
+            Some(self.$field.as_str()).filter(|value| !value.is_empty())
+        }
+    };
+    ($field:ident, option) => {
+        fn $field(&self) -> Option<&str> {
+            self.$field.as_deref()
+        }
+    };
+    // Requests for several instruments record the first one
+    ($field:ident, first) => {
+        fn $field(&self) -> Option<&str> {
+            self.$field.first().map(String::as_str)
+        }
+    };
+}
+
+macro_rules! impl_rpc_attributes {
+    ($($message:ident { $($field:ident: $kind:ident),* }),* $(,)?) => {
+        $(
+            impl RpcAttributes for crate::api::v1::$message {
+                $(rpc_attribute!($field, $kind);)*
+            }
+        )*
+    };
+}
+
+impl_rpc_attributes! {
+    AssetRequest {},
+    AssetsRequest {},
+    BrokerReportRequest {},
+    CancelOrderRequest { account_id: string },
+    CancelStopOrderRequest { account_id: string },
+    CloseSandboxAccountRequest { account_id: string },
+    EditFavoritesRequest {},
+    FilterOptionsRequest {},
+    FindInstrumentRequest {},
+    GenerateBrokerReportRequest { account_id: string },
+    GenerateDividendsForeignIssuerReportRequest { account_id: string },
+    GetAccountsRequest {},
+    GetAccruedInterestsRequest { instrument_id: string },
+    GetAssetFundamentalsRequest {},
+    GetAssetReportsRequest { instrument_id: string },
+    GetBondCouponsRequest { instrument_id: string },
+    GetBondEventsRequest { instrument_id: string },
+    GetBrandRequest {},
+    GetBrandsRequest {},
+    GetBrokerReportRequest {},
+    GetCandlesRequest { instrument_id: option },
+    GetClosePricesRequest {},
+    GetConsensusForecastsRequest {},
+    GetCountriesRequest {},
+    GetDividendsForeignIssuerReportRequest {},
+    GetDividendsForeignIssuerRequest {},
+    GetDividendsRequest { instrument_id: string },
+    GetFavoritesRequest {},
+    GetForecastRequest { instrument_id: string },
+    GetFuturesMarginRequest { instrument_id: string },
+    GetInfoRequest {},
+    GetLastPricesRequest { instrument_id: first },
+    GetLastTradesRequest { instrument_id: option },
+    GetMarginAttributesRequest { account_id: string },
+    GetMaxLotsRequest { account_id: string, instrument_id: string },
+    GetOperationsByCursorRequest { account_id: string, instrument_id: option },
+    GetOrderBookRequest { instrument_id: option },
+    GetOrderPriceRequest { account_id: string, instrument_id: string },
+    GetOrderStateRequest { account_id: string },
+    GetOrdersRequest { account_id: string },
+    GetSignalsRequest {},
+    GetStopOrdersRequest { account_id: string },
+    GetStrategiesRequest {},
+    GetTechAnalysisRequest {},
+    GetTradingStatusRequest { instrument_id: option },
+    GetTradingStatusesRequest { instrument_id: first },
+    GetUserTariffRequest {},
+    IndicativesRequest {},
+    InstrumentClosePriceRequest { instrument_id: string },
+    InstrumentRequest {},
+    InstrumentsRequest {},
+    MarketDataRequest {},
+    MarketDataServerSideStreamRequest {},
+    OpenSandboxAccountRequest {},
+    OperationsRequest { account_id: string },
+    OrderStateStreamRequest {},
+    PingRequest {},
+    PortfolioRequest { account_id: string },
+    PortfolioStreamRequest {},
+    PositionsRequest { account_id: string },
+    PositionsStreamRequest {},
+    PostOrderAsyncRequest { account_id: string, instrument_id: string },
+    PostOrderRequest { account_id: string, instrument_id: string },
+    PostStopOrderRequest { account_id: string, instrument_id: string },
+    ReplaceOrderRequest { account_id: string },
+    SandboxPayInRequest { account_id: string },
+    SubscribeCandlesRequest {},
+    SubscribeInfoRequest {},
+    SubscribeLastPriceRequest {},
+    SubscribeOrderBookRequest {},
+    SubscribeTradesRequest {},
+    TradesStreamRequest {},
+    TradingSchedulesRequest {},
+    WithdrawLimitsRequest { account_id: string },
+}
@@ -0,0 +1,126 @@
+//! RPC and stream metrics
+
+// region:    --- Modules
+
+use super::INSTRUMENTATION_NAME;
+use crate::api::v1::{market_data_response, MarketDataResponse, Ping};
+use opentelemetry::{
+    global,
+    metrics::{Counter, Histogram},
+    KeyValue,
+};
+use std::{
+    sync::OnceLock,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+// endregion: --- Modules
+
+struct RpcInstruments {
+    duration: Histogram<f64>,
+    errors: Counter<u64>,
+}
+
+fn rpc_instruments() -> &'static RpcInstruments {
+    static INSTANCE: OnceLock<RpcInstruments> = OnceLock::new();
+
+    INSTANCE.get_or_init(|| {
+        let meter = global::meter(INSTRUMENTATION_NAME);
+
+        RpcInstruments {
+            duration: meter
+                .f64_histogram("rpc.client.duration")
+                .with_unit("ms")
+                .with_description("Duration of RPC calls")
+                .build(),
+            errors: meter
+                .u64_counter("rpc.client.errors")
+                .with_description("Failed RPC calls by status code")
+                .build(),
+        }
+    })
+}
+
+/// Records latency of RPC and counts it as error if `code` is not `Ok`
+pub(super) fn record_rpc(method: Option<(String, String)>, code: tonic::Code, latency: Duration) {
+    let instruments = rpc_instruments();
+
+    let mut attributes = vec![KeyValue::new("rpc.grpc.status_code", code as i64)];
+    if let Some((service, method)) = method {
+        attributes.push(KeyValue::new("rpc.service", service));
+        attributes.push(KeyValue::new("rpc.method", method));
+    }
+
+    instruments
+        .duration
+        .record(latency.as_secs_f64() * 1000.0, &attributes);
+
+    if code != tonic::Code::Ok {
+        instruments.errors.add(1, &attributes);
+    }
+}
+
+// region:    --- Stream Metrics
+
+/// Metrics of single stream, e.g. `MarketDataStream`
+///
+/// ```ignore
+/// let metrics = StreamMetrics::new("MarketDataStream");
+///
+/// while let Some(message) = stream.message().await? {
+///     metrics.record_market_data(&message);
+/// }
+/// ```
+pub struct StreamMetrics {
+    attributes: Vec<KeyValue>,
+    messages: Counter<u64>,
+    ping_lag: Histogram<f64>,
+}
+
+impl StreamMetrics {
+    pub fn new(stream: impl Into<String>) -> Self {
+        let meter = global::meter(INSTRUMENTATION_NAME);
+
+        Self {
+            attributes: vec![KeyValue::new("tinkoff.stream", stream.into())],
+            messages: meter
+                .u64_counter("tinkoff.stream.messages")
+                .with_description("Received stream messages")
+                .build(),
+            ping_lag: meter
+                .f64_histogram("tinkoff.stream.ping_lag")
+                .with_unit("ms")
+                .with_description("Delay between server ping time and receiving it")
+                .build(),
+        }
+    }
+
+    /// Counts received message
+    pub fn record_message(&self) {
+        self.messages.add(1, &self.attributes);
+    }
+
+    /// Records lag of ping, pings from server in the future are recorded as zero lag
+    pub fn record_ping(&self, ping: &Ping) {
+        let Some(time) = &ping.time else {
+            return;
+        };
+
+        let sent = UNIX_EPOCH + Duration::new(time.seconds.max(0) as u64, time.nanos.max(0) as u32);
+        let lag = SystemTime::now().duration_since(sent).unwrap_or_default();
+
+        self.ping_lag
+            .record(lag.as_secs_f64() * 1000.0, &self.attributes);
+    }
+
+    /// Counts message and records lag if it is ping
+    pub fn record_market_data(&self, message: &MarketDataResponse) {
+        self.record_message();
+
+        if let Some(market_data_response::Payload::Ping(ping)) = &message.payload {
+            self.record_ping(ping);
+        }
+    }
+}
+
+// endregion: --- Stream Metrics
@@ -0,0 +1,190 @@
+//! OpenTelemetry tracing and metrics
+//!
+//! Uses global tracer, meter and text map propagator, so exporters and W3C propagator
+//! are configured by application, e.g. with `opentelemetry_sdk`.
+//!
+//! * `OtelLayer` wraps channel, so every RPC of clients created over it gets client span,
+//!   duration and error metrics and trace context in request headers
+//! * `traced` runs RPC in parent span with account and instrument ids from request
+//! * `StreamMetrics` records stream message rates and ping lag
+//!
+//! ```ignore
+//! let channel = OtelLayer.layer(ChannelBuilder::default()?.connect().await?);
+//! let mut orders = OrdersServiceClient::new(api.intercept(channel.clone()));
+//!
+//! let response = traced(tonic::Request::new(request), |request| orders.post_order(request)).await?;
+//! ```
+
+// region:    --- Modules
+
+mod attributes;
+mod metrics;
+mod service;
+
+pub use attributes::RpcAttributes;
+pub use metrics::StreamMetrics;
+pub use service::{OtelBody, OtelChannel, OtelLayer, OtelService};
+// For `OtelLayer.layer(channel)` without depending on tower
+pub use tower_layer::Layer;
+
+use opentelemetry::{
+    global,
+    trace::{FutureExt, SpanKind, Status, TraceContextExt, Tracer},
+    Context, KeyValue,
+};
+use std::future::Future;
+use tonic::{Request, Response};
+
+// endregion: --- Modules
+
+/// Name of tracer and meter
+pub const INSTRUMENTATION_NAME: &str = "investments-tinkoff";
+
+/// Response header with id for support requests
+const TRACKING_ID_HEADER: &str = "x-tracking-id";
+
+// region:    --- Traced
+
+/// Runs RPC `call` in span with `tinkoff.account_id` and `tinkoff.instrument_id` from request
+///
+/// Span of `OtelLayer` becomes its child, so ids are searchable next to called method,
+/// metrics are recorded by the layer.
+pub async fn traced<T, R, F, Fut>(
+    request: Request<T>,
+    call: F,
+) -> core::result::Result<Response<R>, tonic::Status>
+where
+    T: RpcAttributes,
+    F: FnOnce(Request<T>) -> Fut,
+    Fut: Future<Output = core::result::Result<Response<R>, tonic::Status>>,
+{
+    let tracer = global::tracer(INSTRUMENTATION_NAME);
+
+    let mut attributes = Vec::new();
+    if let Some(account_id) = request.get_ref().account_id() {
+        attributes.push(KeyValue::new("tinkoff.account_id", account_id.to_string()));
+    }
+    if let Some(instrument_id) = request.get_ref().instrument_id() {
+        attributes.push(KeyValue::new(
+            "tinkoff.instrument_id",
+            instrument_id.to_string(),
+        ));
+    }
+
+    let span = tracer
+        .span_builder("tinkoff.rpc")
+        .with_kind(SpanKind::Internal)
+        .with_attributes(attributes)
+        .start(&tracer);
+    let cx = Context::current_with_span(span);
+
+    let result = call(request).with_context(cx.clone()).await;
+
+    let span = cx.span();
+    if let Err(status) = &result {
+        span.set_status(Status::error(status.message().to_string()));
+    }
+    span.end();
+
+    result
+}
+
+// endregion: --- Traced
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    type Error = Box<dyn std::error::Error>;
+    type Result<T> = core::result::Result<T, Error>; // For tests.
+
+    use super::*;
+    use crate::api::v1::{GetOrdersRequest, GetOrdersResponse, PostOrderRequest};
+
+    #[test]
+    fn test_rpc_attributes_ok() -> Result<()> {
+        let request = PostOrderRequest {
+            account_id: "account".to_string(),
+            instrument_id: "instrument".to_string(),
+            ..Default::default()
+        };
+
+        assert_eq!(request.account_id(), Some("account"));
+        assert_eq!(request.instrument_id(), Some("instrument"));
+        assert_eq!(GetOrdersRequest::default().account_id(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_traced_passes_result_ok() -> Result<()> {
+        let runtime = tokio::runtime::Runtime::new()?;
+
+        let response = runtime.block_on(traced(
+            Request::new(GetOrdersRequest::default()),
+            |_| async move { Ok(Response::new(GetOrdersResponse::default())) },
+        ))?;
+
+        assert_eq!(response.get_ref(), &GetOrdersResponse::default());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_otel_layer_client_status_ok() -> Result<()> {
+        use crate::api::v1::{
+            users_service_client::UsersServiceClient, GetAccountsRequest, InvestApi,
+            InvestApiTrait,
+        };
+        use tonic::body::BoxBody;
+        use tower_layer::Layer;
+
+        /// Answers every request with trailers-only `PermissionDenied` response
+        #[derive(Clone)]
+        struct Denied;
+
+        impl tower_service::Service<http::Request<BoxBody>> for Denied {
+            type Response = http::Response<BoxBody>;
+            type Error = std::convert::Infallible;
+            type Future = std::future::Ready<core::result::Result<Self::Response, Self::Error>>;
+
+            fn poll_ready(
+                &mut self,
+                _cx: &mut std::task::Context<'_>,
+            ) -> std::task::Poll<core::result::Result<(), Self::Error>> {
+                std::task::Poll::Ready(Ok(()))
+            }
+
+            fn call(&mut self, request: http::Request<BoxBody>) -> Self::Future {
+                assert_eq!(
+                    request.uri().path(),
+                    "/tinkoff.public.invest.api.contract.v1.UsersService/GetAccounts"
+                );
+
+                let response = http::Response::builder()
+                    .header("content-type", "application/grpc")
+                    .header("grpc-status", "7")
+                    .header("grpc-message", "denied")
+                    .body(tonic::body::empty_body())
+                    .unwrap();
+
+                std::future::ready(Ok(response))
+            }
+        }
+
+        let runtime = tokio::runtime::Runtime::new()?;
+        let api = InvestApi::with_token("token");
+        let mut users = UsersServiceClient::new(api.intercept(OtelLayer.layer(Denied)));
+
+        let status = runtime
+            .block_on(users.get_accounts(GetAccountsRequest::default()))
+            .err()
+            .ok_or("no error")?;
+        assert_eq!(status.code(), tonic::Code::PermissionDenied);
+        assert_eq!(status.message(), "denied");
+
+        Ok(())
+    }
+}
+
+// endregion: --- Tests
@@ -0,0 +1,282 @@
+//! Tower layer creating span and recording metrics for every RPC on channel
+
+// region:    --- Modules
+
+use super::{metrics, INSTRUMENTATION_NAME, TRACKING_ID_HEADER};
+use http::{HeaderMap, HeaderName, HeaderValue};
+use http_body::{Body, Frame, SizeHint};
+use opentelemetry::{
+    global,
+    propagation::Injector,
+    trace::{SpanKind, Status, TraceContextExt, Tracer},
+    Context, KeyValue,
+};
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context as TaskContext, Poll},
+    time::Instant,
+};
+use tonic::transport::Channel;
+use tower_layer::Layer;
+use tower_service::Service;
+
+// endregion: --- Modules
+
+/// Channel with span and metrics for every RPC
+pub type OtelChannel = OtelService<Channel>;
+
+// region:    --- Layer
+
+/// Wraps service, e.g. `Channel`, into `OtelService`
+///
+/// ```ignore
+/// let channel = OtelLayer.layer(ChannelBuilder::default()?.connect().await?);
+/// let mut orders = OrdersServiceClient::new(api.intercept(channel.clone()));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OtelLayer;
+
+impl<S> Layer<S> for OtelLayer {
+    type Service = OtelService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        OtelService { inner }
+    }
+}
+
+// endregion: --- Layer
+
+// region:    --- Service
+
+/// Service creating client span for every RPC and injecting trace context into request headers
+///
+/// Span is named after called method and ends with gRPC status from trailers, so for streams it
+/// lasts until stream is closed. Duration and errors are recorded as metrics.
+#[derive(Debug, Clone)]
+pub struct OtelService<S> {
+    inner: S,
+}
+
+impl<S> OtelService<S> {
+    pub fn new(inner: S) -> Self {
+        Self { inner }
+    }
+
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S, ReqBody, ResBody> Service<http::Request<ReqBody>> for OtelService<S>
+where
+    S: Service<http::Request<ReqBody>, Response = http::Response<ResBody>>,
+    S::Future: Send + 'static,
+{
+    type Response = http::Response<OtelBody<ResBody>>;
+    type Error = S::Error;
+    type Future =
+        Pin<Box<dyn Future<Output = core::result::Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(
+        &mut self,
+        cx: &mut TaskContext<'_>,
+    ) -> Poll<core::result::Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: http::Request<ReqBody>) -> Self::Future {
+        let call = RpcCall::start(request.uri().path());
+
+        global::get_text_map_propagator(|propagator| {
+            propagator.inject_context(&call.cx, &mut HeaderInjector(request.headers_mut()))
+        });
+
+        let future = self.inner.call(request);
+
+        Box::pin(async move {
+            match future.await {
+                Ok(response) => {
+                    let call = call.on_headers(response.headers());
+                    Ok(response.map(|body| OtelBody { inner: body, call }))
+                }
+                Err(err) => {
+                    call.finish(tonic::Code::Unavailable, None);
+                    Err(err)
+                }
+            }
+        })
+    }
+}
+
+// endregion: --- Service
+
+// region:    --- Body
+
+/// Response body ending RPC span on trailers
+#[derive(Debug, Default)]
+pub struct OtelBody<B> {
+    inner: B,
+    call: Option<RpcCall>,
+}
+
+impl<B> OtelBody<B> {
+    fn finish(&mut self, code: tonic::Code, message: Option<&str>) {
+        if let Some(call) = self.call.take() {
+            call.finish(code, message);
+        }
+    }
+}
+
+impl<B: Body + Unpin> Body for OtelBody<B> {
+    type Data = B::Data;
+    type Error = B::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut TaskContext<'_>,
+    ) -> Poll<Option<core::result::Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.get_mut();
+        let poll = Pin::new(&mut this.inner).poll_frame(cx);
+
+        match &poll {
+            Poll::Ready(Some(Ok(frame))) => {
+                if let Some(trailers) = frame.trailers_ref() {
+                    let (code, message) = grpc_status(trailers).unwrap_or((tonic::Code::Ok, None));
+                    this.finish(code, message);
+                }
+            }
+            Poll::Ready(Some(Err(_))) => this.finish(tonic::Code::Unknown, None),
+            // Body ended without trailers
+            Poll::Ready(None) => this.finish(tonic::Code::Unknown, None),
+            Poll::Pending => {}
+        }
+
+        poll
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+impl<B> Drop for OtelBody<B> {
+    fn drop(&mut self) {
+        // Response or stream dropped before trailers
+        self.finish(tonic::Code::Cancelled, None);
+    }
+}
+
+// endregion: --- Body
+
+// region:    --- Rpc Call
+
+/// Span and start time of RPC
+#[derive(Debug)]
+struct RpcCall {
+    cx: Context,
+    method: Option<(String, String)>,
+    start: Instant,
+}
+
+impl RpcCall {
+    /// Starts span as child of current context, path is `/package.Service/Method`
+    fn start(path: &str) -> Self {
+        let tracer = global::tracer(INSTRUMENTATION_NAME);
+
+        let method = path
+            .trim_start_matches('/')
+            .split_once('/')
+            .map(|(service, method)| (service.to_string(), method.to_string()));
+
+        let mut attributes = vec![KeyValue::new("rpc.system", "grpc")];
+        if let Some((service, method)) = &method {
+            attributes.push(KeyValue::new("rpc.service", service.clone()));
+            attributes.push(KeyValue::new("rpc.method", method.clone()));
+        }
+
+        let span = tracer
+            .span_builder(path.trim_start_matches('/').to_string())
+            .with_kind(SpanKind::Client)
+            .with_attributes(attributes)
+            .start(&tracer);
+
+        Self {
+            cx: Context::current_with_span(span),
+            method,
+            start: Instant::now(),
+        }
+    }
+
+    /// Records tracking id, finishes call for trailers-only response, e.g. with error status
+    fn on_headers(self, headers: &HeaderMap) -> Option<Self> {
+        if let Some(tracking_id) = headers
+            .get(TRACKING_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+        {
+            self.cx.span().set_attribute(KeyValue::new(
+                "tinkoff.tracking_id",
+                tracking_id.to_string(),
+            ));
+        }
+
+        match grpc_status(headers) {
+            Some((code, message)) => {
+                self.finish(code, message);
+                None
+            }
+            None => Some(self),
+        }
+    }
+
+    fn finish(self, code: tonic::Code, message: Option<&str>) {
+        let span = self.cx.span();
+
+        span.set_attribute(KeyValue::new("rpc.grpc.status_code", code as i64));
+        if code != tonic::Code::Ok {
+            span.set_status(Status::error(message.unwrap_or_default().to_string()));
+        }
+        span.end();
+
+        metrics::record_rpc(self.method, code, self.start.elapsed());
+    }
+}
+
+// endregion: --- Rpc Call
+
+// region:    --- Helpers
+
+/// Returns `grpc-status` and `grpc-message` from headers or trailers
+fn grpc_status(headers: &HeaderMap) -> Option<(tonic::Code, Option<&str>)> {
+    let code = headers
+        .get("grpc-status")?
+        .to_str()
+        .ok()?
+        .parse::<i32>()
+        .ok()?;
+    let message = headers
+        .get("grpc-message")
+        .and_then(|value| value.to_str().ok());
+
+    Some((tonic::Code::from_i32(code), message))
+}
+
+/// Writes propagated context, e.g. `traceparent`, into request headers
+struct HeaderInjector<'a>(&'a mut HeaderMap);
+
+impl Injector for HeaderInjector<'_> {
+    fn set(&mut self, key: &str, value: String) {
+        if let (Ok(key), Ok(value)) = (
+            HeaderName::from_bytes(key.as_bytes()),
+            HeaderValue::try_from(value),
+        ) {
+            self.0.insert(key, value);
+        }
+    }
+}
+
+// endregion: --- Helpers
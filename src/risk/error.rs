@@ -0,0 +1,57 @@
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Reason order was rejected before sending
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    /// Kill switch is on, with reason it was turned on
+    KillSwitch(String),
+    UnknownInstrument(String),
+    InstrumentNotAllowed(String),
+    /// Market order without last price, so notional cannot be estimated
+    NoPrice(String),
+    /// Bond without nominal or future without price step amount, so price cannot be converted
+    UnknownNotional(String),
+    /// Instrument currency without rate to currency of limits
+    NoFxRate {
+        instrument: String,
+        currency: String,
+    },
+    OrderNotional {
+        instrument: String,
+        notional: f64,
+        limit: f64,
+    },
+    DailyNotional {
+        notional: f64,
+        limit: f64,
+    },
+    /// Position in lots after order exceeds limit
+    Position {
+        instrument: String,
+        lots: i64,
+        limit: i64,
+    },
+    PriceBand {
+        instrument: String,
+        price: f64,
+        last_price: f64,
+    },
+    /// Order is larger than `get_max_lots` allows
+    MaxLots {
+        instrument: String,
+        lots: i64,
+        max_lots: i64,
+    },
+}
+
+// region:    --- Error Boilerplate
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::result::Result<(), core::fmt::Error> {
+        write!(fmt, "{self:?}")
+    }
+}
+
+impl std::error::Error for Error {}
+
+// endregion: --- Error Boilerplate
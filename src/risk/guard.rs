@@ -0,0 +1,773 @@
+//! Limits and state of risk checks
+
+use super::{Error, Result};
+use crate::analytics::{InstrumentCatalog, InstrumentInfo, MaxLots};
+use crate::api::v1::{OrderDirection, PortfolioResponse};
+use crate::{DateTime, TimeZone};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+
+// region:    --- Limits
+
+const DEFAULT_LIMIT_CURRENCY: &str = "rub";
+
+/// Limits checked before order is sent, `None` disables check
+///
+/// Notional is `price * lot * lots` in instrument currency, converted to `currency` of limits with
+/// `fx_rates`. Bond prices in percents are converted with `nominal`, futures prices in points with
+/// `min_price_increment_amount`. If notional is limited, instruments without them or without
+/// rate of their currency are rejected.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RiskLimits {
+    /// Currency of notional limits, lowercase, `rub` if empty
+    pub currency: String,
+    /// Price of currency unit in limit currency by lowercase name, e.g. `usd` to `90.0`
+    pub fx_rates: HashMap<String, f64>,
+    pub max_order_notional: Option<f64>,
+    pub max_daily_notional: Option<f64>,
+    /// Max absolute position in lots after order, for instruments without own limit
+    pub max_position_lots: Option<i64>,
+    /// Max absolute position in lots by uid
+    pub position_limits: HashMap<String, i64>,
+    /// Uids, figis or tickers of instruments allowed to trade, empty allows all
+    pub allowed_instruments: HashSet<String>,
+    /// Max deviation of limit price from last price as fraction, e.g. `0.05`
+    pub price_band: Option<f64>,
+    /// Requests `get_max_lots` and rejects larger orders
+    pub check_max_lots: bool,
+}
+
+impl RiskLimits {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_currency(mut self, currency: impl Into<String>) -> Self {
+        self.currency = currency.into().to_lowercase();
+        self
+    }
+
+    pub fn with_fx_rate(mut self, currency: impl Into<String>, rate: f64) -> Self {
+        self.fx_rates.insert(currency.into().to_lowercase(), rate);
+        self
+    }
+
+    pub fn with_max_order_notional(mut self, notional: f64) -> Self {
+        self.max_order_notional = Some(notional);
+        self
+    }
+
+    pub fn with_max_daily_notional(mut self, notional: f64) -> Self {
+        self.max_daily_notional = Some(notional);
+        self
+    }
+
+    pub fn with_max_position_lots(mut self, lots: i64) -> Self {
+        self.max_position_lots = Some(lots);
+        self
+    }
+
+    pub fn with_position_limit(mut self, uid: impl Into<String>, lots: i64) -> Self {
+        self.position_limits.insert(uid.into(), lots);
+        self
+    }
+
+    pub fn with_allowed_instrument(mut self, instrument: impl Into<String>) -> Self {
+        self.allowed_instruments.insert(instrument.into());
+        self
+    }
+
+    pub fn with_price_band(mut self, band: f64) -> Self {
+        self.price_band = Some(band);
+        self
+    }
+
+    pub fn with_max_lots_check(mut self) -> Self {
+        self.check_max_lots = true;
+        self
+    }
+
+    pub(super) fn has_notional_limits(&self) -> bool {
+        self.max_order_notional.is_some() || self.max_daily_notional.is_some()
+    }
+
+    /// Returns currency of notional limits
+    pub fn limit_currency(&self) -> &str {
+        match self.currency.as_str() {
+            "" => DEFAULT_LIMIT_CURRENCY,
+            currency => currency,
+        }
+    }
+
+    /// Converts amount in `currency` to limit currency, `None` without rate
+    fn convert(&self, currency: &str, amount: f64) -> Option<f64> {
+        if currency.eq_ignore_ascii_case(self.limit_currency()) {
+            return Some(amount);
+        }
+
+        self.fx_rates
+            .get(&currency.to_lowercase())
+            .map(|rate| amount * rate)
+    }
+
+    fn position_limit(&self, uid: &str) -> Option<i64> {
+        self.position_limits
+            .get(uid)
+            .copied()
+            .or(self.max_position_lots)
+    }
+}
+
+// endregion: --- Limits
+
+// region:    --- Order Check
+
+/// Order as seen by risk checks
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderCheck {
+    /// Uid or figi of instrument
+    pub instrument_id: String,
+    pub direction: OrderDirection,
+    pub lots: i64,
+    /// Limit price of one instrument, `None` for market orders
+    pub price: Option<f64>,
+    pub last_price: Option<f64>,
+    /// Limits from `get_max_lots`, required if `check_max_lots` is set
+    pub max_lots: Option<MaxLots>,
+}
+
+impl OrderCheck {
+    pub fn new(instrument_id: impl Into<String>, direction: OrderDirection, lots: i64) -> Self {
+        Self {
+            instrument_id: instrument_id.into(),
+            direction,
+            lots,
+            price: None,
+            last_price: None,
+            max_lots: None,
+        }
+    }
+
+    pub fn with_price(mut self, price: f64) -> Self {
+        self.price = Some(price);
+        self
+    }
+
+    pub fn with_last_price(mut self, price: f64) -> Self {
+        self.last_price = Some(price);
+        self
+    }
+
+    pub fn with_max_lots(mut self, max_lots: MaxLots) -> Self {
+        self.max_lots = Some(max_lots);
+        self
+    }
+
+    /// Lots added to position, negative for sells
+    fn signed_lots(&self) -> i64 {
+        signed_lots(self.direction, self.lots)
+    }
+}
+
+// endregion: --- Order Check
+
+// region:    --- Guard
+
+/// Checks orders against limits, clones share kill switch, daily notional and positions
+///
+/// Approved orders are counted as executed, so positions and daily notional are conservative.
+/// Orders that were not sent should be released. Daily notional is reset at Moscow midnight.
+#[derive(Debug, Clone)]
+pub struct RiskGuard {
+    limits: Arc<RiskLimits>,
+    catalog: Arc<InstrumentCatalog>,
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    killed: Option<String>,
+    /// Start of Moscow day in seconds
+    day: i64,
+    daily_notional: f64,
+    /// Lots by uid
+    positions: HashMap<String, i64>,
+    /// Sent orders by order id
+    orders: HashMap<String, SentOrder>,
+}
+
+/// Sent order as counted in daily notional and position
+#[derive(Debug, Clone)]
+struct SentOrder {
+    uid: String,
+    direction: OrderDirection,
+    lots: i64,
+    notional: f64,
+}
+
+impl RiskGuard {
+    /// Creates guard, `catalog` must have lot sizes of traded instruments
+    pub fn new(limits: RiskLimits, catalog: InstrumentCatalog) -> Self {
+        Self {
+            limits: Arc::new(limits),
+            catalog: Arc::new(catalog),
+            state: Arc::default(),
+        }
+    }
+
+    pub fn limits(&self) -> &RiskLimits {
+        &self.limits
+    }
+
+    pub fn catalog(&self) -> &InstrumentCatalog {
+        &self.catalog
+    }
+
+    /// Turns kill switch on, all orders are rejected until `resume`
+    pub fn kill(&self, reason: impl Into<String>) {
+        self.lock().killed = Some(reason.into());
+    }
+
+    pub fn resume(&self) {
+        self.lock().killed = None;
+    }
+
+    pub fn is_killed(&self) -> bool {
+        self.lock().killed.is_some()
+    }
+
+    /// Returns error if kill switch is on
+    pub fn ensure_active(&self) -> Result<()> {
+        match &self.lock().killed {
+            Some(reason) => Err(Error::KillSwitch(reason.clone())),
+            None => Ok(()),
+        }
+    }
+
+    /// Replaces positions with whole lots of portfolio positions found in catalog
+    pub fn sync_positions(&self, portfolio: &PortfolioResponse) {
+        let mut state = self.lock();
+        state.positions.clear();
+
+        for position in &portfolio.positions {
+            let Some(instrument) = self.catalog.find(&position.instrument_uid, &position.figi)
+            else {
+                continue;
+            };
+            let quantity = position.quantity.as_ref().map_or(0.0, |q| q.to_f64());
+            let lots = (quantity / instrument.lot.max(1) as f64).trunc() as i64;
+
+            state.positions.insert(instrument.uid.clone(), lots);
+        }
+    }
+
+    pub fn set_position(&self, uid: impl Into<String>, lots: i64) {
+        self.lock().positions.insert(uid.into(), lots);
+    }
+
+    /// Returns position in lots, counting approved orders
+    pub fn position(&self, uid: &str) -> i64 {
+        self.lock().positions.get(uid).copied().unwrap_or_default()
+    }
+
+    /// Returns notional of orders approved today in limit currency
+    pub fn daily_notional(&self) -> f64 {
+        let mut state = self.lock();
+        state.roll_day(today());
+
+        state.daily_notional
+    }
+
+    /// Checks order without counting it
+    pub fn check(&self, order: &OrderCheck) -> Result<()> {
+        let mut state = self.lock();
+        state.roll_day(today());
+
+        self.evaluate(&state, order, None).map(|_| ())
+    }
+
+    /// Checks order and counts it in daily notional and position
+    pub fn approve(&self, order: &OrderCheck) -> Result<()> {
+        let mut state = self.lock();
+        state.roll_day(today());
+
+        let (uid, notional) = self.evaluate(&state, order, None)?;
+        state.count(uid, order.signed_lots(), notional);
+
+        Ok(())
+    }
+
+    /// Removes approved order from daily notional and position, e.g. if it failed to be sent
+    pub fn release(&self, order: &OrderCheck) {
+        let mut state = self.lock();
+        state.roll_day(today());
+
+        if let Some((uid, notional)) = self.counted(order) {
+            state.count(uid, -order.signed_lots(), -notional);
+        }
+    }
+
+    /// Checks replacement of remembered order and counts only its difference from that order
+    ///
+    /// Order that is not remembered is checked and counted as a new one.
+    pub fn approve_replace(&self, order_id: &str, order: &OrderCheck) -> Result<()> {
+        let mut state = self.lock();
+        state.roll_day(today());
+
+        let previous = state.orders.get(order_id).cloned();
+        let (uid, notional) = self.evaluate(&state, order, previous.as_ref())?;
+        let (lots, notional) = replace_difference(order, notional, previous.as_ref());
+        state.count(uid, lots, notional);
+
+        Ok(())
+    }
+
+    /// Reverts `approve_replace`, e.g. if replacement failed to be sent
+    pub fn release_replace(&self, order_id: &str, order: &OrderCheck) {
+        let mut state = self.lock();
+        state.roll_day(today());
+
+        let previous = state.orders.get(order_id).cloned();
+        if let Some((uid, notional)) = self.counted(order) {
+            let (lots, notional) = replace_difference(order, notional, previous.as_ref());
+            state.count(uid, -lots, -notional);
+        }
+    }
+
+    /// Remembers sent order for checking its replacement
+    pub fn remember_order(
+        &self,
+        order_id: impl Into<String>,
+        uid: impl Into<String>,
+        order: &OrderCheck,
+    ) {
+        let notional = self.counted(order).map_or(0.0, |(_, notional)| notional);
+
+        self.lock().orders.insert(
+            order_id.into(),
+            SentOrder {
+                uid: uid.into(),
+                direction: order.direction,
+                lots: order.lots,
+                notional,
+            },
+        );
+    }
+
+    /// Forgets replaced or finished order
+    pub fn forget_order(&self, order_id: &str) {
+        self.lock().orders.remove(order_id);
+    }
+
+    /// Returns instrument uid and direction of remembered order
+    pub fn order(&self, order_id: &str) -> Option<(String, OrderDirection)> {
+        self.lock()
+            .orders
+            .get(order_id)
+            .map(|order| (order.uid.clone(), order.direction))
+    }
+
+    /// Returns uid and notional order was counted with
+    fn counted(&self, order: &OrderCheck) -> Option<(String, f64)> {
+        let id = &order.instrument_id;
+        let instrument = self.catalog.find(id, id)?;
+        let notional = order
+            .price
+            .or(order.last_price)
+            .and_then(|price| notional(instrument, price, order.lots))
+            .and_then(|notional| self.limits.convert(&instrument.currency, notional))
+            .unwrap_or_default();
+
+        Some((instrument.uid.clone(), notional))
+    }
+
+    /// Returns uid and notional of order passing all checks, `previous` is replaced order
+    fn evaluate(
+        &self,
+        state: &State,
+        order: &OrderCheck,
+        previous: Option<&SentOrder>,
+    ) -> Result<(String, f64)> {
+        if let Some(reason) = &state.killed {
+            return Err(Error::KillSwitch(reason.clone()));
+        }
+
+        let id = &order.instrument_id;
+        let instrument = self
+            .catalog
+            .find(id, id)
+            .ok_or_else(|| Error::UnknownInstrument(id.clone()))?;
+        let limits = &self.limits;
+
+        if !limits.allowed_instruments.is_empty() && !is_allowed(instrument, limits) {
+            return Err(Error::InstrumentNotAllowed(id.clone()));
+        }
+
+        if limits.check_max_lots {
+            let max_lots = order
+                .max_lots
+                .map(|max| match order.direction {
+                    OrderDirection::Sell => max.sell,
+                    _ if order.price.is_some() => max.buy,
+                    _ => max.buy_market,
+                })
+                .unwrap_or_default();
+            if order.lots > max_lots {
+                return Err(Error::MaxLots {
+                    instrument: id.clone(),
+                    lots: order.lots,
+                    max_lots,
+                });
+            }
+        }
+
+        if let (Some(band), Some(price), Some(last_price)) =
+            (limits.price_band, order.price, order.last_price)
+        {
+            if last_price > 0.0 && (price / last_price - 1.0).abs() > band {
+                return Err(Error::PriceBand {
+                    instrument: id.clone(),
+                    price,
+                    last_price,
+                });
+            }
+        }
+
+        let notional = match order.price.or(order.last_price) {
+            Some(price) => match notional(instrument, price, order.lots) {
+                Some(notional) => match limits.convert(&instrument.currency, notional) {
+                    Some(notional) => notional,
+                    None if limits.has_notional_limits() => {
+                        return Err(Error::NoFxRate {
+                            instrument: id.clone(),
+                            currency: instrument.currency.clone(),
+                        })
+                    }
+                    None => 0.0,
+                },
+                None if limits.has_notional_limits() => {
+                    return Err(Error::UnknownNotional(id.clone()))
+                }
+                None => 0.0,
+            },
+            None if limits.has_notional_limits() => return Err(Error::NoPrice(id.clone())),
+            None => 0.0,
+        };
+        let (lots, daily) = replace_difference(order, notional, previous);
+        if let Some(limit) = limits.max_order_notional.filter(|limit| notional > *limit) {
+            return Err(Error::OrderNotional {
+                instrument: id.clone(),
+                notional,
+                limit,
+            });
+        }
+        if let Some(limit) = limits.max_daily_notional {
+            let daily = state.daily_notional + daily;
+            if daily > limit {
+                return Err(Error::DailyNotional {
+                    notional: daily,
+                    limit,
+                });
+            }
+        }
+
+        if let Some(limit) = limits.position_limit(&instrument.uid) {
+            let current = state
+                .positions
+                .get(&instrument.uid)
+                .copied()
+                .unwrap_or_default();
+            let lots = current + lots;
+            // Orders reducing position are always allowed
+            if lots.abs() > limit && lots.abs() > current.abs() {
+                return Err(Error::Position {
+                    instrument: id.clone(),
+                    lots,
+                    limit,
+                });
+            }
+        }
+
+        Ok((instrument.uid.clone(), notional))
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+impl State {
+    fn roll_day(&mut self, day: i64) {
+        if self.day != day {
+            self.day = day;
+            self.daily_notional = 0.0;
+        }
+    }
+
+    /// Adds signed lots and notional, released notional of previous day is dropped
+    fn count(&mut self, uid: String, lots: i64, notional: f64) {
+        self.daily_notional = (self.daily_notional + notional).max(0.0);
+        *self.positions.entry(uid).or_default() += lots;
+    }
+}
+
+/// Returns notional of lots in instrument currency, `None` if price cannot be converted
+fn notional(instrument: &InstrumentInfo, price: f64, lots: i64) -> Option<f64> {
+    let price = match instrument.instrument_type.as_str() {
+        // Percents of nominal
+        "bond" if instrument.nominal > 0.0 => price / 100.0 * instrument.nominal,
+        // Points, converted by amount of price step
+        "futures" => instrument.points_to_amount(price)?,
+        "bond" => return None,
+        _ => price,
+    };
+
+    Some(price * instrument.lot.max(1) as f64 * lots as f64)
+}
+
+/// Returns signed lots and notional added by order replacing `previous` one
+fn replace_difference(
+    order: &OrderCheck,
+    notional: f64,
+    previous: Option<&SentOrder>,
+) -> (i64, f64) {
+    match previous {
+        Some(previous) => (
+            order.signed_lots() - signed_lots(previous.direction, previous.lots),
+            notional - previous.notional,
+        ),
+        None => (order.signed_lots(), notional),
+    }
+}
+
+fn signed_lots(direction: OrderDirection, lots: i64) -> i64 {
+    match direction {
+        OrderDirection::Sell => -lots,
+        _ => lots,
+    }
+}
+
+fn is_allowed(instrument: &InstrumentInfo, limits: &RiskLimits) -> bool {
+    [&instrument.uid, &instrument.figi, &instrument.ticker]
+        .into_iter()
+        .any(|key| !key.is_empty() && limits.allowed_instruments.contains(key))
+}
+
+/// Returns start of current Moscow day in seconds
+fn today() -> i64 {
+    DateTime::now()
+        .truncate_day(TimeZone::Moscow)
+        .timestamp()
+        .seconds
+}
+
+// endregion: --- Guard
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    type Error = Box<dyn std::error::Error>;
+    type Result<T> = core::result::Result<T, Error>; // For tests.
+
+    use super::*;
+    use crate::api::v1::{Bond, Future, MoneyValue, Quotation, Share};
+
+    fn guard(limits: RiskLimits) -> RiskGuard {
+        let mut catalog = InstrumentCatalog::new();
+        catalog.insert(Share {
+            uid: "sber".to_string(),
+            figi: "BBG004730N88".to_string(),
+            ticker: "SBER".to_string(),
+            currency: "rub".to_string(),
+            lot: 10,
+            ..Default::default()
+        });
+        catalog.insert(Share {
+            uid: "gazp".to_string(),
+            currency: "rub".to_string(),
+            lot: 10,
+            ..Default::default()
+        });
+        catalog.insert(Share {
+            uid: "aapl".to_string(),
+            currency: "usd".to_string(),
+            lot: 1,
+            ..Default::default()
+        });
+        catalog.insert(Share {
+            uid: "sap".to_string(),
+            currency: "EUR".to_string(),
+            lot: 1,
+            ..Default::default()
+        });
+
+        RiskGuard::new(limits, catalog)
+    }
+
+    #[test]
+    fn test_risk_guard_limits_ok() -> Result<()> {
+        let guard = guard(
+            RiskLimits::new()
+                .with_allowed_instrument("SBER")
+                .with_max_order_notional(50_000.0)
+                .with_max_daily_notional(60_000.0)
+                .with_max_position_lots(15)
+                .with_price_band(0.05),
+        );
+        let buy = |lots| OrderCheck::new("BBG004730N88", OrderDirection::Buy, lots);
+
+        // 300 * 10 * 10
+        guard.approve(&buy(10).with_price(300.0))?;
+        assert_eq!(guard.position("sber"), 10);
+        assert_eq!(guard.daily_notional(), 30_000.0);
+
+        assert!(matches!(
+            guard.check(&OrderCheck::new("gazp", OrderDirection::Buy, 1).with_price(150.0)),
+            Err(super::Error::InstrumentNotAllowed(_))
+        ));
+        assert!(matches!(
+            guard.check(&buy(1)),
+            Err(super::Error::NoPrice(_))
+        ));
+        assert!(matches!(
+            guard.check(&buy(1).with_price(330.0).with_last_price(300.0)),
+            Err(super::Error::PriceBand { .. })
+        ));
+        assert!(matches!(
+            guard.check(&buy(20).with_price(300.0)),
+            Err(super::Error::OrderNotional { .. })
+        ));
+        assert!(matches!(
+            guard.check(&buy(6).with_last_price(300.0)),
+            Err(super::Error::Position { lots: 16, .. })
+        ));
+        guard.approve(&buy(5).with_last_price(300.0))?;
+        assert!(matches!(
+            guard.check(&OrderCheck::new("sber", OrderDirection::Sell, 6).with_price(300.0)),
+            Err(super::Error::DailyNotional { .. })
+        ));
+
+        guard.clone().kill("test");
+        assert!(matches!(
+            guard.check(&buy(1).with_price(300.0)),
+            Err(super::Error::KillSwitch(reason)) if reason == "test"
+        ));
+        guard.resume();
+        assert!(guard.ensure_active().is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_risk_guard_max_lots_ok() -> Result<()> {
+        let guard = guard(RiskLimits::new().with_max_lots_check());
+        let max_lots = MaxLots {
+            buy: 100,
+            buy_market: 50,
+            sell: 0,
+        };
+        let buy = OrderCheck::new("gazp", OrderDirection::Buy, 80).with_max_lots(max_lots);
+
+        guard.check(&buy.clone().with_price(150.0))?;
+        assert!(matches!(
+            guard.check(&buy),
+            Err(super::Error::MaxLots { max_lots: 50, .. })
+        ));
+        assert!(matches!(
+            guard.check(&OrderCheck::new("gazp", OrderDirection::Buy, 1)),
+            Err(super::Error::MaxLots { max_lots: 0, .. })
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_risk_guard_points_notional_ok() -> Result<()> {
+        let mut catalog = InstrumentCatalog::new();
+        catalog.insert(Bond {
+            uid: "ofz".to_string(),
+            currency: "rub".to_string(),
+            lot: 1,
+            nominal: Some(MoneyValue::new("rub", 1000, 0)),
+            ..Default::default()
+        });
+        catalog.insert(Future {
+            uid: "si".to_string(),
+            currency: "rub".to_string(),
+            lot: 1,
+            min_price_increment: Some(Quotation::new(1, 0)),
+            min_price_increment_amount: Some(Quotation::new(1, 0)),
+            ..Default::default()
+        });
+        catalog.insert(Future {
+            uid: "br".to_string(),
+            currency: "rub".to_string(),
+            lot: 1,
+            ..Default::default()
+        });
+        let guard = RiskGuard::new(
+            RiskLimits::new().with_max_order_notional(100_000.0),
+            catalog,
+        );
+
+        // 10 bonds at 95% of 1000
+        guard.approve(&OrderCheck::new("ofz", OrderDirection::Buy, 10).with_price(95.0))?;
+        assert_eq!(guard.daily_notional(), 9_500.0);
+
+        // 1 contract at 90000 points of 1 rub, 2 contracts exceed limit
+        guard.approve(&OrderCheck::new("si", OrderDirection::Buy, 1).with_price(90_000.0))?;
+        assert_eq!(guard.daily_notional(), 99_500.0);
+        assert!(matches!(
+            guard.check(&OrderCheck::new("si", OrderDirection::Buy, 2).with_price(90_000.0)),
+            Err(super::Error::OrderNotional { .. })
+        ));
+        assert!(matches!(
+            guard.check(&OrderCheck::new("br", OrderDirection::Buy, 1).with_price(80.0)),
+            Err(super::Error::UnknownNotional(_))
+        ));
+
+        // Released order is not counted
+        guard.release(&OrderCheck::new("si", OrderDirection::Buy, 1).with_price(90_000.0));
+        assert_eq!(guard.daily_notional(), 9_500.0);
+        assert_eq!(guard.position("si"), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_risk_guard_fx_notional_ok() -> Result<()> {
+        let guard = guard(
+            RiskLimits::new()
+                .with_fx_rate("USD", 90.0)
+                .with_max_order_notional(50_000.0)
+                .with_max_daily_notional(100_000.0),
+        );
+        let buy = |id, lots| OrderCheck::new(id, OrderDirection::Buy, lots);
+
+        // 500 shares at 1 usd, 90 rub each
+        guard.approve(&buy("aapl", 500).with_price(1.0))?;
+        assert_eq!(guard.daily_notional(), 45_000.0);
+        assert!(matches!(
+            guard.check(&buy("aapl", 600).with_price(1.0)),
+            Err(super::Error::OrderNotional { notional, .. }) if notional == 54_000.0
+        ));
+
+        // Rub and usd orders are summed in rub
+        guard.approve(&buy("sber", 10).with_price(300.0))?;
+        assert_eq!(guard.daily_notional(), 75_000.0);
+        assert!(matches!(
+            guard.check(&buy("aapl", 300).with_price(1.0)),
+            Err(super::Error::DailyNotional { .. })
+        ));
+
+        assert!(matches!(
+            guard.check(&buy("sap", 1).with_price(1.0)),
+            Err(super::Error::NoFxRate { currency, .. }) if currency == "eur"
+        ));
+
+        Ok(())
+    }
+}
+
+// endregion: --- Tests
@@ -0,0 +1,364 @@
+//! Margin utilisation alerts from `UsersService/GetMarginAttributes`
+
+use crate::api::v1::{
+    users_service_client::UsersServiceClient, GetMarginAttributesRequest,
+    GetMarginAttributesResponse, MoneyValue,
+};
+use crate::Result;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tonic::body::BoxBody;
+use tonic::client::GrpcService;
+use tonic::codegen::{Body, Bytes, StdError};
+
+// region:    --- Snapshot
+
+/// Margin state, ordered by severity
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MarginLevel {
+    #[default]
+    Normal,
+    Warning,
+    /// Starting margin is not covered, new positions cannot be opened
+    Critical,
+    /// Minimal margin is not covered, positions may be closed by broker
+    MarginCall,
+}
+
+/// Utilisation levels of `MarginLevel::Warning` and `MarginLevel::Critical`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MarginThresholds {
+    pub warning: f64,
+    pub critical: f64,
+}
+
+impl Default for MarginThresholds {
+    fn default() -> Self {
+        Self {
+            warning: 0.8,
+            critical: 1.0,
+        }
+    }
+}
+
+impl MarginThresholds {
+    /// Returns level for margin attributes, margin call is checked against minimal margin
+    pub fn level(&self, snapshot: &MarginSnapshot) -> MarginLevel {
+        if snapshot.minimal_margin > 0.0 && snapshot.liquid_portfolio <= snapshot.minimal_margin {
+            MarginLevel::MarginCall
+        } else if snapshot.utilisation >= self.critical {
+            MarginLevel::Critical
+        } else if snapshot.utilisation >= self.warning {
+            MarginLevel::Warning
+        } else {
+            MarginLevel::Normal
+        }
+    }
+}
+
+/// Margin attributes of account in currency of liquid portfolio
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MarginSnapshot {
+    pub currency: String,
+    pub liquid_portfolio: f64,
+    pub starting_margin: f64,
+    pub minimal_margin: f64,
+    /// Liquid portfolio to starting margin
+    pub funds_sufficiency_level: f64,
+    pub missing_funds: f64,
+    /// Starting margin to liquid portfolio, `1.0` and more if starting margin is not covered
+    pub utilisation: f64,
+}
+
+impl From<&GetMarginAttributesResponse> for MarginSnapshot {
+    fn from(response: &GetMarginAttributesResponse) -> Self {
+        let liquid_portfolio = amount(&response.liquid_portfolio);
+        let starting_margin = amount(&response.starting_margin);
+
+        let utilisation = if liquid_portfolio > 0.0 {
+            starting_margin / liquid_portfolio
+        } else if starting_margin > 0.0 {
+            f64::INFINITY
+        } else {
+            0.0
+        };
+
+        Self {
+            currency: response
+                .liquid_portfolio
+                .as_ref()
+                .map(|money| money.currency.to_lowercase())
+                .unwrap_or_default(),
+            liquid_portfolio,
+            starting_margin,
+            minimal_margin: amount(&response.minimal_margin),
+            funds_sufficiency_level: response
+                .funds_sufficiency_level
+                .as_ref()
+                .map_or(0.0, |level| level.to_f64()),
+            missing_funds: amount(&response.amount_of_missing_funds),
+            utilisation,
+        }
+    }
+}
+
+/// Level change of account
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarginAlert {
+    pub account_id: String,
+    pub previous: MarginLevel,
+    pub level: MarginLevel,
+    pub snapshot: MarginSnapshot,
+}
+
+impl MarginAlert {
+    /// Returns `true` if level became lower
+    pub fn is_recovery(&self) -> bool {
+        self.level < self.previous
+    }
+}
+
+// endregion: --- Snapshot
+
+// region:    --- Monitor
+
+/// Polls margin attributes of account and sends alert on every level change
+///
+/// ```ignore
+/// let (sender, mut alerts) = tokio::sync::mpsc::channel(16);
+/// let monitor = MarginMonitor::new(&account_id).with_interval(Duration::from_secs(10));
+/// tokio::spawn(monitor.run(api.users(&channel)?, sender));
+///
+/// while let Some(alert) = alerts.recv().await {
+///     if alert.level == MarginLevel::MarginCall {
+///         guard.kill("margin call");
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct MarginMonitor {
+    account_id: String,
+    thresholds: MarginThresholds,
+    interval: Duration,
+    level: MarginLevel,
+    last: Option<MarginSnapshot>,
+}
+
+impl MarginMonitor {
+    /// Creates monitor polling every 30 seconds with default thresholds
+    pub fn new(account_id: impl Into<String>) -> Self {
+        Self {
+            account_id: account_id.into(),
+            thresholds: MarginThresholds::default(),
+            interval: Duration::from_secs(30),
+            level: MarginLevel::Normal,
+            last: None,
+        }
+    }
+
+    pub fn with_thresholds(mut self, thresholds: MarginThresholds) -> Self {
+        self.thresholds = thresholds;
+        self
+    }
+
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    pub fn account_id(&self) -> &str {
+        &self.account_id
+    }
+
+    pub fn level(&self) -> MarginLevel {
+        self.level
+    }
+
+    /// Returns last evaluated attributes
+    pub fn last(&self) -> Option<&MarginSnapshot> {
+        self.last.as_ref()
+    }
+
+    /// Updates level from response, returns alert if level changed
+    pub fn evaluate(&mut self, response: &GetMarginAttributesResponse) -> Option<MarginAlert> {
+        let snapshot = MarginSnapshot::from(response);
+        let level = self.thresholds.level(&snapshot);
+        let previous = std::mem::replace(&mut self.level, level);
+        self.last = Some(snapshot.clone());
+
+        (level != previous).then(|| MarginAlert {
+            account_id: self.account_id.clone(),
+            previous,
+            level,
+            snapshot,
+        })
+    }
+
+    /// Requests attributes and evaluates them, e.g. on portfolio stream update
+    pub async fn refresh<T>(
+        &mut self,
+        users: &mut UsersServiceClient<T>,
+    ) -> Result<Option<MarginAlert>>
+    where
+        T: GrpcService<BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        let response = users
+            .get_margin_attributes(GetMarginAttributesRequest {
+                account_id: self.account_id.clone(),
+            })
+            .await?
+            .into_inner();
+
+        Ok(self.evaluate(&response))
+    }
+
+    /// Polls until receiver is dropped, failed requests are logged and retried on next poll
+    pub async fn run<T>(
+        mut self,
+        mut users: UsersServiceClient<T>,
+        alerts: mpsc::Sender<MarginAlert>,
+    ) where
+        T: GrpcService<BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        while !alerts.is_closed() {
+            match self.refresh(&mut users).await {
+                Ok(Some(alert)) => {
+                    if alerts.send(alert).await.is_err() {
+                        return;
+                    }
+                }
+                Ok(None) => {}
+                Err(err) => tracing::warn!(
+                    "Failed to get margin attributes of {}: {err}",
+                    self.account_id
+                ),
+            }
+
+            tokio::time::sleep(self.interval).await;
+        }
+    }
+}
+
+// endregion: --- Monitor
+
+// region:    --- Helpers
+
+fn amount(value: &Option<MoneyValue>) -> f64 {
+    value.as_ref().map_or(0.0, MoneyValue::to_f64)
+}
+
+// endregion: --- Helpers
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    type Error = Box<dyn std::error::Error>;
+    type Result<T> = core::result::Result<T, Error>; // For tests.
+
+    use super::*;
+
+    fn attributes(liquid: f64, starting: f64, minimal: f64) -> GetMarginAttributesResponse {
+        GetMarginAttributesResponse {
+            liquid_portfolio: Some(MoneyValue::from_f64("RUB", liquid)),
+            starting_margin: Some(MoneyValue::from_f64("RUB", starting)),
+            minimal_margin: Some(MoneyValue::from_f64("RUB", minimal)),
+            ..Default::default()
+        }
+    }
+
+    /// Answers `GetMarginAttributes` with fixed attributes
+    #[derive(Clone)]
+    struct Stub(GetMarginAttributesResponse);
+
+    impl tower_service::Service<http::Request<BoxBody>> for Stub {
+        type Response = http::Response<BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = std::future::Ready<core::result::Result<Self::Response, Self::Error>>;
+
+        fn poll_ready(
+            &mut self,
+            _cx: &mut std::task::Context<'_>,
+        ) -> std::task::Poll<core::result::Result<(), Self::Error>> {
+            std::task::Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, _request: http::Request<BoxBody>) -> Self::Future {
+            let message = prost::Message::encode_to_vec(&self.0);
+
+            // Length-prefixed message, status is sent in headers
+            let mut frame = vec![0];
+            frame.extend((message.len() as u32).to_be_bytes());
+            frame.extend(message);
+
+            let response = http::Response::builder()
+                .header("content-type", "application/grpc")
+                .header("grpc-status", "0")
+                .body(tonic::body::boxed(http_body_util::Full::new(Bytes::from(
+                    frame,
+                ))));
+
+            std::future::ready(Ok(response.unwrap()))
+        }
+    }
+
+    #[test]
+    fn test_margin_monitor_levels_ok() -> Result<()> {
+        let mut monitor = MarginMonitor::new("account");
+
+        assert_eq!(monitor.evaluate(&attributes(100.0, 50.0, 25.0)), None);
+
+        let alert = monitor
+            .evaluate(&attributes(100.0, 85.0, 40.0))
+            .ok_or("no alert")?;
+        assert_eq!(alert.level, MarginLevel::Warning);
+        assert_eq!(alert.snapshot.utilisation, 0.85);
+        assert_eq!(alert.snapshot.currency, "rub");
+
+        // Same level is not repeated
+        assert_eq!(monitor.evaluate(&attributes(100.0, 90.0, 45.0)), None);
+        assert_eq!(
+            monitor
+                .evaluate(&attributes(100.0, 120.0, 60.0))
+                .map(|alert| alert.level),
+            Some(MarginLevel::Critical)
+        );
+        assert_eq!(
+            monitor
+                .evaluate(&attributes(50.0, 120.0, 60.0))
+                .map(|alert| alert.level),
+            Some(MarginLevel::MarginCall)
+        );
+
+        let alert = monitor
+            .evaluate(&attributes(200.0, 50.0, 25.0))
+            .ok_or("no alert")?;
+        assert!(alert.is_recovery());
+        assert_eq!(alert.previous, MarginLevel::MarginCall);
+        assert_eq!(monitor.level(), MarginLevel::Normal);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_margin_monitor_refresh_ok() -> Result<()> {
+        let runtime = tokio::runtime::Runtime::new()?;
+        let mut monitor = MarginMonitor::new("account");
+        let mut users = UsersServiceClient::new(Stub(attributes(100.0, 90.0, 40.0)));
+
+        let alert = runtime.block_on(monitor.refresh(&mut users))?;
+
+        assert_eq!(alert.map(|alert| alert.level), Some(MarginLevel::Warning));
+        assert_eq!(monitor.last().map(|last| last.utilisation), Some(0.9));
+
+        Ok(())
+    }
+}
+
+// endregion: --- Tests
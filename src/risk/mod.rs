@@ -0,0 +1,34 @@
+//! Pre-trade risk checks and margin monitoring
+//!
+//! `RiskGuard` holds limits and state shared by its clones. `GuardedOrders` checks orders with
+//! the guard before they are sent with `post_order`, `replace_order` and `post_stop_order`.
+//! `MarginMonitor` sends alerts when margin utilisation of account crosses thresholds.
+//!
+//! ```ignore
+//! let guard = RiskGuard::new(
+//!     RiskLimits::new()
+//!         .with_fx_rate("usd", 90.0)
+//!         .with_max_order_notional(100_000.0)
+//!         .with_price_band(0.05)
+//!         .with_max_lots_check(),
+//!     catalog,
+//! );
+//! let mut orders = guard.orders(&api, &channel)?;
+//!
+//! orders.post_order(request).await?; // Err(Error::RiskError(..)) if rejected
+//! guard.kill("strategy error"); // Rejects all orders from now on
+//! ```
+
+// region:    --- Modules
+
+mod error;
+mod guard;
+mod margin;
+mod orders;
+
+pub use error::{Error, Result};
+pub use guard::{OrderCheck, RiskGuard, RiskLimits};
+pub use margin::{MarginAlert, MarginLevel, MarginMonitor, MarginSnapshot, MarginThresholds};
+pub use orders::GuardedOrders;
+
+// endregion: --- Modules
@@ -0,0 +1,434 @@
+//! Order clients behind risk checks
+
+#![allow(clippy::result_large_err)]
+
+use super::{OrderCheck, RiskGuard};
+use crate::analytics::MaxLots;
+use crate::api::v1::{
+    market_data_service_client::MarketDataServiceClient,
+    orders_service_client::OrdersServiceClient,
+    stop_orders_service_client::StopOrdersServiceClient, GetLastPricesRequest, GetMaxLotsRequest,
+    GetOrderStateRequest, IntercemptorWithNew, InvestApiTrait, OrderDirection, PostOrderRequest,
+    PostOrderResponse, PostStopOrderRequest, PostStopOrderResponse, Quotation, ReplaceOrderRequest,
+};
+use crate::Result;
+use tonic::body::BoxBody;
+use tonic::client::GrpcService;
+use tonic::codegen::{Body, Bytes, StdError};
+use tonic::service::interceptor::InterceptedService;
+use tonic::transport::Channel;
+
+/// Sends orders only if `RiskGuard` approves them
+///
+/// Last prices and max lots are requested only for enabled checks. Orders failed to be sent
+/// are released from the guard.
+#[derive(Debug, Clone)]
+pub struct GuardedOrders<T> {
+    guard: RiskGuard,
+    orders: OrdersServiceClient<T>,
+    stop_orders: StopOrdersServiceClient<T>,
+    market_data: MarketDataServiceClient<T>,
+}
+
+impl RiskGuard {
+    /// Creates order clients checked by this guard
+    pub fn orders<D, I>(
+        &self,
+        api: &impl InvestApiTrait<D, I>,
+        channel: &Channel,
+    ) -> Result<GuardedOrders<InterceptedService<Channel, I>>>
+    where
+        I: IntercemptorWithNew<D>,
+    {
+        Ok(GuardedOrders {
+            guard: self.clone(),
+            orders: api.orders(channel)?,
+            stop_orders: api.stop_orders(channel)?,
+            market_data: api.market_data(channel)?,
+        })
+    }
+}
+
+impl<T> GuardedOrders<T>
+where
+    T: GrpcService<BoxBody> + Clone,
+    T::Error: Into<StdError>,
+    T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+    <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+{
+    /// Creates order clients on service, e.g. channel with layers and `InterceptorStack`
+    pub fn new(guard: RiskGuard, service: T) -> Self {
+        Self {
+            guard,
+            orders: OrdersServiceClient::new(service.clone()),
+            stop_orders: StopOrdersServiceClient::new(service.clone()),
+            market_data: MarketDataServiceClient::new(service),
+        }
+    }
+}
+
+impl<T> GuardedOrders<T>
+where
+    T: GrpcService<BoxBody>,
+    T::Error: Into<StdError>,
+    T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+    <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+{
+    pub fn guard(&self) -> &RiskGuard {
+        &self.guard
+    }
+
+    pub async fn post_order(&mut self, request: PostOrderRequest) -> Result<PostOrderResponse> {
+        #[allow(deprecated)]
+        let instrument_id = instrument_id(&request.instrument_id, &request.figi);
+        let direction = direction(request.direction);
+        let check = self
+            .order_check(
+                &request.account_id,
+                instrument_id,
+                direction,
+                request.quantity,
+                price(&request.price),
+            )
+            .await?;
+
+        self.guard.approve(&check)?;
+        let response = match self.orders.post_order(request).await {
+            Ok(response) => response.into_inner(),
+            Err(status) => {
+                self.guard.release(&check);
+                return Err(status.into());
+            }
+        };
+        self.remember(&response, &check);
+
+        Ok(response)
+    }
+
+    /// Checks new quantity and price of order replacing sent one
+    ///
+    /// Only difference from order sent with this guard is counted, other orders are counted
+    /// as new ones.
+    pub async fn replace_order(
+        &mut self,
+        request: ReplaceOrderRequest,
+    ) -> Result<PostOrderResponse> {
+        self.guard.ensure_active()?;
+
+        let (instrument_id, direction) = match self.guard.order(&request.order_id) {
+            Some(order) => order,
+            None => {
+                let state = self
+                    .orders
+                    .get_order_state(GetOrderStateRequest {
+                        account_id: request.account_id.clone(),
+                        order_id: request.order_id.clone(),
+                        ..Default::default()
+                    })
+                    .await?
+                    .into_inner();
+
+                (state.instrument_uid, direction(state.direction))
+            }
+        };
+        let check = self
+            .order_check(
+                &request.account_id,
+                instrument_id,
+                direction,
+                request.quantity,
+                price(&request.price),
+            )
+            .await?;
+
+        let order_id = request.order_id.clone();
+        self.guard.approve_replace(&order_id, &check)?;
+        let response = match self.orders.replace_order(request).await {
+            Ok(response) => response.into_inner(),
+            Err(status) => {
+                self.guard.release_replace(&order_id, &check);
+                return Err(status.into());
+            }
+        };
+        self.guard.forget_order(&order_id);
+        self.remember(&response, &check);
+
+        Ok(response)
+    }
+
+    /// Checks stop order at its limit price, or at stop price if it has none
+    pub async fn post_stop_order(
+        &mut self,
+        request: PostStopOrderRequest,
+    ) -> Result<PostStopOrderResponse> {
+        #[allow(deprecated)]
+        let instrument_id = instrument_id(&request.instrument_id, &request.figi);
+        // `StopOrderDirection` has the same values
+        let direction = direction(request.direction);
+        let check = self
+            .order_check(
+                &request.account_id,
+                instrument_id,
+                direction,
+                request.quantity,
+                price(&request.price).or(price(&request.stop_price)),
+            )
+            .await?;
+
+        self.guard.approve(&check)?;
+        match self.stop_orders.post_stop_order(request).await {
+            Ok(response) => Ok(response.into_inner()),
+            Err(status) => {
+                self.guard.release(&check);
+                Err(status.into())
+            }
+        }
+    }
+
+    /// Requests data for enabled checks
+    async fn order_check(
+        &mut self,
+        account_id: &str,
+        instrument_id: String,
+        direction: OrderDirection,
+        lots: i64,
+        price: Option<f64>,
+    ) -> Result<OrderCheck> {
+        // Kill switch rejects before any request
+        self.guard.ensure_active()?;
+
+        let limits = self.guard.limits();
+        let needs_last_price =
+            limits.price_band.is_some() || (price.is_none() && limits.has_notional_limits());
+        let check_max_lots = limits.check_max_lots;
+
+        let mut check = OrderCheck::new(instrument_id.clone(), direction, lots);
+        check.price = price;
+
+        if needs_last_price {
+            let response = self
+                .market_data
+                .get_last_prices(GetLastPricesRequest {
+                    instrument_id: vec![instrument_id.clone()],
+                    ..Default::default()
+                })
+                .await?
+                .into_inner();
+
+            check.last_price = response
+                .last_prices
+                .into_iter()
+                .find_map(|last| last.price)
+                .map(|price| price.to_f64());
+        }
+
+        if check_max_lots {
+            let response = self
+                .orders
+                .get_max_lots(GetMaxLotsRequest {
+                    account_id: account_id.to_string(),
+                    instrument_id,
+                    price: price.map(Quotation::from_f64),
+                })
+                .await?
+                .into_inner();
+
+            check.max_lots = Some(MaxLots::from(&response));
+        }
+
+        Ok(check)
+    }
+
+    fn remember(&self, response: &PostOrderResponse, check: &OrderCheck) {
+        let uid = if response.instrument_uid.is_empty() {
+            check.instrument_id.clone()
+        } else {
+            response.instrument_uid.clone()
+        };
+
+        self.guard
+            .remember_order(response.order_id.clone(), uid, check);
+    }
+}
+
+/// Instrument id, or legacy figi field
+fn instrument_id(instrument_id: &str, figi: &Option<String>) -> String {
+    match figi {
+        Some(figi) if instrument_id.is_empty() => figi.clone(),
+        _ => instrument_id.to_string(),
+    }
+}
+
+fn direction(value: i32) -> OrderDirection {
+    OrderDirection::try_from(value).unwrap_or(OrderDirection::Unspecified)
+}
+
+fn price(value: &Option<Quotation>) -> Option<f64> {
+    value.as_ref().map(Quotation::to_f64)
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    type Error = Box<dyn std::error::Error>;
+    type Result<T> = core::result::Result<T, Error>; // For tests.
+
+    use super::*;
+    use crate::analytics::InstrumentCatalog;
+    use crate::api::v1::{InterceptorStack, InvestApi, Share};
+    use crate::risk::{self, RiskLimits};
+    use prost::Message;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::{Arc, Mutex};
+    use tonic::codegen::http;
+
+    /// Answers `PostOrder` and `ReplaceOrder` with new order id, fails them with `Unavailable` if set
+    #[derive(Clone, Default)]
+    struct Stub {
+        fail: Arc<AtomicBool>,
+        paths: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl tower_service::Service<http::Request<BoxBody>> for Stub {
+        type Response = http::Response<BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = std::future::Ready<core::result::Result<Self::Response, Self::Error>>;
+
+        fn poll_ready(
+            &mut self,
+            _cx: &mut std::task::Context<'_>,
+        ) -> std::task::Poll<core::result::Result<(), Self::Error>> {
+            std::task::Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, request: http::Request<BoxBody>) -> Self::Future {
+            let mut paths = self.paths.lock().unwrap();
+            paths.push(request.uri().path().to_string());
+
+            let response = http::Response::builder().header("content-type", "application/grpc");
+            let response = if self.fail.load(Ordering::Relaxed) {
+                response
+                    .header("grpc-status", "14")
+                    .body(tonic::body::empty_body())
+            } else {
+                let message = PostOrderResponse {
+                    order_id: format!("order-{}", paths.len()),
+                    instrument_uid: "sber".to_string(),
+                    ..Default::default()
+                }
+                .encode_to_vec();
+
+                // Length-prefixed message, status is sent in headers
+                let mut frame = vec![0];
+                frame.extend((message.len() as u32).to_be_bytes());
+                frame.extend(message);
+
+                response.header("grpc-status", "0").body(tonic::body::boxed(
+                    http_body_util::Full::new(Bytes::from(frame)),
+                ))
+            };
+
+            std::future::ready(Ok(response.unwrap()))
+        }
+    }
+
+    fn orders(stub: &Stub) -> GuardedOrders<InterceptedService<Stub, InterceptorStack>> {
+        let mut catalog = InstrumentCatalog::new();
+        catalog.insert(Share {
+            uid: "sber".to_string(),
+            currency: "rub".to_string(),
+            lot: 10,
+            ..Default::default()
+        });
+        let guard = RiskGuard::new(RiskLimits::new().with_max_daily_notional(50_000.0), catalog);
+
+        let api = InvestApi::with_token("token").layered();
+
+        GuardedOrders::new(guard, api.intercept(stub.clone()))
+    }
+
+    fn buy(lots: i64) -> PostOrderRequest {
+        PostOrderRequest {
+            quantity: lots,
+            price: Some(Quotation::new(300, 0)),
+            direction: OrderDirection::Buy as i32,
+            account_id: "account".to_string(),
+            instrument_id: "sber".to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn replace(order_id: &str, lots: i64) -> ReplaceOrderRequest {
+        ReplaceOrderRequest {
+            account_id: "account".to_string(),
+            order_id: order_id.to_string(),
+            quantity: lots,
+            price: Some(Quotation::new(300, 0)),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_guarded_orders_kill_switch_and_rollback_ok() -> Result<()> {
+        let runtime = tokio::runtime::Runtime::new()?;
+        let stub = Stub::default();
+        let mut orders = orders(&stub);
+
+        // Kill switch rejects before any request
+        orders.guard().kill("test");
+        assert!(matches!(
+            runtime.block_on(orders.post_order(buy(1))),
+            Err(crate::Error::RiskError(risk::Error::KillSwitch(_)))
+        ));
+        assert!(matches!(
+            runtime.block_on(orders.replace_order(replace("order-1", 1))),
+            Err(crate::Error::RiskError(risk::Error::KillSwitch(_)))
+        ));
+        assert!(stub.paths.lock().unwrap().is_empty());
+        orders.guard().resume();
+
+        // Failed order is released
+        stub.fail.store(true, Ordering::Relaxed);
+        assert!(matches!(
+            runtime.block_on(orders.post_order(buy(10))),
+            Err(crate::Error::StatusError(_))
+        ));
+        assert_eq!(stub.paths.lock().unwrap().len(), 1);
+        assert_eq!(orders.guard().daily_notional(), 0.0);
+        assert_eq!(orders.guard().position("sber"), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_guarded_orders_replace_ok() -> Result<()> {
+        let runtime = tokio::runtime::Runtime::new()?;
+        let stub = Stub::default();
+        let mut orders = orders(&stub);
+
+        // 300 * 10 * 10
+        let sent = runtime.block_on(orders.post_order(buy(10)))?;
+        assert_eq!(sent.order_id, "order-1");
+        assert_eq!(orders.guard().daily_notional(), 30_000.0);
+
+        // Only 2 more lots are counted, replacing order as new one would exceed daily limit
+        let replaced = runtime.block_on(orders.replace_order(replace("order-1", 12)))?;
+        assert_eq!(orders.guard().daily_notional(), 36_000.0);
+        assert_eq!(orders.guard().position("sber"), 12);
+        assert!(orders.guard().order("order-1").is_none());
+        assert!(orders.guard().order(&replaced.order_id).is_some());
+
+        // Failed replacement is released
+        stub.fail.store(true, Ordering::Relaxed);
+        assert!(runtime
+            .block_on(orders.replace_order(replace(&replaced.order_id, 4)))
+            .is_err());
+        assert_eq!(orders.guard().daily_notional(), 36_000.0);
+        assert_eq!(orders.guard().position("sber"), 12);
+
+        Ok(())
+    }
+}
+
+// endregion: --- Tests
@@ -0,0 +1,136 @@
+//! API token handling
+//!
+//! `SecretToken` keeps token out of logs and zeroizes it on drop,
+//! `TokenProvider` is queried by interceptor on each request, so token may be rotated
+//! without rebuilding clients.
+
+#![allow(clippy::result_large_err)]
+
+// region:    --- Modules
+
+mod providers;
+
+pub use providers::{CallbackTokenProvider, EnvTokenProvider, FileTokenProvider};
+
+use crate::Result;
+use std::{convert::Infallible, str::FromStr};
+use zeroize::Zeroizing;
+
+// endregion: --- Modules
+
+// region:    --- Secret Token
+
+const BEARER_PREFIX: &str = "Bearer ";
+
+/// API token, redacted in `Debug` and `Display` and zeroized on drop
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct SecretToken(Zeroizing<String>);
+
+impl SecretToken {
+    pub fn new(token: impl Into<String>) -> Self {
+        Self(Zeroizing::new(token.into()))
+    }
+
+    /// Returns token value, avoid keeping or printing it
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns `authorization` header value, zeroized on drop
+    pub fn bearer(&self) -> Zeroizing<String> {
+        let mut value = Zeroizing::new(String::with_capacity(BEARER_PREFIX.len() + self.0.len()));
+        value.push_str(BEARER_PREFIX);
+        value.push_str(&self.0);
+
+        value
+    }
+}
+
+impl core::fmt::Debug for SecretToken {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("SecretToken(***)")
+    }
+}
+
+impl core::fmt::Display for SecretToken {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("***")
+    }
+}
+
+impl From<String> for SecretToken {
+    fn from(token: String) -> Self {
+        Self::new(token)
+    }
+}
+
+impl From<&str> for SecretToken {
+    fn from(token: &str) -> Self {
+        Self::new(token)
+    }
+}
+
+impl FromStr for SecretToken {
+    type Err = Infallible;
+
+    fn from_str(token: &str) -> core::result::Result<Self, Self::Err> {
+        Ok(Self::new(token))
+    }
+}
+
+#[cfg(feature = "config-file")]
+impl<'de> serde::Deserialize<'de> for SecretToken {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self::new)
+    }
+}
+
+// endregion: --- Secret Token
+
+// region:    --- Token Provider
+
+/// Source of API token, queried on each request
+pub trait TokenProvider: core::fmt::Debug + Send + Sync {
+    /// Returns current token
+    fn token(&self) -> Result<SecretToken>;
+}
+
+/// Static token
+impl TokenProvider for SecretToken {
+    fn token(&self) -> Result<SecretToken> {
+        Ok(self.clone())
+    }
+}
+
+// endregion: --- Token Provider
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    type Error = Box<dyn std::error::Error>;
+    type Result<T> = core::result::Result<T, Error>; // For tests.
+
+    use super::*;
+
+    #[test]
+    fn test_secret_token_redacted_ok() -> Result<()> {
+        let token = SecretToken::new("t.secret");
+
+        assert_eq!(format!("{token:?}"), "SecretToken(***)");
+        assert_eq!(token.to_string(), "***");
+        assert_eq!(token.expose(), "t.secret");
+        assert_eq!(token.token()?.expose(), "t.secret");
+        assert_eq!(token.bearer().as_str(), "Bearer t.secret");
+
+        Ok(())
+    }
+}
+
+// endregion: --- Tests
@@ -0,0 +1,216 @@
+//! Token providers reading token on each request
+
+// region:    --- Modules
+
+use super::{SecretToken, TokenProvider};
+use crate::{Error, Result};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime};
+
+// endregion: --- Modules
+
+/// Default interval of checking token file for changes
+const DEFAULT_FILE_TTL: Duration = Duration::from_secs(1);
+
+/// Reads token from environment variable
+#[derive(Debug, Clone)]
+pub struct EnvTokenProvider {
+    name: String,
+    vars: Option<BTreeMap<String, SecretToken>>,
+}
+
+impl EnvTokenProvider {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            vars: None,
+        }
+    }
+
+    /// Reads variables from map instead of process environment, e.g. in tests
+    pub fn env_vars<K, V>(mut self, vars: impl IntoIterator<Item = (K, V)>) -> Self
+    where
+        K: Into<String>,
+        V: Into<SecretToken>,
+    {
+        self.vars = Some(
+            vars.into_iter()
+                .map(|(name, value)| (name.into(), value.into()))
+                .collect(),
+        );
+        self
+    }
+}
+
+impl TokenProvider for EnvTokenProvider {
+    fn token(&self) -> Result<SecretToken> {
+        let token = match &self.vars {
+            Some(vars) => vars.get(&self.name).cloned(),
+            None => std::env::var(&self.name).ok().map(SecretToken::new),
+        };
+
+        token.ok_or_else(|| Error::ConfigMissingEnv(self.name.clone()))
+    }
+}
+
+/// Reads token from file, surrounding whitespace is trimmed
+///
+/// Token is cached, file is checked for changes at most once per `ttl` and read again only
+/// if its modification time or size changed.
+#[derive(Debug, Clone)]
+pub struct FileTokenProvider {
+    path: PathBuf,
+    ttl: Duration,
+    cache: Arc<Mutex<Option<CachedToken>>>,
+}
+
+#[derive(Debug)]
+struct CachedToken {
+    token: SecretToken,
+    /// Modification time and size of file token was read from
+    version: (Option<SystemTime>, u64),
+    checked: Instant,
+}
+
+impl FileTokenProvider {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            ttl: DEFAULT_FILE_TTL,
+            cache: Arc::default(),
+        }
+    }
+
+    /// Sets interval of checking file for changes, 1 second by default
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    fn io_error(&self, err: std::io::Error) -> Error {
+        Error::TokenFileError {
+            path: self.path.display().to_string(),
+            kind: err.kind(),
+        }
+    }
+}
+
+impl TokenProvider for FileTokenProvider {
+    fn token(&self) -> Result<SecretToken> {
+        let mut cache = self.cache.lock().unwrap_or_else(|err| err.into_inner());
+
+        if let Some(cached) = cache.as_mut() {
+            if cached.checked.elapsed() < self.ttl {
+                return Ok(cached.token.clone());
+            }
+        }
+
+        let metadata = std::fs::metadata(&self.path).map_err(|err| self.io_error(err))?;
+        let version = (metadata.modified().ok(), metadata.len());
+
+        if let Some(cached) = cache.as_mut().filter(|cached| cached.version == version) {
+            cached.checked = Instant::now();
+            return Ok(cached.token.clone());
+        }
+
+        let content = zeroize::Zeroizing::new(
+            std::fs::read_to_string(&self.path).map_err(|err| self.io_error(err))?,
+        );
+        let token = SecretToken::new(content.trim());
+
+        *cache = Some(CachedToken {
+            token: token.clone(),
+            version,
+            checked: Instant::now(),
+        });
+
+        Ok(token)
+    }
+}
+
+/// Gets token from callback, e.g. reading keyring or running command
+pub struct CallbackTokenProvider<F> {
+    callback: F,
+}
+
+impl<F> CallbackTokenProvider<F>
+where
+    F: Fn() -> Result<SecretToken> + Send + Sync,
+{
+    pub fn new(callback: F) -> Self {
+        Self { callback }
+    }
+}
+
+impl<F> core::fmt::Debug for CallbackTokenProvider<F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("CallbackTokenProvider")
+    }
+}
+
+impl<F> TokenProvider for CallbackTokenProvider<F>
+where
+    F: Fn() -> Result<SecretToken> + Send + Sync,
+{
+    fn token(&self) -> Result<SecretToken> {
+        (self.callback)()
+    }
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    type Error = Box<dyn std::error::Error>;
+    type Result<T> = core::result::Result<T, Error>; // For tests.
+
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_token_providers_ok() -> Result<()> {
+        let env = EnvTokenProvider::new("TEST_PROVIDER_TOKEN")
+            .env_vars([("TEST_PROVIDER_TOKEN", "env-token")]);
+        assert_eq!(env.token()?.expose(), "env-token");
+        let missing = EnvTokenProvider::new("TEST_PROVIDER_MISSING").env_vars([("TOKEN", "token")]);
+        assert!(matches!(
+            missing.token(),
+            Err(crate::Error::ConfigMissingEnv(name)) if name == "TEST_PROVIDER_MISSING"
+        ));
+
+        let path = std::env::temp_dir().join(format!(
+            "investments-tinkoff-test-token-{}",
+            uuid::Uuid::new_v4()
+        ));
+        std::fs::write(&path, "file-token\n")?;
+        let file = FileTokenProvider::new(&path).with_ttl(Duration::from_secs(3600));
+        assert_eq!(file.token()?.expose(), "file-token");
+
+        // Cached until ttl passes, then read again as file changed
+        std::fs::write(&path, "rotated-token-value\n")?;
+        assert_eq!(file.token()?.expose(), "file-token");
+        let file = file.with_ttl(Duration::ZERO);
+        assert_eq!(file.token()?.expose(), "rotated-token-value");
+
+        std::fs::remove_file(&path)?;
+        assert!(matches!(
+            file.token(),
+            Err(crate::Error::TokenFileError { kind, .. }) if kind == std::io::ErrorKind::NotFound
+        ));
+
+        // Token is requested each time, so it may be rotated
+        let calls = AtomicUsize::new(0);
+        let callback = CallbackTokenProvider::new(|| {
+            let call = calls.fetch_add(1, Ordering::SeqCst);
+            Ok(SecretToken::new(format!("token-{call}")))
+        });
+        assert_eq!(callback.token()?.expose(), "token-0");
+        assert_eq!(callback.token()?.expose(), "token-1");
+
+        Ok(())
+    }
+}
+
+// endregion: --- Tests